@@ -0,0 +1,283 @@
+use crate::Client;
+use anyhow::Result;
+
+#[doc = " A callback producing the authentication headers of a request."]
+pub type AuthCallback = std::sync::Arc<
+    dyn Fn() -> futures::future::BoxFuture<
+            'static,
+            Result<reqwest::header::HeaderMap, crate::types::error::Error>,
+        > + Send
+        + Sync,
+>;
+
+#[doc = " How the client authenticates its requests."]
+#[derive(Clone)]
+pub enum Auth {
+    #[doc = " Send no credentials, e.g. to obtain a token."]
+    None,
+    #[doc = " An API token, sent as `Authorization: Token <token>`."]
+    Token(String),
+    #[doc = " HTTP basic authentication."]
+    Basic {
+        #[doc = " The user name."]
+        username: String,
+        #[doc = " The password."]
+        password: String,
+    },
+    #[doc = " Credentials exchanged for an API token via `/api/token/` on the first request,"]
+    #[doc = " and again whenever the server refuses that token."]
+    Credentials {
+        #[doc = " The user name."]
+        username: String,
+        #[doc = " The password."]
+        password: String,
+        #[doc = " The current MFA code, if the account requires one."]
+        code: Option<String>,
+    },
+    #[doc = " An existing Django session, e.g. taken from a logged in browser."]
+    #[doc = ""]
+    #[doc = " The CSRF token is sent with every request so unsafe methods are accepted."]
+    Session {
+        #[doc = " The value of the `sessionid` cookie."]
+        session_id: String,
+        #[doc = " The value of the `csrftoken` cookie."]
+        csrf_token: String,
+    },
+    #[doc = " Headers produced by a callback for every request."]
+    Custom(AuthCallback),
+}
+
+impl Auth {
+    #[doc = " Authenticate with headers produced by `f` for every request."]
+    pub fn custom<F, Fut>(f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<
+                Output = Result<reqwest::header::HeaderMap, crate::types::error::Error>,
+            > + Send
+            + 'static,
+    {
+        Auth::Custom(std::sync::Arc::new(move || Box::pin(f())))
+    }
+}
+
+impl std::fmt::Debug for Auth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Auth::None => write!(f, "None"),
+            Auth::Token(_) => write!(f, "Token(..)"),
+            Auth::Basic { username, .. } => write!(f, "Basic {{ username: {username:?}, .. }}"),
+            Auth::Credentials { username, .. } => {
+                write!(f, "Credentials {{ username: {username:?}, .. }}")
+            }
+            Auth::Session { .. } => write!(f, "Session {{ .. }}"),
+            Auth::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+fn header_value(value: &str) -> Result<reqwest::header::HeaderValue, crate::types::error::Error> {
+    let mut value = reqwest::header::HeaderValue::from_str(value).map_err(|e| {
+        crate::types::error::Error::InvalidRequest(format!("invalid auth header: {e}"))
+    })?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type HeadersFuture<'a> =
+    futures::future::BoxFuture<'a, Result<reqwest::header::HeaderMap, crate::types::error::Error>>;
+#[cfg(target_arch = "wasm32")]
+type HeadersFuture<'a> = futures::future::LocalBoxFuture<
+    'a,
+    Result<reqwest::header::HeaderMap, crate::types::error::Error>,
+>;
+
+impl Client {
+    #[doc = " The headers authenticating a request with the configured [`Auth`]."]
+    pub fn auth_headers(&self) -> HeadersFuture<'_> {
+        // Boxed, as obtaining a token for credentials sends a request itself.
+        Box::pin(self.auth_headers_inner())
+    }
+
+    async fn auth_headers_inner(
+        &self,
+    ) -> Result<reqwest::header::HeaderMap, crate::types::error::Error> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        match &self.auth {
+            Auth::None => {}
+            Auth::Token(token) => {
+                headers.insert(
+                    reqwest::header::AUTHORIZATION,
+                    header_value(&format!("Token {token}"))?,
+                );
+            }
+            Auth::Basic { username, password } => {
+                use base64::Engine;
+                let encoded = base64::engine::general_purpose::STANDARD
+                    .encode(format!("{username}:{password}"));
+                headers.insert(
+                    reqwest::header::AUTHORIZATION,
+                    header_value(&format!("Basic {encoded}"))?,
+                );
+            }
+            Auth::Credentials {
+                username,
+                password,
+                code,
+            } => {
+                let token = self.credentials_token(username, password, code).await?;
+                headers.insert(
+                    reqwest::header::AUTHORIZATION,
+                    header_value(&format!("Token {token}"))?,
+                );
+            }
+            Auth::Session {
+                session_id,
+                csrf_token,
+            } => {
+                headers.insert(
+                    reqwest::header::COOKIE,
+                    header_value(&format!("sessionid={session_id}; csrftoken={csrf_token}"))?,
+                );
+                headers.insert("X-CSRFToken", header_value(csrf_token)?);
+                // Django checks the referer of unsafe requests over https.
+                headers.insert(
+                    reqwest::header::REFERER,
+                    header_value(&format!("{}/", self.base_url))?,
+                );
+            }
+            Auth::Custom(callback) => headers = callback().await?,
+        }
+        Ok(headers)
+    }
+
+    async fn credentials_token(
+        &self,
+        username: &str,
+        password: &str,
+        code: &Option<String>,
+    ) -> Result<String, crate::types::error::Error> {
+        // Held across the request, so concurrent first requests obtain one token.
+        let mut cache = self.token_cache.lock().await;
+        if let Some(token) = cache.clone() {
+            return Ok(token);
+        }
+        let anonymous = Client {
+            auth: Auth::None,
+            ..self.clone()
+        };
+        let body = crate::types::PaperlessAuthTokenRequest {
+            username: username.to_string(),
+            password: password.to_string(),
+            code: code.clone(),
+        };
+        let token = anonymous.token().create(&body).await?.token;
+        *cache = Some(token.clone());
+        Ok(token)
+    }
+
+    #[doc = " Obtain a new token for the configured credentials after `rejected`, the"]
+    #[doc = " `Authorization` header of a request, was refused by the server."]
+    #[doc = ""]
+    #[doc = " The cached token is only dropped while it is still the rejected one, so"]
+    #[doc = " concurrent requests failing with the same token obtain one new token."]
+    pub(crate) async fn refresh_token(
+        &self,
+        rejected: &reqwest::header::HeaderValue,
+    ) -> Result<reqwest::header::HeaderValue, crate::types::error::Error> {
+        let Auth::Credentials {
+            username,
+            password,
+            code,
+        } = &self.auth
+        else {
+            return Err(crate::types::error::Error::InvalidRequest(
+                "only tokens obtained for credentials can be refreshed".to_string(),
+            ));
+        };
+        {
+            let mut cache = self.token_cache.lock().await;
+            let sent = cache.as_ref().map(|token| format!("Token {token}"));
+            if sent.as_deref() == rejected.to_str().ok() {
+                *cache = None;
+            }
+        }
+        let token = self.credentials_token(username, password, code).await?;
+        header_value(&format!("Token {token}"))
+    }
+
+    pub(crate) async fn request_headers(
+        &self,
+    ) -> Result<reqwest::header::HeaderMap, crate::types::error::Error> {
+        let mut headers = self.auth_headers().await?;
+        headers.insert(reqwest::header::ACCEPT, self.accept_header());
+        Ok(headers)
+    }
+
+    #[doc = " Add the authentication headers, the pinned API version and the client's retry"]
+    #[doc = " policy to a request. Requests authenticated with a token obtained for"]
+    #[doc = " credentials are marked for [`TokenRefreshMiddleware`]."]
+    pub(crate) async fn authenticate(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<reqwest_middleware::RequestBuilder, crate::types::error::Error> {
+        let req = req.headers(self.request_headers().await?);
+        #[cfg(feature = "retry")]
+        let req = match &self.retry_policy {
+            Some(policy) => req.with_extension(policy.clone()),
+            None => req,
+        };
+        let req = match &self.auth {
+            Auth::Credentials { .. } => req.with_extension(CredentialsToken(self.clone())),
+            _ => req,
+        };
+        Ok(req)
+    }
+}
+
+#[doc = " Marks a request authenticated with a token obtained for credentials by the"]
+#[doc = " client that sent it."]
+#[derive(Clone)]
+struct CredentialsToken(Client);
+
+#[doc = " Middleware sending a request once more with a new token when the token obtained"]
+#[doc = " for the client's credentials was refused, e.g. after it was rotated or revoked."]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct TokenRefreshMiddleware;
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl reqwest_middleware::Middleware for TokenRefreshMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut http::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let Some(CredentialsToken(client)) = extensions.get::<CredentialsToken>().cloned() else {
+            return next.run(req, extensions).await;
+        };
+        let rejected = req.headers().get(reqwest::header::AUTHORIZATION).cloned();
+        let again = req.try_clone();
+        let resp = next.clone().run(req, extensions).await?;
+        if resp.status() != reqwest::StatusCode::UNAUTHORIZED {
+            return Ok(resp);
+        }
+        let (Some(rejected), Some(mut again)) = (rejected, again) else {
+            return Ok(resp);
+        };
+        match client.refresh_token(&rejected).await {
+            Ok(authorization) => {
+                again
+                    .headers_mut()
+                    .insert(reqwest::header::AUTHORIZATION, authorization);
+                next.run(again, extensions).await
+            }
+            Err(e) => {
+                tracing::debug!("could not refresh the credentials token: {e}");
+                Ok(resp)
+            }
+        }
+    }
+}
@@ -22,7 +22,7 @@ impl BulkEditObjects {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/bulk_edit_objects/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -21,7 +21,7 @@ impl Config {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/config/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -56,7 +56,7 @@ impl Config {
                 "api/config/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -92,7 +92,7 @@ impl Config {
                 "api/config/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -125,7 +125,7 @@ impl Config {
                 "api/config/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -155,7 +155,7 @@ impl Config {
                 "api/config/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
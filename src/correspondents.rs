@@ -31,7 +31,7 @@ impl Correspondents {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/correspondents/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = full_perms {
             query_params.push(("full_perms", format!("{p}")));
@@ -111,76 +111,24 @@ impl Correspondents {
     ) -> impl futures::Stream<Item = Result<crate::types::Correspondent, crate::types::error::Error>>
            + Unpin
            + '_ {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(
-            full_perms,
-            id,
-            id__in,
-            name__icontains,
-            name__iendswith,
-            name__iexact,
-            name__istartswith,
-            ordering,
-            None,
-            page_size,
+        crate::paginator::list_items(
+            &self.client,
+            self.list(
+                full_perms,
+                id,
+                id__in,
+                name__icontains,
+                name__iendswith,
+                name__iexact,
+                name__istartswith,
+                ordering,
+                None,
+                page_size,
+            ),
         )
-        .map_ok(move |result| {
-            let items = futures::stream::iter(result.items().into_iter().map(Ok));
-            let next_pages = futures::stream::try_unfold(
-                (None, result),
-                move |(prev_page_token, new_result)| async move {
-                    if new_result.has_more_pages()
-                        && !new_result.items().is_empty()
-                        && prev_page_token != new_result.next_page_token()
-                    {
-                        async {
-                            let mut req = self.client.client.request(
-                                http::Method::GET,
-                                format!("{}/{}", self.client.base_url, "api/correspondents/"),
-                            );
-                            req = req
-                                .header("Authorization", format!("Token {}", &self.client.token));
-                            let mut request = req.build()?;
-                            request = new_result.next_page(request)?;
-                            let resp = self.client.client.execute(request).await?;
-                            let status = resp.status();
-                            if status.is_success() {
-                                let text = resp.text().await.unwrap_or_default();
-                                serde_json::from_str(&text).map_err(|err| {
-                                    crate::types::error::Error::from_serde_error(
-                                        format_serde_error::SerdeError::new(text.to_string(), err),
-                                        status,
-                                    )
-                                })
-                            } else {
-                                let text = resp.text().await.unwrap_or_default();
-                                Err(crate::types::error::Error::Server {
-                                    body: text.to_string(),
-                                    status,
-                                })
-                            }
-                        }
-                        .map_ok(|result: crate::types::PaginatedCorrespondentList| {
-                            Some((
-                                futures::stream::iter(result.items().into_iter().map(Ok)),
-                                (new_result.next_page_token(), result),
-                            ))
-                        })
-                        .await
-                    } else {
-                        Ok(None)
-                    }
-                },
-            )
-            .try_flatten();
-            items.chain(next_pages)
-        })
-        .try_flatten_stream()
-        .boxed()
     }
 
-    #[doc = "Perform a `POST` request to `/api/correspondents/`.\n\n```rust,no_run\nasync fn example_correspondents_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Correspondent = client\n        .correspondents()\n        .create(&paperless_api_client::types::CorrespondentRequest {\n            name: \"some-string\".to_string(),\n            match_: Some(\"some-string\".to_string()),\n            matching_algorithm: Some(4 as i64),\n            is_insensitive: Some(true),\n            owner: Some(4 as i64),\n            set_permissions: Some(paperless_api_client::types::SetPermissions {\n                view: Some(paperless_api_client::types::View {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n                change: Some(paperless_api_client::types::Change {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n            }),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `POST` request to `/api/correspondents/`.\n\n```rust,no_run\nasync fn example_correspondents_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Correspondent = client\n        .correspondents()\n        .create(&paperless_api_client::types::CorrespondentRequest {\n            name: \"some-string\".to_string(),\n            match_: Some(\"some-string\".to_string()),\n            matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n            is_insensitive: Some(true),\n            owner: Some(4 as i64),\n            set_permissions: Some(paperless_api_client::types::SetPermissions {\n                view: Some(paperless_api_client::types::View {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n                change: Some(paperless_api_client::types::Change {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n            }),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn create<'a>(
@@ -191,7 +139,7 @@ impl Correspondents {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/correspondents/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -228,7 +176,7 @@ impl Correspondents {
                 "api/correspondents/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = full_perms {
             query_params.push(("full_perms", format!("{p}")));
@@ -254,7 +202,7 @@ impl Correspondents {
         }
     }
 
-    #[doc = "Perform a `PUT` request to `/api/correspondents/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this correspondent. (required)\n\n```rust,no_run\nasync fn example_correspondents_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Correspondent = client\n        .correspondents()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::CorrespondentRequest {\n                name: \"some-string\".to_string(),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(4 as i64),\n                is_insensitive: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PUT` request to `/api/correspondents/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this correspondent. (required)\n\n```rust,no_run\nasync fn example_correspondents_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Correspondent = client\n        .correspondents()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::CorrespondentRequest {\n                name: \"some-string\".to_string(),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                is_insensitive: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn update<'a>(
@@ -270,7 +218,7 @@ impl Correspondents {
                 "api/correspondents/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -303,7 +251,7 @@ impl Correspondents {
                 "api/correspondents/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -317,7 +265,7 @@ impl Correspondents {
         }
     }
 
-    #[doc = "Perform a `PATCH` request to `/api/correspondents/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this correspondent. (required)\n\n```rust,no_run\nasync fn example_correspondents_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Correspondent = client\n        .correspondents()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedCorrespondentRequest {\n                name: Some(\"some-string\".to_string()),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(4 as i64),\n                is_insensitive: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PATCH` request to `/api/correspondents/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this correspondent. (required)\n\n```rust,no_run\nasync fn example_correspondents_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Correspondent = client\n        .correspondents()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedCorrespondentRequest {\n                name: Some(\"some-string\".to_string()),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                is_insensitive: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn partial_update<'a>(
@@ -333,7 +281,7 @@ impl Correspondents {
                 "api/correspondents/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -353,4 +301,31 @@ impl Correspondents {
             })
         }
     }
+
+    #[doc = "Fetch correspondents by id with `GET` requests to `/api/correspondents/` with `id__in` filters, in batches sent in parallel.\n\nIds not found, e.g. deleted or not visible to the user, are reported as missing.\n\n```rust,no_run\nasync fn example_correspondents_get_many() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::paginator::ByIds<paperless_api_client::types::Correspondent> =\n        client.correspondents().get_many(&[4 as i64, 5 as i64]).await?;\n    println!(\"{:?} missing\", result.missing);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn get_many<'a>(
+        &'a self,
+        ids: &'a [i64],
+    ) -> Result<crate::paginator::ByIds<crate::types::Correspondent>, crate::types::error::Error>
+    {
+        crate::paginator::get_many::<crate::types::PaginatedCorrespondentList>(
+            &self.client,
+            "api/correspondents/",
+            ids,
+            |item| item.id,
+        )
+        .await
+    }
+
+    crate::taxonomy::impl_name_lookups!(
+        owned,
+        "correspondent",
+        "api/correspondents/",
+        correspondents,
+        Correspondent,
+        PaginatedCorrespondentList,
+        CorrespondentRequest,
+        "&paperless_api_client::types::CorrespondentRequest {\n                name: String::new(),\n                match_: None,\n                matching_algorithm: None,\n                is_insensitive: None,\n                owner: None,\n                set_permissions: None,\n            }"
+    );
 }
@@ -30,7 +30,7 @@ impl CustomFields {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/custom_fields/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = id {
             query_params.push(("id", format!("{p}")));
@@ -105,72 +105,20 @@ impl CustomFields {
     ) -> impl futures::Stream<Item = Result<crate::types::CustomField, crate::types::error::Error>>
            + Unpin
            + '_ {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(
-            id,
-            id__in,
-            name__icontains,
-            name__iendswith,
-            name__iexact,
-            name__istartswith,
-            ordering,
-            None,
-            page_size,
+        crate::paginator::list_items(
+            &self.client,
+            self.list(
+                id,
+                id__in,
+                name__icontains,
+                name__iendswith,
+                name__iexact,
+                name__istartswith,
+                ordering,
+                None,
+                page_size,
+            ),
         )
-        .map_ok(move |result| {
-            let items = futures::stream::iter(result.items().into_iter().map(Ok));
-            let next_pages = futures::stream::try_unfold(
-                (None, result),
-                move |(prev_page_token, new_result)| async move {
-                    if new_result.has_more_pages()
-                        && !new_result.items().is_empty()
-                        && prev_page_token != new_result.next_page_token()
-                    {
-                        async {
-                            let mut req = self.client.client.request(
-                                http::Method::GET,
-                                format!("{}/{}", self.client.base_url, "api/custom_fields/"),
-                            );
-                            req = req
-                                .header("Authorization", format!("Token {}", &self.client.token));
-                            let mut request = req.build()?;
-                            request = new_result.next_page(request)?;
-                            let resp = self.client.client.execute(request).await?;
-                            let status = resp.status();
-                            if status.is_success() {
-                                let text = resp.text().await.unwrap_or_default();
-                                serde_json::from_str(&text).map_err(|err| {
-                                    crate::types::error::Error::from_serde_error(
-                                        format_serde_error::SerdeError::new(text.to_string(), err),
-                                        status,
-                                    )
-                                })
-                            } else {
-                                let text = resp.text().await.unwrap_or_default();
-                                Err(crate::types::error::Error::Server {
-                                    body: text.to_string(),
-                                    status,
-                                })
-                            }
-                        }
-                        .map_ok(|result: crate::types::PaginatedCustomFieldList| {
-                            Some((
-                                futures::stream::iter(result.items().into_iter().map(Ok)),
-                                (new_result.next_page_token(), result),
-                            ))
-                        })
-                        .await
-                    } else {
-                        Ok(None)
-                    }
-                },
-            )
-            .try_flatten();
-            items.chain(next_pages)
-        })
-        .try_flatten_stream()
-        .boxed()
     }
 
     #[doc = "Perform a `POST` request to `/api/custom_fields/`.\n\n```rust,no_run\nasync fn example_custom_fields_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::CustomField = client\n        .custom_fields()\n        .create(&paperless_api_client::types::CustomFieldRequest {\n            name: \"some-string\".to_string(),\n            data_type: paperless_api_client::types::DataTypeEnum::Float,\n            extra_data: Some(serde_json::Value::String(\"some-string\".to_string())),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
@@ -184,7 +132,7 @@ impl CustomFields {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/custom_fields/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -220,7 +168,7 @@ impl CustomFields {
                 "api/custom_fields/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -256,7 +204,7 @@ impl CustomFields {
                 "api/custom_fields/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -289,7 +237,7 @@ impl CustomFields {
                 "api/custom_fields/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -319,7 +267,7 @@ impl CustomFields {
                 "api/custom_fields/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -339,4 +287,31 @@ impl CustomFields {
             })
         }
     }
+
+    #[doc = "Fetch custom fields by id with `GET` requests to `/api/custom_fields/` with `id__in` filters, in batches sent in parallel.\n\nIds not found, e.g. deleted or not visible to the user, are reported as missing.\n\n```rust,no_run\nasync fn example_custom_fields_get_many() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::paginator::ByIds<paperless_api_client::types::CustomField> =\n        client.custom_fields().get_many(&[4 as i64, 5 as i64]).await?;\n    println!(\"{:?} missing\", result.missing);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn get_many<'a>(
+        &'a self,
+        ids: &'a [i64],
+    ) -> Result<crate::paginator::ByIds<crate::types::CustomField>, crate::types::error::Error>
+    {
+        crate::paginator::get_many::<crate::types::PaginatedCustomFieldList>(
+            &self.client,
+            "api/custom_fields/",
+            ids,
+            |item| item.id,
+        )
+        .await
+    }
+
+    crate::taxonomy::impl_name_lookups!(
+        global,
+        "custom field",
+        "api/custom_fields/",
+        custom_fields,
+        CustomField,
+        PaginatedCustomFieldList,
+        CustomFieldRequest,
+        "&paperless_api_client::types::CustomFieldRequest {\n                name: String::new(),\n                data_type: paperless_api_client::types::DataTypeEnum::Monetary,\n                extra_data: None,\n            }"
+    );
 }
@@ -1,5 +1,9 @@
 use crate::Client;
 use anyhow::Result;
+
+#[doc = " How often [`Documents::edit`] applies an edit before giving up on concurrent changes."]
+pub const EDIT_ATTEMPTS: usize = 3;
+
 #[derive(Clone, Debug)]
 pub struct Documents {
     pub client: Client,
@@ -128,474 +132,120 @@ impl Documents {
         title_istartswith: Option<String>,
         title_content: Option<String>,
     ) -> Result<crate::types::PaginatedDocumentList, crate::types::error::Error> {
-        let mut req = self.client.client.request(
-            http::Method::GET,
-            format!("{}/{}", self.client.base_url, "api/documents/"),
-        );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
-        let mut query_params = vec![];
-        if let Some(p) = added_date_gt {
-            query_params.push(("added__date__gt", format!("{p}")));
-        }
-
-        if let Some(p) = added_date_gte {
-            query_params.push(("added__date__gte", format!("{p}")));
-        }
-
-        if let Some(p) = added_date_lt {
-            query_params.push(("added__date__lt", format!("{p}")));
-        }
-
-        if let Some(p) = added_date_lte {
-            query_params.push(("added__date__lte", format!("{p}")));
-        }
-
-        if let Some(p) = added_day {
-            query_params.push(("added__day", format!("{p}")));
-        }
-
-        if let Some(p) = added_gt {
-            query_params.push(("added__gt", format!("{p}")));
-        }
-
-        if let Some(p) = added_gte {
-            query_params.push(("added__gte", format!("{p}")));
-        }
-
-        if let Some(p) = added_lt {
-            query_params.push(("added__lt", format!("{p}")));
-        }
-
-        if let Some(p) = added_lte {
-            query_params.push(("added__lte", format!("{p}")));
-        }
-
-        if let Some(p) = added_month {
-            query_params.push(("added__month", format!("{p}")));
-        }
-
-        if let Some(p) = added_year {
-            query_params.push(("added__year", format!("{p}")));
-        }
-
-        if let Some(p) = archive_serial_number {
-            query_params.push(("archive_serial_number", format!("{p}")));
-        }
-
-        if let Some(p) = archive_serial_number_gt {
-            query_params.push(("archive_serial_number__gt", format!("{p}")));
-        }
-
-        if let Some(p) = archive_serial_number_gte {
-            query_params.push(("archive_serial_number__gte", format!("{p}")));
-        }
-
-        if let Some(p) = archive_serial_number_isnull {
-            query_params.push(("archive_serial_number__isnull", format!("{p}")));
-        }
-
-        if let Some(p) = archive_serial_number_lt {
-            query_params.push(("archive_serial_number__lt", format!("{p}")));
-        }
-
-        if let Some(p) = archive_serial_number_lte {
-            query_params.push(("archive_serial_number__lte", format!("{p}")));
-        }
-
-        if let Some(p) = checksum_icontains {
-            query_params.push(("checksum__icontains", p));
-        }
-
-        if let Some(p) = checksum_iendswith {
-            query_params.push(("checksum__iendswith", p));
-        }
-
-        if let Some(p) = checksum_iexact {
-            query_params.push(("checksum__iexact", p));
-        }
-
-        if let Some(p) = checksum_istartswith {
-            query_params.push(("checksum__istartswith", p));
-        }
-
-        if let Some(p) = content_icontains {
-            query_params.push(("content__icontains", p));
-        }
-
-        if let Some(p) = content_iendswith {
-            query_params.push(("content__iendswith", p));
-        }
-
-        if let Some(p) = content_iexact {
-            query_params.push(("content__iexact", p));
-        }
-
-        if let Some(p) = content_istartswith {
-            query_params.push(("content__istartswith", p));
-        }
-
-        if let Some(p) = correspondent_id {
-            query_params.push(("correspondent__id", format!("{p}")));
-        }
-
-        if let Some(p) = correspondent_id_in {
-            query_params.push(("correspondent__id__in", itertools::join(p, ",")));
-        }
-
-        if let Some(p) = correspondent_id_none {
-            query_params.push(("correspondent__id__none", format!("{p}")));
-        }
-
-        if let Some(p) = correspondent_isnull {
-            query_params.push(("correspondent__isnull", format!("{p}")));
-        }
-
-        if let Some(p) = correspondent_name_icontains {
-            query_params.push(("correspondent__name__icontains", p));
-        }
-
-        if let Some(p) = correspondent_name_iendswith {
-            query_params.push(("correspondent__name__iendswith", p));
-        }
-
-        if let Some(p) = correspondent_name_iexact {
-            query_params.push(("correspondent__name__iexact", p));
-        }
-
-        if let Some(p) = correspondent_name_istartswith {
-            query_params.push(("correspondent__name__istartswith", p));
-        }
-
-        if let Some(p) = created_date_gt {
-            query_params.push(("created__date__gt", format!("{p}")));
-        }
-
-        if let Some(p) = created_date_gte {
-            query_params.push(("created__date__gte", format!("{p}")));
-        }
-
-        if let Some(p) = created_date_lt {
-            query_params.push(("created__date__lt", format!("{p}")));
-        }
-
-        if let Some(p) = created_date_lte {
-            query_params.push(("created__date__lte", format!("{p}")));
-        }
-
-        if let Some(p) = created_day {
-            query_params.push(("created__day", format!("{p}")));
-        }
-
-        if let Some(p) = created_gt {
-            query_params.push(("created__gt", format!("{p}")));
-        }
-
-        if let Some(p) = created_gte {
-            query_params.push(("created__gte", format!("{p}")));
-        }
-
-        if let Some(p) = created_lt {
-            query_params.push(("created__lt", format!("{p}")));
-        }
-
-        if let Some(p) = created_lte {
-            query_params.push(("created__lte", format!("{p}")));
-        }
-
-        if let Some(p) = created_month {
-            query_params.push(("created__month", format!("{p}")));
-        }
-
-        if let Some(p) = created_year {
-            query_params.push(("created__year", format!("{p}")));
-        }
-
-        if let Some(p) = custom_field_query {
-            query_params.push(("custom_field_query", p));
-        }
-
-        if let Some(p) = custom_fields_icontains {
-            query_params.push(("custom_fields__icontains", p));
-        }
-
-        if let Some(p) = custom_fields_id_all {
-            query_params.push(("custom_fields__id__all", format!("{p}")));
-        }
-
-        if let Some(p) = custom_fields_id_in {
-            query_params.push(("custom_fields__id__in", format!("{p}")));
-        }
-
-        if let Some(p) = custom_fields_id_none {
-            query_params.push(("custom_fields__id__none", format!("{p}")));
-        }
-
-        if let Some(p) = document_type_id {
-            query_params.push(("document_type__id", format!("{p}")));
-        }
-
-        if let Some(p) = document_type_id_in {
-            query_params.push(("document_type__id__in", itertools::join(p, ",")));
-        }
-
-        if let Some(p) = document_type_id_none {
-            query_params.push(("document_type__id__none", format!("{p}")));
-        }
-
-        if let Some(p) = document_type_isnull {
-            query_params.push(("document_type__isnull", format!("{p}")));
-        }
-
-        if let Some(p) = document_type_name_icontains {
-            query_params.push(("document_type__name__icontains", p));
-        }
-
-        if let Some(p) = document_type_name_iendswith {
-            query_params.push(("document_type__name__iendswith", p));
-        }
-
-        if let Some(p) = document_type_name_iexact {
-            query_params.push(("document_type__name__iexact", p));
-        }
-
-        if let Some(p) = document_type_name_istartswith {
-            query_params.push(("document_type__name__istartswith", p));
-        }
-
-        if let Some(p) = fields {
-            query_params.push(("fields", itertools::join(p, ",")));
-        }
-
-        if let Some(p) = full_perms {
-            query_params.push(("full_perms", format!("{p}")));
-        }
-
-        if let Some(p) = has_custom_fields {
-            query_params.push(("has_custom_fields", format!("{p}")));
-        }
-
-        if let Some(p) = id {
-            query_params.push(("id", format!("{p}")));
-        }
-
-        if let Some(p) = id_in {
-            query_params.push(("id__in", itertools::join(p, ",")));
-        }
-
-        if let Some(p) = is_in_inbox {
-            query_params.push(("is_in_inbox", format!("{p}")));
-        }
-
-        if let Some(p) = is_tagged {
-            query_params.push(("is_tagged", format!("{p}")));
-        }
-
-        if let Some(p) = mime_type {
-            query_params.push(("mime_type", p));
-        }
-
-        if let Some(p) = modified_date_gt {
-            query_params.push(("modified__date__gt", format!("{p}")));
-        }
-
-        if let Some(p) = modified_date_gte {
-            query_params.push(("modified__date__gte", format!("{p}")));
-        }
-
-        if let Some(p) = modified_date_lt {
-            query_params.push(("modified__date__lt", format!("{p}")));
-        }
-
-        if let Some(p) = modified_date_lte {
-            query_params.push(("modified__date__lte", format!("{p}")));
-        }
-
-        if let Some(p) = modified_day {
-            query_params.push(("modified__day", format!("{p}")));
-        }
-
-        if let Some(p) = modified_gt {
-            query_params.push(("modified__gt", format!("{p}")));
-        }
-
-        if let Some(p) = modified_gte {
-            query_params.push(("modified__gte", format!("{p}")));
-        }
-
-        if let Some(p) = modified_lt {
-            query_params.push(("modified__lt", format!("{p}")));
-        }
-
-        if let Some(p) = modified_lte {
-            query_params.push(("modified__lte", format!("{p}")));
-        }
-
-        if let Some(p) = modified_month {
-            query_params.push(("modified__month", format!("{p}")));
-        }
-
-        if let Some(p) = modified_year {
-            query_params.push(("modified__year", format!("{p}")));
-        }
-
-        if let Some(p) = ordering {
-            query_params.push(("ordering", p));
-        }
-
-        if let Some(p) = original_filename_icontains {
-            query_params.push(("original_filename__icontains", p));
-        }
-
-        if let Some(p) = original_filename_iendswith {
-            query_params.push(("original_filename__iendswith", p));
-        }
-
-        if let Some(p) = original_filename_iexact {
-            query_params.push(("original_filename__iexact", p));
-        }
-
-        if let Some(p) = original_filename_istartswith {
-            query_params.push(("original_filename__istartswith", p));
-        }
-
-        if let Some(p) = owner_id {
-            query_params.push(("owner__id", format!("{p}")));
-        }
-
-        if let Some(p) = owner_id_in {
-            query_params.push(("owner__id__in", itertools::join(p, ",")));
-        }
-
-        if let Some(p) = owner_id_none {
-            query_params.push(("owner__id__none", format!("{p}")));
-        }
-
-        if let Some(p) = owner_isnull {
-            query_params.push(("owner__isnull", format!("{p}")));
-        }
-
-        if let Some(p) = page {
-            query_params.push(("page", format!("{p}")));
-        }
-
-        if let Some(p) = page_size {
-            query_params.push(("page_size", format!("{p}")));
-        }
-
-        if let Some(p) = query {
-            query_params.push(("query", p));
-        }
-
-        if let Some(p) = search {
-            query_params.push(("search", p));
-        }
-
-        if let Some(p) = shared_by_id {
-            query_params.push(("shared_by__id", format!("{p}")));
-        }
-
-        if let Some(p) = storage_path_id {
-            query_params.push(("storage_path__id", format!("{p}")));
-        }
-
-        if let Some(p) = storage_path_id_in {
-            query_params.push(("storage_path__id__in", itertools::join(p, ",")));
-        }
-
-        if let Some(p) = storage_path_id_none {
-            query_params.push(("storage_path__id__none", format!("{p}")));
-        }
-
-        if let Some(p) = storage_path_isnull {
-            query_params.push(("storage_path__isnull", format!("{p}")));
-        }
-
-        if let Some(p) = storage_path_name_icontains {
-            query_params.push(("storage_path__name__icontains", p));
-        }
-
-        if let Some(p) = storage_path_name_iendswith {
-            query_params.push(("storage_path__name__iendswith", p));
-        }
-
-        if let Some(p) = storage_path_name_iexact {
-            query_params.push(("storage_path__name__iexact", p));
-        }
-
-        if let Some(p) = storage_path_name_istartswith {
-            query_params.push(("storage_path__name__istartswith", p));
-        }
-
-        if let Some(p) = tags_id {
-            query_params.push(("tags__id", format!("{p}")));
-        }
-
-        if let Some(p) = tags_id_all {
-            query_params.push(("tags__id__all", format!("{p}")));
-        }
-
-        if let Some(p) = tags_id_in {
-            query_params.push(("tags__id__in", format!("{p}")));
-        }
-
-        if let Some(p) = tags_id_none {
-            query_params.push(("tags__id__none", format!("{p}")));
-        }
-
-        if let Some(p) = tags_name_icontains {
-            query_params.push(("tags__name__icontains", p));
-        }
-
-        if let Some(p) = tags_name_iendswith {
-            query_params.push(("tags__name__iendswith", p));
-        }
-
-        if let Some(p) = tags_name_iexact {
-            query_params.push(("tags__name__iexact", p));
-        }
-
-        if let Some(p) = tags_name_istartswith {
-            query_params.push(("tags__name__istartswith", p));
-        }
-
-        if let Some(p) = title_icontains {
-            query_params.push(("title__icontains", p));
-        }
-
-        if let Some(p) = title_iendswith {
-            query_params.push(("title__iendswith", p));
-        }
-
-        if let Some(p) = title_iexact {
-            query_params.push(("title__iexact", p));
-        }
-
-        if let Some(p) = title_istartswith {
-            query_params.push(("title__istartswith", p));
-        }
-
-        if let Some(p) = title_content {
-            query_params.push(("title_content", p));
-        }
-
-        req = req.query(&query_params);
-        let resp = req.send().await?;
-        let status = resp.status();
-        if status.is_success() {
-            let text = resp.text().await.unwrap_or_default();
-            serde_json::from_str(&text).map_err(|err| {
-                crate::types::error::Error::from_serde_error(
-                    format_serde_error::SerdeError::new(text.to_string(), err),
-                    status,
-                )
-            })
-        } else {
-            let text = resp.text().await.unwrap_or_default();
-            Err(crate::types::error::Error::Server {
-                body: text.to_string(),
-                status,
-            })
-        }
+        self.list_with_query(&crate::types::DocumentQuery {
+            added_date_gt,
+            added_date_gte,
+            added_date_lt,
+            added_date_lte,
+            added_day,
+            added_gt,
+            added_gte,
+            added_lt,
+            added_lte,
+            added_month,
+            added_year,
+            archive_serial_number,
+            archive_serial_number_gt,
+            archive_serial_number_gte,
+            archive_serial_number_isnull,
+            archive_serial_number_lt,
+            archive_serial_number_lte,
+            checksum_icontains,
+            checksum_iendswith,
+            checksum_iexact,
+            checksum_istartswith,
+            content_icontains,
+            content_iendswith,
+            content_iexact,
+            content_istartswith,
+            correspondent_id,
+            correspondent_id_in,
+            correspondent_id_none: correspondent_id_none.map(|p| vec![p]),
+            correspondent_isnull,
+            correspondent_name_icontains,
+            correspondent_name_iendswith,
+            correspondent_name_iexact,
+            correspondent_name_istartswith,
+            created_date_gt,
+            created_date_gte,
+            created_date_lt,
+            created_date_lte,
+            created_day,
+            created_gt,
+            created_gte,
+            created_lt,
+            created_lte,
+            created_month,
+            created_year,
+            custom_field_query,
+            custom_fields_icontains,
+            custom_fields_id_all: custom_fields_id_all.map(|p| vec![p]),
+            custom_fields_id_in: custom_fields_id_in.map(|p| vec![p]),
+            custom_fields_id_none: custom_fields_id_none.map(|p| vec![p]),
+            document_type_id,
+            document_type_id_in,
+            document_type_id_none: document_type_id_none.map(|p| vec![p]),
+            document_type_isnull,
+            document_type_name_icontains,
+            document_type_name_iendswith,
+            document_type_name_iexact,
+            document_type_name_istartswith,
+            fields,
+            full_perms,
+            has_custom_fields,
+            id,
+            id_in,
+            is_in_inbox,
+            is_tagged,
+            mime_type,
+            modified_date_gt,
+            modified_date_gte,
+            modified_date_lt,
+            modified_date_lte,
+            modified_day,
+            modified_gt,
+            modified_gte,
+            modified_lt,
+            modified_lte,
+            modified_month,
+            modified_year,
+            ordering,
+            original_filename_icontains,
+            original_filename_iendswith,
+            original_filename_iexact,
+            original_filename_istartswith,
+            owner_id,
+            owner_id_in,
+            owner_id_none: owner_id_none.map(|p| vec![p]),
+            owner_isnull,
+            page,
+            page_size,
+            query,
+            search,
+            shared_by_id,
+            storage_path_id,
+            storage_path_id_in,
+            storage_path_id_none: storage_path_id_none.map(|p| vec![p]),
+            storage_path_isnull,
+            storage_path_name_icontains,
+            storage_path_name_iendswith,
+            storage_path_name_iexact,
+            storage_path_name_istartswith,
+            tags_id,
+            tags_id_all: tags_id_all.map(|p| vec![p]),
+            tags_id_in: tags_id_in.map(|p| vec![p]),
+            tags_id_none: tags_id_none.map(|p| vec![p]),
+            tags_name_icontains,
+            tags_name_iendswith,
+            tags_name_iexact,
+            tags_name_istartswith,
+            title_icontains,
+            title_iendswith,
+            title_iexact,
+            title_istartswith,
+            title_content,
+        })
+        .await
     }
 
     #[doc = "Perform a `GET` request to `/api/documents/`.\n\nDocument views including search\n\n**Parameters:**\n\n- `added_date_gt: Option<chrono::NaiveDate>`\n- `added_date_gte: Option<chrono::NaiveDate>`\n- `added_date_lt: Option<chrono::NaiveDate>`\n- `added_date_lte: Option<chrono::NaiveDate>`\n- `added_day: Option<f64>`\n- `added_gt: Option<chrono::DateTime<chrono::Utc>>`\n- `added_gte: Option<chrono::DateTime<chrono::Utc>>`\n- `added_lt: Option<chrono::DateTime<chrono::Utc>>`\n- `added_lte: Option<chrono::DateTime<chrono::Utc>>`\n- `added_month: Option<f64>`\n- `added_year: Option<f64>`\n- `archive_serial_number: Option<i64>`\n- `archive_serial_number_gt: Option<i64>`\n- `archive_serial_number_gte: Option<i64>`\n- `archive_serial_number_isnull: Option<bool>`\n- `archive_serial_number_lt: Option<i64>`\n- `archive_serial_number_lte: Option<i64>`\n- `checksum_icontains: Option<String>`\n- `checksum_iendswith: Option<String>`\n- `checksum_iexact: Option<String>`\n- `checksum_istartswith: Option<String>`\n- `content_icontains: Option<String>`\n- `content_iendswith: Option<String>`\n- `content_iexact: Option<String>`\n- `content_istartswith: Option<String>`\n- `correspondent_id: Option<i64>`\n- `correspondent_id_in: Option<Vec<i64>>`: Multiple values may be separated by commas.\n- `correspondent_id_none: Option<i64>`\n- `correspondent_isnull: Option<bool>`\n- `correspondent_name_icontains: Option<String>`\n- `correspondent_name_iendswith: Option<String>`\n- `correspondent_name_iexact: Option<String>`\n- `correspondent_name_istartswith: Option<String>`\n- `created_date_gt: Option<chrono::NaiveDate>`\n- `created_date_gte: Option<chrono::NaiveDate>`\n- `created_date_lt: Option<chrono::NaiveDate>`\n- `created_date_lte: Option<chrono::NaiveDate>`\n- `created_day: Option<f64>`\n- `created_gt: Option<chrono::NaiveDate>`\n- `created_gte: Option<chrono::NaiveDate>`\n- `created_lt: Option<chrono::NaiveDate>`\n- `created_lte: Option<chrono::NaiveDate>`\n- `created_month: Option<f64>`\n- `created_year: Option<f64>`\n- `custom_field_query: Option<String>`\n- `custom_fields_icontains: Option<String>`\n- `custom_fields_id_all: Option<i64>`\n- `custom_fields_id_in: Option<i64>`\n- `custom_fields_id_none: Option<i64>`\n- `document_type_id: Option<i64>`\n- `document_type_id_in: Option<Vec<i64>>`: Multiple values may be separated by commas.\n- `document_type_id_none: Option<i64>`\n- `document_type_isnull: Option<bool>`\n- `document_type_name_icontains: Option<String>`\n- `document_type_name_iendswith: Option<String>`\n- `document_type_name_iexact: Option<String>`\n- `document_type_name_istartswith: Option<String>`\n- `fields: Option<Vec<String>>`\n- `full_perms: Option<bool>`\n- `has_custom_fields: Option<bool>`: Has custom field\n- `id: Option<i64>`\n- `id_in: Option<Vec<i64>>`: Multiple values may be separated by commas.\n- `is_in_inbox: Option<bool>`\n- `is_tagged: Option<bool>`: Is tagged\n- `mime_type: Option<String>`\n- `modified_date_gt: Option<chrono::NaiveDate>`\n- `modified_date_gte: Option<chrono::NaiveDate>`\n- `modified_date_lt: Option<chrono::NaiveDate>`\n- `modified_date_lte: Option<chrono::NaiveDate>`\n- `modified_day: Option<f64>`\n- `modified_gt: Option<chrono::DateTime<chrono::Utc>>`\n- `modified_gte: Option<chrono::DateTime<chrono::Utc>>`\n- `modified_lt: Option<chrono::DateTime<chrono::Utc>>`\n- `modified_lte: Option<chrono::DateTime<chrono::Utc>>`\n- `modified_month: Option<f64>`\n- `modified_year: Option<f64>`\n- `ordering: Option<String>`: Which field to use when ordering the results.\n- `original_filename_icontains: Option<String>`\n- `original_filename_iendswith: Option<String>`\n- `original_filename_iexact: Option<String>`\n- `original_filename_istartswith: Option<String>`\n- `owner_id: Option<i64>`\n- `owner_id_in: Option<Vec<i64>>`: Multiple values may be separated by commas.\n- `owner_id_none: Option<i64>`\n- `owner_isnull: Option<bool>`\n- `page: Option<i64>`: A page number within the paginated result set.\n- `page_size: Option<i64>`: Number of results to return per page.\n- `query: Option<String>`: Advanced search query string\n- `search: Option<String>`: A search term.\n- `shared_by_id: Option<bool>`\n- `storage_path_id: Option<i64>`\n- `storage_path_id_in: Option<Vec<i64>>`: Multiple values may be separated by commas.\n- `storage_path_id_none: Option<i64>`\n- `storage_path_isnull: Option<bool>`\n- `storage_path_name_icontains: Option<String>`\n- `storage_path_name_iendswith: Option<String>`\n- `storage_path_name_iexact: Option<String>`\n- `storage_path_name_istartswith: Option<String>`\n- `tags_id: Option<i64>`\n- `tags_id_all: Option<i64>`\n- `tags_id_in: Option<i64>`\n- `tags_id_none: Option<i64>`\n- `tags_name_icontains: Option<String>`\n- `tags_name_iendswith: Option<String>`\n- `tags_name_iexact: Option<String>`\n- `tags_name_istartswith: Option<String>`\n- `title_icontains: Option<String>`\n- `title_iendswith: Option<String>`\n- `title_iexact: Option<String>`\n- `title_istartswith: Option<String>`\n- `title_content: Option<String>`\n\n```rust,no_run\nuse futures_util::TryStreamExt;\nasync fn example_documents_list_stream() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let mut documents = client.documents();\n    let mut stream = documents.list_stream(\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(3.14 as f64),\n        Some(chrono::Utc::now()),\n        Some(chrono::Utc::now()),\n        Some(chrono::Utc::now()),\n        Some(chrono::Utc::now()),\n        Some(3.14 as f64),\n        Some(3.14 as f64),\n        Some(4 as i64),\n        Some(4 as i64),\n        Some(4 as i64),\n        Some(true),\n        Some(4 as i64),\n        Some(4 as i64),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(4 as i64),\n        Some(vec![4 as i64]),\n        Some(4 as i64),\n        Some(true),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(3.14 as f64),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(3.14 as f64),\n        Some(3.14 as f64),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(4 as i64),\n        Some(4 as i64),\n        Some(4 as i64),\n        Some(4 as i64),\n        Some(vec![4 as i64]),\n        Some(4 as i64),\n        Some(true),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(vec![\"some-string\".to_string()]),\n        Some(true),\n        Some(true),\n        Some(4 as i64),\n        Some(vec![4 as i64]),\n        Some(true),\n        Some(true),\n        Some(\"some-string\".to_string()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(chrono::Utc::now().date_naive()),\n        Some(3.14 as f64),\n        Some(chrono::Utc::now()),\n        Some(chrono::Utc::now()),\n        Some(chrono::Utc::now()),\n        Some(chrono::Utc::now()),\n        Some(3.14 as f64),\n        Some(3.14 as f64),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(4 as i64),\n        Some(vec![4 as i64]),\n        Some(4 as i64),\n        Some(true),\n        Some(4 as i64),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(true),\n        Some(4 as i64),\n        Some(vec![4 as i64]),\n        Some(4 as i64),\n        Some(true),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(4 as i64),\n        Some(4 as i64),\n        Some(4 as i64),\n        Some(4 as i64),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n        Some(\"some-string\".to_string()),\n    );\n    loop {\n        match stream.try_next().await {\n            Ok(Some(item)) => {\n                println!(\"{:?}\", item);\n            }\n            Ok(None) => {\n                break;\n            }\n            Err(err) => {\n                return Err(err.into());\n            }\n        }\n    }\n\n    Ok(())\n}\n```"]
@@ -717,174 +367,174 @@ impl Documents {
     ) -> impl futures::Stream<Item = Result<crate::types::Document, crate::types::error::Error>>
            + Unpin
            + '_ {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(
-            added__date__gt,
-            added__date__gte,
-            added__date__lt,
-            added__date__lte,
-            added__day,
-            added__gt,
-            added__gte,
-            added__lt,
-            added__lte,
-            added__month,
-            added__year,
+        self.list_stream_with_query(crate::types::DocumentQuery {
+            added_date_gt: added__date__gt,
+            added_date_gte: added__date__gte,
+            added_date_lt: added__date__lt,
+            added_date_lte: added__date__lte,
+            added_day: added__day,
+            added_gt: added__gt,
+            added_gte: added__gte,
+            added_lt: added__lt,
+            added_lte: added__lte,
+            added_month: added__month,
+            added_year: added__year,
             archive_serial_number,
-            archive_serial_number__gt,
-            archive_serial_number__gte,
-            archive_serial_number__isnull,
-            archive_serial_number__lt,
-            archive_serial_number__lte,
-            checksum__icontains,
-            checksum__iendswith,
-            checksum__iexact,
-            checksum__istartswith,
-            content__icontains,
-            content__iendswith,
-            content__iexact,
-            content__istartswith,
-            correspondent__id,
-            correspondent__id__in,
-            correspondent__id__none,
-            correspondent__isnull,
-            correspondent__name__icontains,
-            correspondent__name__iendswith,
-            correspondent__name__iexact,
-            correspondent__name__istartswith,
-            created__date__gt,
-            created__date__gte,
-            created__date__lt,
-            created__date__lte,
-            created__day,
-            created__gt,
-            created__gte,
-            created__lt,
-            created__lte,
-            created__month,
-            created__year,
+            archive_serial_number_gt: archive_serial_number__gt,
+            archive_serial_number_gte: archive_serial_number__gte,
+            archive_serial_number_isnull: archive_serial_number__isnull,
+            archive_serial_number_lt: archive_serial_number__lt,
+            archive_serial_number_lte: archive_serial_number__lte,
+            checksum_icontains: checksum__icontains,
+            checksum_iendswith: checksum__iendswith,
+            checksum_iexact: checksum__iexact,
+            checksum_istartswith: checksum__istartswith,
+            content_icontains: content__icontains,
+            content_iendswith: content__iendswith,
+            content_iexact: content__iexact,
+            content_istartswith: content__istartswith,
+            correspondent_id: correspondent__id,
+            correspondent_id_in: correspondent__id__in,
+            correspondent_id_none: correspondent__id__none.map(|p| vec![p]),
+            correspondent_isnull: correspondent__isnull,
+            correspondent_name_icontains: correspondent__name__icontains,
+            correspondent_name_iendswith: correspondent__name__iendswith,
+            correspondent_name_iexact: correspondent__name__iexact,
+            correspondent_name_istartswith: correspondent__name__istartswith,
+            created_date_gt: created__date__gt,
+            created_date_gte: created__date__gte,
+            created_date_lt: created__date__lt,
+            created_date_lte: created__date__lte,
+            created_day: created__day,
+            created_gt: created__gt,
+            created_gte: created__gte,
+            created_lt: created__lt,
+            created_lte: created__lte,
+            created_month: created__month,
+            created_year: created__year,
             custom_field_query,
-            custom_fields__icontains,
-            custom_fields__id__all,
-            custom_fields__id__in,
-            custom_fields__id__none,
-            document_type__id,
-            document_type__id__in,
-            document_type__id__none,
-            document_type__isnull,
-            document_type__name__icontains,
-            document_type__name__iendswith,
-            document_type__name__iexact,
-            document_type__name__istartswith,
+            custom_fields_icontains: custom_fields__icontains,
+            custom_fields_id_all: custom_fields__id__all.map(|p| vec![p]),
+            custom_fields_id_in: custom_fields__id__in.map(|p| vec![p]),
+            custom_fields_id_none: custom_fields__id__none.map(|p| vec![p]),
+            document_type_id: document_type__id,
+            document_type_id_in: document_type__id__in,
+            document_type_id_none: document_type__id__none.map(|p| vec![p]),
+            document_type_isnull: document_type__isnull,
+            document_type_name_icontains: document_type__name__icontains,
+            document_type_name_iendswith: document_type__name__iendswith,
+            document_type_name_iexact: document_type__name__iexact,
+            document_type_name_istartswith: document_type__name__istartswith,
             fields,
             full_perms,
             has_custom_fields,
             id,
-            id__in,
+            id_in: id__in,
             is_in_inbox,
             is_tagged,
             mime_type,
-            modified__date__gt,
-            modified__date__gte,
-            modified__date__lt,
-            modified__date__lte,
-            modified__day,
-            modified__gt,
-            modified__gte,
-            modified__lt,
-            modified__lte,
-            modified__month,
-            modified__year,
+            modified_date_gt: modified__date__gt,
+            modified_date_gte: modified__date__gte,
+            modified_date_lt: modified__date__lt,
+            modified_date_lte: modified__date__lte,
+            modified_day: modified__day,
+            modified_gt: modified__gt,
+            modified_gte: modified__gte,
+            modified_lt: modified__lt,
+            modified_lte: modified__lte,
+            modified_month: modified__month,
+            modified_year: modified__year,
             ordering,
-            original_filename__icontains,
-            original_filename__iendswith,
-            original_filename__iexact,
-            original_filename__istartswith,
-            owner__id,
-            owner__id__in,
-            owner__id__none,
-            owner__isnull,
-            None,
+            original_filename_icontains: original_filename__icontains,
+            original_filename_iendswith: original_filename__iendswith,
+            original_filename_iexact: original_filename__iexact,
+            original_filename_istartswith: original_filename__istartswith,
+            owner_id: owner__id,
+            owner_id_in: owner__id__in,
+            owner_id_none: owner__id__none.map(|p| vec![p]),
+            owner_isnull: owner__isnull,
+            page: None,
             page_size,
             query,
             search,
-            shared_by__id,
-            storage_path__id,
-            storage_path__id__in,
-            storage_path__id__none,
-            storage_path__isnull,
-            storage_path__name__icontains,
-            storage_path__name__iendswith,
-            storage_path__name__iexact,
-            storage_path__name__istartswith,
-            tags__id,
-            tags__id__all,
-            tags__id__in,
-            tags__id__none,
-            tags__name__icontains,
-            tags__name__iendswith,
-            tags__name__iexact,
-            tags__name__istartswith,
-            title__icontains,
-            title__iendswith,
-            title__iexact,
-            title__istartswith,
+            shared_by_id: shared_by__id,
+            storage_path_id: storage_path__id,
+            storage_path_id_in: storage_path__id__in,
+            storage_path_id_none: storage_path__id__none.map(|p| vec![p]),
+            storage_path_isnull: storage_path__isnull,
+            storage_path_name_icontains: storage_path__name__icontains,
+            storage_path_name_iendswith: storage_path__name__iendswith,
+            storage_path_name_iexact: storage_path__name__iexact,
+            storage_path_name_istartswith: storage_path__name__istartswith,
+            tags_id: tags__id,
+            tags_id_all: tags__id__all.map(|p| vec![p]),
+            tags_id_in: tags__id__in.map(|p| vec![p]),
+            tags_id_none: tags__id__none.map(|p| vec![p]),
+            tags_name_icontains: tags__name__icontains,
+            tags_name_iendswith: tags__name__iendswith,
+            tags_name_iexact: tags__name__iexact,
+            tags_name_istartswith: tags__name__istartswith,
+            title_icontains: title__icontains,
+            title_iendswith: title__iendswith,
+            title_iexact: title__iexact,
+            title_istartswith: title__istartswith,
             title_content,
-        )
-        .map_ok(move |result| {
-            let items = futures::stream::iter(result.items().into_iter().map(Ok));
-            let next_pages = futures::stream::try_unfold(
-                (None, result),
-                move |(prev_page_token, new_result)| async move {
-                    if new_result.has_more_pages()
-                        && !new_result.items().is_empty()
-                        && prev_page_token != new_result.next_page_token()
-                    {
-                        async {
-                            let mut req = self.client.client.request(
-                                http::Method::GET,
-                                format!("{}/{}", self.client.base_url, "api/documents/"),
-                            );
-                            req = req
-                                .header("Authorization", format!("Token {}", &self.client.token));
-                            let mut request = req.build()?;
-                            request = new_result.next_page(request)?;
-                            let resp = self.client.client.execute(request).await?;
-                            let status = resp.status();
-                            if status.is_success() {
-                                let text = resp.text().await.unwrap_or_default();
-                                serde_json::from_str(&text).map_err(|err| {
-                                    crate::types::error::Error::from_serde_error(
-                                        format_serde_error::SerdeError::new(text.to_string(), err),
-                                        status,
-                                    )
-                                })
-                            } else {
-                                let text = resp.text().await.unwrap_or_default();
-                                Err(crate::types::error::Error::Server {
-                                    body: text.to_string(),
-                                    status,
-                                })
-                            }
-                        }
-                        .map_ok(|result: crate::types::PaginatedDocumentList| {
-                            Some((
-                                futures::stream::iter(result.items().into_iter().map(Ok)),
-                                (new_result.next_page_token(), result),
-                            ))
-                        })
-                        .await
-                    } else {
-                        Ok(None)
-                    }
-                },
-            )
-            .try_flatten();
-            items.chain(next_pages)
         })
-        .try_flatten_stream()
-        .boxed()
+    }
+
+    #[doc = "Perform a `GET` request to `/api/documents/` with the filters of a [`DocumentQuery`](crate::types::DocumentQuery).\n\nDocument views including search\n\n**Parameters:**\n\n- `query: &crate::types::DocumentQuery`: Filters, ordering and paging of the request.\n\n```rust,no_run\nasync fn example_documents_list_with_query() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let query = paperless_api_client::types::DocumentQuery::new()\n        .tags_all([1, 2])\n        .correspondent_name(\"ACME Inc.\")\n        .created_in(chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()..)\n        .order_by_desc(\"created\")\n        .page_size(50);\n    let result: paperless_api_client::types::PaginatedDocumentList =\n        client.documents().list_with_query(&query).await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn list_with_query<'a>(
+        &'a self,
+        query: &crate::types::DocumentQuery,
+    ) -> Result<crate::types::PaginatedDocumentList, crate::types::error::Error> {
+        if query.custom_field_query.is_some() {
+            self.client
+                .require_capability(crate::types::Capability::CustomFieldQuery)
+                .await?;
+        }
+        let mut req = self.client.client.request(
+            http::Method::GET,
+            format!("{}/{}", self.client.base_url, "api/documents/"),
+        );
+        req = self.client.authenticate(req).await?;
+        req = req.query(&query.query_params());
+        let resp = req.send().await?;
+        let status = resp.status();
+        if status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            serde_json::from_str(&text).map_err(|err| {
+                crate::types::error::Error::from_serde_error(
+                    format_serde_error::SerdeError::new(text.to_string(), err),
+                    status,
+                )
+            })
+        } else {
+            let text = resp.text().await.unwrap_or_default();
+            Err(crate::types::error::Error::Server {
+                body: text.to_string(),
+                status,
+            })
+        }
+    }
+
+    #[doc = "Perform a `GET` request to `/api/documents/` with the filters of a [`DocumentQuery`](crate::types::DocumentQuery), following every page of the result.\n\nThe `page` of the query is ignored, the stream always starts at the first page.\n\n**Parameters:**\n\n- `query: crate::types::DocumentQuery`: Filters, ordering and page size of the request.\n\n```rust,no_run\nuse futures_util::TryStreamExt;\nasync fn example_documents_list_stream_with_query() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let documents = client.documents();\n    let query = paperless_api_client::types::DocumentQuery::new()\n        .tags_none([4])\n        .without_correspondent();\n    let mut stream = documents.list_stream_with_query(query);\n    while let Some(item) = stream.try_next().await? {\n        println!(\"{:?}\", item);\n    }\n\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    #[cfg(not(feature = "js"))]
+    pub fn list_stream_with_query<'a>(
+        &'a self,
+        query: crate::types::DocumentQuery,
+    ) -> impl futures::Stream<Item = Result<crate::types::Document, crate::types::error::Error>>
+           + Unpin
+           + '_ {
+        let query = crate::types::DocumentQuery {
+            page: None,
+            ..query
+        };
+        crate::paginator::list_items(
+            &self.client,
+            async move { self.list_with_query(&query).await },
+        )
     }
 
     #[doc = "Perform a `GET` request to `/api/documents/{doc_id}/`.\n\nRetrieve a single document\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `fields: Option<Vec<String>>`\n- `full_perms: Option<bool>`\n\n```rust,no_run\nasync fn example_documents_retrieve() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Document = client\n        .documents()\n        .retrieve(4 as i64, Some(vec![\"some-string\".to_string()]), Some(true))\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
@@ -904,7 +554,7 @@ impl Documents {
                 "api/documents/{doc_id}/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = fields {
             query_params.push(("fields", itertools::join(p, ",")));
@@ -950,7 +600,7 @@ impl Documents {
                 "api/documents/{doc_id}/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -983,7 +633,7 @@ impl Documents {
                 "api/documents/{doc_id}/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -1013,7 +663,7 @@ impl Documents {
                 "api/documents/{doc_id}/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -1034,14 +684,14 @@ impl Documents {
         }
     }
 
-    #[doc = "Perform a `GET` request to `/api/documents/{doc_id}/download/`.\n\nDownload the document\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `original: Option<bool>`\n\n```rust,no_run\nasync fn example_documents_download_retrieve() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: bytes::Bytes = client\n        .documents()\n        .download_retrieve(4 as i64, Some(true))\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `GET` request to `/api/documents/{doc_id}/download/`.\n\nDownload the document\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `original: Option<bool>`\n\n```rust,no_run\nasync fn example_documents_download_retrieve() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::download::DownloadedFile = client\n        .documents()\n        .download_retrieve(4 as i64, Some(true))\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn download_retrieve<'a>(
         &'a self,
         doc_id: i64,
         original: Option<bool>,
-    ) -> Result<bytes::Bytes, crate::types::error::Error> {
+    ) -> Result<crate::types::download::DownloadedFile, crate::types::error::Error> {
         let mut req = self.client.client.request(
             http::Method::GET,
             format!(
@@ -1050,7 +700,7 @@ impl Documents {
                 "api/documents/{doc_id}/download/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = original {
             query_params.push(("original", format!("{p}")));
@@ -1060,13 +710,43 @@ impl Documents {
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
+            crate::types::download::DownloadedFile::from_response(resp).await
+        } else {
             let text = resp.text().await.unwrap_or_default();
-            serde_json::from_str(&text).map_err(|err| {
-                crate::types::error::Error::from_serde_error(
-                    format_serde_error::SerdeError::new(text.to_string(), err),
-                    status,
-                )
+            Err(crate::types::error::Error::Server {
+                body: text.to_string(),
+                status,
             })
+        }
+    }
+
+    #[doc = "Perform a `GET` request to `/api/documents/{doc_id}/download/`, streaming the file instead of buffering it.\n\nDownload the document\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `original: Option<bool>`\n\n```rust,no_run\nuse futures_util::TryStreamExt;\nasync fn example_documents_download_stream() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let mut stream = client\n        .documents()\n        .download_stream(4 as i64, Some(true))\n        .await?;\n    println!(\"{:?} {:?}\", stream.filename, stream.content_type);\n    while let Some(chunk) = stream.try_next().await? {\n        println!(\"{} bytes\", chunk.len());\n    }\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    #[cfg(not(feature = "js"))]
+    pub async fn download_stream<'a>(
+        &'a self,
+        doc_id: i64,
+        original: Option<bool>,
+    ) -> Result<crate::types::download::DownloadStream, crate::types::error::Error> {
+        let mut req = self.client.client.request(
+            http::Method::GET,
+            format!(
+                "{}/{}",
+                self.client.base_url,
+                "api/documents/{doc_id}/download/".replace("{doc_id}", &format!("{doc_id}"))
+            ),
+        );
+        req = self.client.authenticate(req).await?;
+        let mut query_params = vec![];
+        if let Some(p) = original {
+            query_params.push(("original", format!("{p}")));
+        }
+
+        req = req.query(&query_params);
+        let resp = req.send().await?;
+        let status = resp.status();
+        if status.is_success() {
+            Ok(crate::types::download::DownloadStream::from_response(resp))
         } else {
             let text = resp.text().await.unwrap_or_default();
             Err(crate::types::error::Error::Server {
@@ -1092,7 +772,7 @@ impl Documents {
                 "api/documents/{doc_id}/email/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -1130,7 +810,7 @@ impl Documents {
                 "api/documents/{doc_id}/history/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = page {
             query_params.push(("page", format!("{p}")));
@@ -1171,72 +851,7 @@ impl Documents {
     ) -> impl futures::Stream<Item = Result<crate::types::LogEntry, crate::types::error::Error>>
            + Unpin
            + '_ {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.history_list(doc_id, None, page_size)
-            .map_ok(move |result| {
-                let items = futures::stream::iter(result.items().into_iter().map(Ok));
-                let next_pages = futures::stream::try_unfold(
-                    (None, result),
-                    move |(prev_page_token, new_result)| async move {
-                        if new_result.has_more_pages()
-                            && !new_result.items().is_empty()
-                            && prev_page_token != new_result.next_page_token()
-                        {
-                            async {
-                                let mut req = self.client.client.request(
-                                    http::Method::GET,
-                                    format!(
-                                        "{}/{}",
-                                        self.client.base_url,
-                                        "api/documents/{doc_id}/history/"
-                                            .replace("{doc_id}", &format!("{}", doc_id))
-                                    ),
-                                );
-                                req = req.header(
-                                    "Authorization",
-                                    format!("Token {}", &self.client.token),
-                                );
-                                let mut request = req.build()?;
-                                request = new_result.next_page(request)?;
-                                let resp = self.client.client.execute(request).await?;
-                                let status = resp.status();
-                                if status.is_success() {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    serde_json::from_str(&text).map_err(|err| {
-                                        crate::types::error::Error::from_serde_error(
-                                            format_serde_error::SerdeError::new(
-                                                text.to_string(),
-                                                err,
-                                            ),
-                                            status,
-                                        )
-                                    })
-                                } else {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    Err(crate::types::error::Error::Server {
-                                        body: text.to_string(),
-                                        status,
-                                    })
-                                }
-                            }
-                            .map_ok(|result: crate::types::PaginatedLogEntryList| {
-                                Some((
-                                    futures::stream::iter(result.items().into_iter().map(Ok)),
-                                    (new_result.next_page_token(), result),
-                                ))
-                            })
-                            .await
-                        } else {
-                            Ok(None)
-                        }
-                    },
-                )
-                .try_flatten();
-                items.chain(next_pages)
-            })
-            .try_flatten_stream()
-            .boxed()
+        crate::paginator::list_items(&self.client, self.history_list(doc_id, None, page_size))
     }
 
     #[doc = "Perform a `GET` request to `/api/documents/{doc_id}/metadata/`.\n\nView the document metadata\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n\n```rust,no_run\nasync fn example_documents_metadata_retrieve() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Metadata = client.documents().metadata_retrieve(4 as i64).await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
@@ -1254,7 +869,7 @@ impl Documents {
                 "api/documents/{doc_id}/metadata/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -1292,7 +907,7 @@ impl Documents {
                 "api/documents/{doc_id}/notes/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = id {
             query_params.push(("id", format!("{p}")));
@@ -1337,72 +952,7 @@ impl Documents {
         page_size: Option<i64>,
     ) -> impl futures::Stream<Item = Result<crate::types::Notes, crate::types::error::Error>> + Unpin + '_
     {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.notes_list(doc_id, id, None, page_size)
-            .map_ok(move |result| {
-                let items = futures::stream::iter(result.items().into_iter().map(Ok));
-                let next_pages = futures::stream::try_unfold(
-                    (None, result),
-                    move |(prev_page_token, new_result)| async move {
-                        if new_result.has_more_pages()
-                            && !new_result.items().is_empty()
-                            && prev_page_token != new_result.next_page_token()
-                        {
-                            async {
-                                let mut req = self.client.client.request(
-                                    http::Method::GET,
-                                    format!(
-                                        "{}/{}",
-                                        self.client.base_url,
-                                        "api/documents/{doc_id}/notes/"
-                                            .replace("{doc_id}", &format!("{}", doc_id))
-                                    ),
-                                );
-                                req = req.header(
-                                    "Authorization",
-                                    format!("Token {}", &self.client.token),
-                                );
-                                let mut request = req.build()?;
-                                request = new_result.next_page(request)?;
-                                let resp = self.client.client.execute(request).await?;
-                                let status = resp.status();
-                                if status.is_success() {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    serde_json::from_str(&text).map_err(|err| {
-                                        crate::types::error::Error::from_serde_error(
-                                            format_serde_error::SerdeError::new(
-                                                text.to_string(),
-                                                err,
-                                            ),
-                                            status,
-                                        )
-                                    })
-                                } else {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    Err(crate::types::error::Error::Server {
-                                        body: text.to_string(),
-                                        status,
-                                    })
-                                }
-                            }
-                            .map_ok(|result: crate::types::PaginatedNotesList| {
-                                Some((
-                                    futures::stream::iter(result.items().into_iter().map(Ok)),
-                                    (new_result.next_page_token(), result),
-                                ))
-                            })
-                            .await
-                        } else {
-                            Ok(None)
-                        }
-                    },
-                )
-                .try_flatten();
-                items.chain(next_pages)
-            })
-            .try_flatten_stream()
-            .boxed()
+        crate::paginator::list_items(&self.client, self.notes_list(doc_id, id, None, page_size))
     }
 
     #[doc = "Perform a `POST` request to `/api/documents/{doc_id}/notes/`.\n\nView, add, or delete notes for the document\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `id: Option<i64>`: Note ID to delete (used only for DELETE requests)\n- `page: Option<i64>`: A page number within the paginated result set.\n- `page_size: Option<i64>`: Number of results to return per page.\n\n```rust,no_run\nasync fn example_documents_notes_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::PaginatedNotesList = client\n        .documents()\n        .notes_create(\n            4 as i64,\n            Some(4 as i64),\n            Some(4 as i64),\n            Some(4 as i64),\n            &paperless_api_client::types::NoteCreateRequestRequest {\n                note: \"some-string\".to_string(),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
@@ -1424,7 +974,7 @@ impl Documents {
                 "api/documents/{doc_id}/notes/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = id {
             query_params.push(("id", format!("{p}")));
@@ -1477,7 +1027,7 @@ impl Documents {
                 "api/documents/{doc_id}/notes/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = id {
             query_params.push(("id", format!("{p}")));
@@ -1511,13 +1061,13 @@ impl Documents {
         }
     }
 
-    #[doc = "Perform a `GET` request to `/api/documents/{doc_id}/preview/`.\n\nView the document preview\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n\n```rust,no_run\nasync fn example_documents_preview_retrieve() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: bytes::Bytes = client.documents().preview_retrieve(4 as i64).await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `GET` request to `/api/documents/{doc_id}/preview/`.\n\nView the document preview\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n\n```rust,no_run\nasync fn example_documents_preview_retrieve() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::download::DownloadedFile = client.documents().preview_retrieve(4 as i64).await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn preview_retrieve<'a>(
         &'a self,
         doc_id: i64,
-    ) -> Result<bytes::Bytes, crate::types::error::Error> {
+    ) -> Result<crate::types::download::DownloadedFile, crate::types::error::Error> {
         let mut req = self.client.client.request(
             http::Method::GET,
             format!(
@@ -1526,17 +1076,11 @@ impl Documents {
                 "api/documents/{doc_id}/preview/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
-            let text = resp.text().await.unwrap_or_default();
-            serde_json::from_str(&text).map_err(|err| {
-                crate::types::error::Error::from_serde_error(
-                    format_serde_error::SerdeError::new(text.to_string(), err),
-                    status,
-                )
-            })
+            crate::types::download::DownloadedFile::from_response(resp).await
         } else {
             let text = resp.text().await.unwrap_or_default();
             Err(crate::types::error::Error::Server {
@@ -1561,7 +1105,7 @@ impl Documents {
                 "api/documents/{doc_id}/share_links/".replace("{doc_id}", doc_id)
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -1596,7 +1140,7 @@ impl Documents {
                 "api/documents/{doc_id}/suggestions/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -1616,13 +1160,13 @@ impl Documents {
         }
     }
 
-    #[doc = "Perform a `GET` request to `/api/documents/{doc_id}/thumb/`.\n\nView the document thumbnail\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n\n```rust,no_run\nasync fn example_documents_thumb_retrieve() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: bytes::Bytes = client.documents().thumb_retrieve(4 as i64).await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `GET` request to `/api/documents/{doc_id}/thumb/`.\n\nView the document thumbnail\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n\n```rust,no_run\nasync fn example_documents_thumb_retrieve() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::download::DownloadedFile = client.documents().thumb_retrieve(4 as i64).await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn thumb_retrieve<'a>(
         &'a self,
         doc_id: i64,
-    ) -> Result<bytes::Bytes, crate::types::error::Error> {
+    ) -> Result<crate::types::download::DownloadedFile, crate::types::error::Error> {
         let mut req = self.client.client.request(
             http::Method::GET,
             format!(
@@ -1631,17 +1175,11 @@ impl Documents {
                 "api/documents/{doc_id}/thumb/".replace("{doc_id}", &format!("{doc_id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
-            let text = resp.text().await.unwrap_or_default();
-            serde_json::from_str(&text).map_err(|err| {
-                crate::types::error::Error::from_serde_error(
-                    format_serde_error::SerdeError::new(text.to_string(), err),
-                    status,
-                )
-            })
+            crate::types::download::DownloadedFile::from_response(resp).await
         } else {
             let text = resp.text().await.unwrap_or_default();
             Err(crate::types::error::Error::Server {
@@ -1651,13 +1189,13 @@ impl Documents {
         }
     }
 
-    #[doc = "Perform a `POST` request to `/api/documents/bulk_download/`.\n\n```rust,no_run\nasync fn example_documents_bulk_download_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::BulkDownload = client\n        .documents()\n        .bulk_download_create(&paperless_api_client::types::BulkDownloadRequest {\n            documents: vec![4 as i64],\n            content: Some(paperless_api_client::types::ContentEnum::Originals),\n            compression: Some(paperless_api_client::types::CompressionEnum::Bzip2),\n            follow_formatting: true,\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `POST` request to `/api/documents/bulk_download/`.\n\n```rust,no_run\nasync fn example_documents_bulk_download_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::download::DownloadedFile = client\n        .documents()\n        .bulk_download_create(&paperless_api_client::types::BulkDownloadRequest {\n            documents: vec![4 as i64],\n            content: Some(paperless_api_client::types::ContentEnum::Originals),\n            compression: Some(paperless_api_client::types::CompressionEnum::Bzip2),\n            follow_formatting: true,\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn bulk_download_create<'a>(
         &'a self,
         body: &crate::types::BulkDownloadRequest,
-    ) -> Result<crate::types::BulkDownload, crate::types::error::Error> {
+    ) -> Result<crate::types::download::DownloadedFile, crate::types::error::Error> {
         let mut req = self.client.client.request(
             http::Method::POST,
             format!(
@@ -1665,18 +1203,41 @@ impl Documents {
                 self.client.base_url, "api/documents/bulk_download/"
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
+            crate::types::download::DownloadedFile::from_response(resp).await
+        } else {
             let text = resp.text().await.unwrap_or_default();
-            serde_json::from_str(&text).map_err(|err| {
-                crate::types::error::Error::from_serde_error(
-                    format_serde_error::SerdeError::new(text.to_string(), err),
-                    status,
-                )
+            Err(crate::types::error::Error::Server {
+                body: text.to_string(),
+                status,
             })
+        }
+    }
+
+    #[doc = "Perform a `POST` request to `/api/documents/bulk_download/`, streaming the archive instead of buffering it.\n\n```rust,no_run\nasync fn example_documents_bulk_download_stream() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let stream = client\n        .documents()\n        .bulk_download_stream(&paperless_api_client::types::BulkDownloadRequest {\n            documents: vec![4 as i64],\n            content: Some(paperless_api_client::types::ContentEnum::Originals),\n            compression: Some(paperless_api_client::types::CompressionEnum::Deflated),\n            follow_formatting: true,\n        })\n        .await?;\n    let mut archive = futures_util::io::Cursor::new(Vec::new());\n    let written = stream.write_to(&mut archive).await?;\n    println!(\"{written} bytes\");\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    #[cfg(not(feature = "js"))]
+    pub async fn bulk_download_stream<'a>(
+        &'a self,
+        body: &crate::types::BulkDownloadRequest,
+    ) -> Result<crate::types::download::DownloadStream, crate::types::error::Error> {
+        let mut req = self.client.client.request(
+            http::Method::POST,
+            format!(
+                "{}/{}",
+                self.client.base_url, "api/documents/bulk_download/"
+            ),
+        );
+        req = self.client.authenticate(req).await?;
+        req = req.json(body);
+        let resp = req.send().await?;
+        let status = resp.status();
+        if status.is_success() {
+            Ok(crate::types::download::DownloadStream::from_response(resp))
         } else {
             let text = resp.text().await.unwrap_or_default();
             Err(crate::types::error::Error::Server {
@@ -1686,19 +1247,28 @@ impl Documents {
         }
     }
 
-    #[doc = "Perform a `POST` request to `/api/documents/bulk_edit/`.\n\nPerform a bulk edit operation on a list of documents\n\nSee <https://docs.paperless-ngx.com/api/#bulk-editing|Further documentation> for more information.\n\n```rust,no_run\nasync fn example_documents_bulk_edit() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::BulkEditDocumentsResult = client\n        .documents()\n        .bulk_edit(&paperless_api_client::types::BulkEditRequest {\n            documents: vec![4 as i64],\n            method: paperless_api_client::types::MethodEnum::SetPermissions,\n            parameters: Some(std::collections::HashMap::from([(\n                \"some-key\".to_string(),\n                serde_json::Value::String(\"some-string\".to_string()),\n            )])),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `POST` request to `/api/documents/bulk_edit/`.\n\nPerform a bulk edit operation on a list of documents\n\nSee <https://docs.paperless-ngx.com/api/#bulk-editing|Further documentation> for more information.\n\nTakes a [`BulkEditRequest`](crate::types::BulkEditRequest), usually built from a typed [`BulkOperation`](crate::types::BulkOperation):\n\n```rust,no_run\nasync fn example_documents_bulk_edit_operation() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::BulkEditDocumentsResult = client\n        .documents()\n        .bulk_edit(\n            paperless_api_client::types::BulkOperation::ModifyTags {\n                add_tags: vec![4 as i64],\n                remove_tags: vec![],\n            }\n            .on([4 as i64]),\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```\n\n```rust,no_run\nasync fn example_documents_bulk_edit() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::BulkEditDocumentsResult = client\n        .documents()\n        .bulk_edit(&paperless_api_client::types::BulkEditRequest {\n            documents: vec![4 as i64],\n            method: paperless_api_client::types::MethodEnum::SetPermissions,\n            parameters: Some(std::collections::HashMap::from([(\n                \"some-key\".to_string(),\n                serde_json::Value::String(\"some-string\".to_string()),\n            )])),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
-    pub async fn bulk_edit<'a>(
+    pub async fn bulk_edit<'a, B>(
         &'a self,
-        body: &crate::types::BulkEditRequest,
-    ) -> Result<crate::types::BulkEditDocumentsResult, crate::types::error::Error> {
+        body: B,
+    ) -> Result<crate::types::BulkEditDocumentsResult, crate::types::error::Error>
+    where
+        B: Into<crate::types::BulkEditRequest> + std::fmt::Debug,
+    {
+        let body: crate::types::BulkEditRequest = body.into();
+        if body.method == crate::types::MethodEnum::EditPdf {
+            self.client
+                .require_capability(crate::types::Capability::EditPdf)
+                .await?;
+        }
         let mut req = self.client.client.request(
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/documents/bulk_edit/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
-        req = req.json(body);
+        req = self.client.authenticate(req).await?;
+        req = req.json(&body);
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -1726,7 +1296,7 @@ impl Documents {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/documents/next_asn/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -1761,13 +1331,18 @@ impl Documents {
                 self.client.base_url, "api/documents/post_document/"
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         use std::convert::TryInto;
         let mut form = reqwest::multipart::Form::new();
-        let mut json_part = reqwest::multipart::Part::text(serde_json::to_string(&body)?);
-        json_part = json_part.file_name(format!("{}.json", "body"));
-        json_part = json_part.mime_str("application/json")?;
-        form = form.part("body", json_part);
+        for (name, value) in crate::types::UploadMetadata::from(body).form_fields() {
+            form = form.text(name, value);
+        }
+        if !body.document.is_empty() {
+            form = form.part(
+                "document",
+                reqwest::multipart::Part::stream(body.document.clone()).file_name("document"),
+            );
+        }
         for attachment in attachments {
             form = form.part(attachment.name.clone(), attachment.try_into()?);
         }
@@ -1792,6 +1367,207 @@ impl Documents {
         }
     }
 
+    #[doc = "Perform a `POST` request to `/api/documents/post_document/`, streaming the document from its source.\n\nUpload a document via the API. The metadata is sent as regular form fields next to the `document` file. Returns the Celery id of the consumption task.\n\nSee <https://docs.paperless-ngx.com/api/#file-uploads|Further documentation> for more information.\n\n```rust,no_run\nasync fn example_documents_upload() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: String = client\n        .documents()\n        .upload(\n            paperless_api_client::types::multipart::DocumentUpload::from_path(\"scan.pdf\").await?,\n            &paperless_api_client::types::UploadMetadata {\n                title: Some(\"some-string\".to_string()),\n                tags: Some(vec![4 as i64]),\n                ..Default::default()\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn upload<'a>(
+        &'a self,
+        document: crate::types::multipart::DocumentUpload,
+        metadata: &crate::types::UploadMetadata,
+    ) -> Result<String, crate::types::error::Error> {
+        let mut req = self.client.client.request(
+            http::Method::POST,
+            format!(
+                "{}/{}",
+                self.client.base_url, "api/documents/post_document/"
+            ),
+        );
+        req = self.client.authenticate(req).await?;
+        use std::convert::TryInto;
+        let mut form = reqwest::multipart::Form::new();
+        for (name, value) in metadata.form_fields() {
+            form = form.text(name, value);
+        }
+        form = form.part("document", document.try_into()?);
+        req = req.multipart(form);
+        let resp = req.send().await?;
+        let status = resp.status();
+        if status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            serde_json::from_str(&text).map_err(|err| {
+                crate::types::error::Error::from_serde_error(
+                    format_serde_error::SerdeError::new(text.to_string(), err),
+                    status,
+                )
+            })
+        } else {
+            let text = resp.text().await.unwrap_or_default();
+            Err(crate::types::error::Error::Server {
+                body: text.to_string(),
+                status,
+            })
+        }
+    }
+
+    #[doc = "Upload a document via `/api/documents/post_document/` and wait until the server has consumed it.\n\nThe document is streamed like in [`upload`](Self::upload) and the consumption task is polled according to `options`. Returns the created document, or [`Error::TaskFailed`](crate::types::error::Error::TaskFailed) carrying the task's result message, e.g. when the file is a duplicate or OCR failed. A task that succeeded without creating a single document, e.g. because the file was split, returns [`Error::TaskWithoutDocument`](crate::types::error::Error::TaskWithoutDocument).\n\n```rust,no_run\nasync fn example_documents_consume() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Document = client\n        .documents()\n        .consume(\n            paperless_api_client::types::multipart::DocumentUpload::from_path(\"scan.pdf\").await?,\n            &paperless_api_client::types::UploadMetadata {\n                title: Some(\"some-string\".to_string()),\n                tags: Some(vec![4 as i64]),\n                ..Default::default()\n            },\n            &paperless_api_client::types::TaskWaitOptions::default(),\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn consume<'a>(
+        &'a self,
+        document: crate::types::multipart::DocumentUpload,
+        metadata: &crate::types::UploadMetadata,
+        options: &crate::types::TaskWaitOptions,
+    ) -> Result<crate::types::Document, crate::types::error::Error> {
+        let task_id = self.upload(document, metadata).await?;
+        let task = self.client.tasks().wait_for(&task_id, options).await?;
+        match task.related_document_id() {
+            Some(doc_id) => self.retrieve(doc_id, None, None).await,
+            None => Err(crate::types::error::Error::TaskWithoutDocument {
+                task_id: task.task_id,
+                result: task.result,
+            }),
+        }
+    }
+
+    #[doc = "Page through `/api/documents/` with the filters of a [`DocumentQuery`](crate::types::DocumentQuery) using a [`Paginator`](crate::paginator::Paginator).\n\nThe paginator starts at the `page` of the query. Large crawls can request several pages at once with [`Paginator::prefetch`](crate::paginator::Paginator::prefetch).\n\n**Parameters:**\n\n- `query: &crate::types::DocumentQuery`: Filters, ordering and page size of the request.\n\n```rust,no_run\nuse futures_util::TryStreamExt;\nasync fn example_documents_list_paginator() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let query = paperless_api_client::types::DocumentQuery::new().page_size(100);\n    let mut documents = client.documents().list_paginator(&query).prefetch(4).items();\n    while let Some(document) = documents.try_next().await? {\n        println!(\"{:?}\", document);\n    }\n    Ok(())\n}\n```"]
+    pub fn list_paginator(
+        &self,
+        query: &crate::types::DocumentQuery,
+    ) -> crate::paginator::Paginator<crate::types::PaginatedDocumentList> {
+        let paginator = crate::paginator::Paginator::resume(
+            self.client.clone(),
+            crate::paginator::PageCursor::first(
+                &self.client,
+                "api/documents/",
+                query
+                    .query_params()
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value))
+                    .collect(),
+            ),
+        );
+        if query.custom_field_query.is_some() {
+            paginator.require(crate::types::Capability::CustomFieldQuery)
+        } else {
+            paginator
+        }
+    }
+
+    #[doc = "Page through `/api/documents/{doc_id}/history/` with a [`Paginator`](crate::paginator::Paginator).\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `page_size: Option<i64>`: Number of results to return per page.\n\n```rust,no_run\nasync fn example_documents_history_paginator() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let mut history = client.documents().history_paginator(4 as i64, Some(25 as i64));\n    println!(\"{:?} entries\", history.total_count().await?);\n    while let Some(page) = history.next_page().await? {\n        println!(\"{:?}\", page.results);\n        // Save `history.cursor()` to continue later with `Paginator::resume`.\n    }\n    Ok(())\n}\n```"]
+    pub fn history_paginator(
+        &self,
+        doc_id: i64,
+        page_size: Option<i64>,
+    ) -> crate::paginator::Paginator<crate::types::PaginatedLogEntryList> {
+        crate::paginator::Paginator::resume(
+            self.client.clone(),
+            crate::paginator::PageCursor::first(
+                &self.client,
+                &format!("api/documents/{doc_id}/history/"),
+                page_size
+                    .map(|p| vec![("page_size".to_string(), format!("{p}"))])
+                    .unwrap_or_default(),
+            ),
+        )
+    }
+
+    #[doc = "Page through `/api/documents/{doc_id}/notes/` with a [`Paginator`](crate::paginator::Paginator).\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `page_size: Option<i64>`: Number of results to return per page.\n\n```rust,no_run\nasync fn example_documents_notes_paginator() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let notes: Vec<paperless_api_client::types::Notes> = client\n        .documents()\n        .notes_paginator(4 as i64, None)\n        .collect_all()\n        .await?;\n    println!(\"{:?}\", notes);\n    Ok(())\n}\n```"]
+    pub fn notes_paginator(
+        &self,
+        doc_id: i64,
+        page_size: Option<i64>,
+    ) -> crate::paginator::Paginator<crate::types::PaginatedNotesList> {
+        crate::paginator::Paginator::resume(
+            self.client.clone(),
+            crate::paginator::PageCursor::first(
+                &self.client,
+                &format!("api/documents/{doc_id}/notes/"),
+                page_size
+                    .map(|p| vec![("page_size".to_string(), format!("{p}"))])
+                    .unwrap_or_default(),
+            ),
+        )
+    }
+
+    #[doc = "Page through `/api/documents/{doc_id}/share_links/` with a [`Paginator`](crate::paginator::Paginator).\n\nThe server returns all share links of a document at once, so there is a single page.\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n\n```rust,no_run\nuse futures_util::TryStreamExt;\nasync fn example_documents_share_links_paginator() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let mut links = client.documents().share_links_paginator(4 as i64).items();\n    while let Some(link) = links.try_next().await? {\n        println!(\"{:?}\", link);\n    }\n    Ok(())\n}\n```"]
+    pub fn share_links_paginator(
+        &self,
+        doc_id: i64,
+    ) -> crate::paginator::Paginator<Vec<crate::types::DocumentShareLinksResponse>> {
+        crate::paginator::Paginator::resume(
+            self.client.clone(),
+            crate::paginator::PageCursor::first(
+                &self.client,
+                &format!("api/documents/{doc_id}/share_links/"),
+                vec![],
+            ),
+        )
+    }
+
+    #[doc = "Return an interface for page operations on a document: rotating, deleting pages, splitting, merging and editing."]
+    pub fn pages(&self, doc_id: i64) -> crate::pages::Pages {
+        crate::pages::Pages::new(self.client.clone(), doc_id)
+    }
+
+    #[doc = "Set custom field values of a document with a `PATCH` request to `/api/documents/{id}/`.\n\nFields are addressed by name or id and looked up in `fields`, usually the result of listing `/api/custom_fields/`. Every value is checked against its field definition before any request is sent. Fields of the document that are not in `values` keep their values.\n\n```rust,no_run\nasync fn example_documents_set_custom_fields() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let fields = client\n        .custom_fields()\n        .list(None, None, None, None, None, None, None, None, None)\n        .await?\n        .results;\n    let result: paperless_api_client::types::Document = client\n        .documents()\n        .set_custom_fields(\n            4 as i64,\n            &fields,\n            &[(\n                \"amount\".into(),\n                paperless_api_client::types::CustomFieldValue::parse_monetary(\"EUR123.45\"),\n            )],\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn set_custom_fields<'a>(
+        &'a self,
+        doc_id: i64,
+        fields: &[crate::types::CustomField],
+        values: &[(
+            crate::types::CfField,
+            Option<crate::types::CustomFieldValue>,
+        )],
+    ) -> Result<crate::types::Document, crate::types::error::Error> {
+        let mut instances = Vec::with_capacity(values.len());
+        for (key, value) in values {
+            let field = fields
+                .iter()
+                .find(|field| match key {
+                    crate::types::CfField::Name(name) => &field.name == name,
+                    crate::types::CfField::Id(id) => field.id == *id,
+                })
+                .ok_or_else(|| {
+                    crate::types::error::Error::InvalidRequest(format!(
+                        "unknown custom field {key:?}"
+                    ))
+                })?;
+            instances.push(field.instance(value.as_ref())?);
+        }
+        let document = self
+            .retrieve(doc_id, Some(vec!["custom_fields".to_string()]), None)
+            .await?;
+        let mut custom_fields: Vec<crate::types::CustomFieldInstanceRequest> = document
+            .custom_fields
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|existing| !instances.iter().any(|i| i.field == existing.field))
+            .map(|existing| crate::types::CustomFieldInstanceRequest {
+                value: existing.value,
+                field: existing.field,
+            })
+            .collect();
+        custom_fields.extend(instances);
+        #[allow(deprecated)]
+        let body = crate::types::PatchedDocumentRequest {
+            correspondent: None,
+            document_type: None,
+            storage_path: None,
+            title: None,
+            content: None,
+            tags: None,
+            created: None,
+            created_date: None,
+            deleted_at: None,
+            archive_serial_number: None,
+            owner: None,
+            set_permissions: None,
+            custom_fields: Some(custom_fields),
+            remove_inbox_tags: None,
+        };
+        self.partial_update(doc_id, &body).await
+    }
+
     #[doc = "Perform a `POST` request to `/api/documents/selection_data/`.\n\nGet selection data for the selected documents\n\n```rust,no_run\nasync fn example_documents_selection_data_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::SelectionData = client\n        .documents()\n        .selection_data_create(\n            vec![paperless_api_client::types::multipart::Attachment {\n                name: \"thing\".to_string(),\n                filepath: Some(\"myfile.json\".into()),\n                content_type: Some(\"application/json\".to_string()),\n                data: std::fs::read(\"myfile.json\").unwrap(),\n            }],\n            &paperless_api_client::types::DocumentListRequest {\n                documents: vec![4 as i64],\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
@@ -1807,7 +1583,7 @@ impl Documents {
                 self.client.base_url, "api/documents/selection_data/"
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         use std::convert::TryInto;
         let mut form = reqwest::multipart::Form::new();
         let mut json_part = reqwest::multipart::Part::text(serde_json::to_string(&body)?);
@@ -1837,4 +1613,148 @@ impl Documents {
             })
         }
     }
+
+    #[doc = "Fetch documents by id with `GET` requests to `/api/documents/` with `id__in` filters, in batches sent in parallel.\n\nIds not found, e.g. deleted or not visible to the user, are reported as missing.\n\n```rust,no_run\nasync fn example_documents_get_many() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::paginator::ByIds<paperless_api_client::types::Document> =\n        client.documents().get_many(&[4 as i64, 5 as i64]).await?;\n    println!(\"{:?} missing\", result.missing);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn get_many<'a>(
+        &'a self,
+        ids: &'a [i64],
+    ) -> Result<crate::paginator::ByIds<crate::types::Document>, crate::types::error::Error> {
+        crate::paginator::get_many::<crate::types::PaginatedDocumentList>(
+            &self.client,
+            "api/documents/",
+            ids,
+            |item| item.id,
+        )
+        .await
+    }
+
+    #[doc = "Apply the suggestions of `/api/documents/{doc_id}/suggestions/` to the document with a single `PATCH` request.\n\nThe `policy` picks which suggestions are applied; see [`SuggestionPolicy`](crate::types::SuggestionPolicy). Returns the changes made, which on a dry run are only reported. No request is sent if nothing changes.\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `policy: &crate::types::SuggestionPolicy`: Which suggestions to apply.\n\n```rust,no_run\nasync fn example_documents_apply_suggestions() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let policy = paperless_api_client::types::SuggestionPolicy {\n        tags: paperless_api_client::types::SuggestedTags::Replace,\n        ..Default::default()\n    };\n    let result: paperless_api_client::types::AppliedSuggestions = client\n        .documents()\n        .apply_suggestions(4 as i64, &policy.dry_run())\n        .await?;\n    println!(\"{}\", result.changes);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn apply_suggestions<'a>(
+        &'a self,
+        doc_id: i64,
+        policy: &'a crate::types::SuggestionPolicy,
+    ) -> Result<crate::types::AppliedSuggestions, crate::types::error::Error> {
+        let (document, suggestions) = futures::try_join!(
+            self.retrieve(doc_id, None, None),
+            self.suggestions_retrieve(doc_id)
+        )?;
+        let changes = policy.changes(&document, &suggestions);
+        if policy.dry_run || changes.is_empty() {
+            return Ok(crate::types::AppliedSuggestions {
+                document,
+                changes,
+                applied: false,
+            });
+        }
+        let document = self.partial_update(doc_id, &changes.to_patch()).await?;
+        Ok(crate::types::AppliedSuggestions {
+            document,
+            changes,
+            applied: true,
+        })
+    }
+
+    #[doc = "Edit a document with `f` and send only the changed fields with a `PATCH` request to `/api/documents/{doc_id}/`.\n\nThe document is fetched and passed to `f`; the difference to the fetched document, as given by [`Document::diff`](crate::types::Document::diff), is sent, with cleared fields sent as `null`. Right before the update the document is fetched again: if it was modified in the meantime the edit is applied anew to the latest version, so `f` may be called up to [`EDIT_ATTEMPTS`] times, after which [`Error::Conflict`](crate::types::error::Error::Conflict) is returned. No request is sent if nothing changes.\n\nThe check is best-effort, as the server can't make the update conditional: a change saved between that last fetch and the `PATCH` is overwritten in the fields the edit sends.\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `f: FnMut(&mut crate::types::Document)`: The edit.\n\n```rust,no_run\nasync fn example_documents_edit() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Document = client\n        .documents()\n        .edit(4 as i64, |document| {\n            document.title = Some(\"some-string\".to_string());\n            document.tags.push(4 as i64);\n            document.correspondent = None;\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[tracing::instrument(skip(f))]
+    pub async fn edit<'a, F>(
+        &'a self,
+        doc_id: i64,
+        mut f: F,
+    ) -> Result<crate::types::Document, crate::types::error::Error>
+    where
+        F: FnMut(&mut crate::types::Document),
+    {
+        let mut current = self.retrieve(doc_id, None, None).await?;
+        for _ in 0..EDIT_ATTEMPTS {
+            let mut edited = current.clone();
+            f(&mut edited);
+            let mut body = serde_json::to_value(current.diff(&edited))?;
+            if let serde_json::Value::Object(fields) = &mut body {
+                for field in current.cleared_fields(&edited) {
+                    fields.insert(field.to_string(), serde_json::Value::Null);
+                }
+                if fields.is_empty() {
+                    return Ok(current);
+                }
+            }
+            let latest = self.retrieve(doc_id, None, None).await?;
+            if latest.modified != current.modified {
+                current = latest;
+                continue;
+            }
+            return self.patch(doc_id, &body).await;
+        }
+        Err(crate::types::error::Error::Conflict {
+            id: doc_id,
+            modified: current.modified,
+        })
+    }
+
+    #[doc = " Send `body` with a `PATCH` request to `/api/documents/{doc_id}/`, for fields"]
+    #[doc = " [`PatchedDocumentRequest`](crate::types::PatchedDocumentRequest) can't express."]
+    async fn patch(
+        &self,
+        doc_id: i64,
+        body: &serde_json::Value,
+    ) -> Result<crate::types::Document, crate::types::error::Error> {
+        let mut req = self.client.client.request(
+            http::Method::PATCH,
+            format!(
+                "{}/{}",
+                self.client.base_url,
+                "api/documents/{doc_id}/".replace("{doc_id}", &format!("{doc_id}"))
+            ),
+        );
+        req = self.client.authenticate(req).await?;
+        req = req.json(body);
+        let resp = req.send().await?;
+        let status = resp.status();
+        if status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            serde_json::from_str(&text).map_err(|err| {
+                crate::types::error::Error::from_serde_error(
+                    format_serde_error::SerdeError::new(text.to_string(), err),
+                    status,
+                )
+            })
+        } else {
+            let text = resp.text().await.unwrap_or_default();
+            Err(crate::types::error::Error::Server {
+                body: text.to_string(),
+                status,
+            })
+        }
+    }
+
+    #[doc = "Stream the audit log of a document from `/api/documents/{doc_id}/history/` as typed [`HistoryEntry`](crate::types::HistoryEntry) items, paging through it with a [`Paginator`](crate::paginator::Paginator).\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `page_size: Option<i64>`: Number of results to return per page.\n\n```rust,no_run\nuse futures_util::TryStreamExt;\nasync fn example_documents_history_stream() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let mut history = client.documents().history_stream(4 as i64, Some(100 as i64));\n    while let Some(entry) = history.try_next().await? {\n        println!(\"{} {} {:?}\", entry.timestamp, entry.action, entry.changes);\n    }\n    Ok(())\n}\n```"]
+    pub fn history_stream(
+        &self,
+        doc_id: i64,
+        page_size: Option<i64>,
+    ) -> impl futures::Stream<Item = Result<crate::types::HistoryEntry, crate::types::error::Error>>
+           + Unpin {
+        use futures::TryStreamExt;
+        self.history_paginator(doc_id, page_size)
+            .items()
+            .map_ok(crate::types::HistoryEntry::from)
+    }
+
+    #[doc = "Who changed `field` of a document and when, oldest change first, from the audit log at `/api/documents/{doc_id}/history/`.\n\n`field` is a field of the document like `title` or `correspondent`, or the name of a custom field.\n\n**Parameters:**\n\n- `doc_id: i64`: A unique integer value identifying this document. (required)\n- `field: &str`: The field of the document or the name of a custom field.\n\n```rust,no_run\nasync fn example_documents_field_history() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let edits: Vec<paperless_api_client::types::FieldEdit> =\n        client.documents().field_history(4 as i64, \"correspondent\").await?;\n    for edit in edits {\n        let user = edit.actor.map(|actor| actor.username);\n        println!(\"{} {:?} {:?}\", edit.timestamp, user, edit.change);\n    }\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn field_history<'a>(
+        &'a self,
+        doc_id: i64,
+        field: &'a str,
+    ) -> Result<Vec<crate::types::FieldEdit>, crate::types::error::Error> {
+        use futures::TryStreamExt;
+        let mut edits: Vec<crate::types::FieldEdit> = self
+            .history_stream(doc_id, None)
+            .try_filter_map(|entry| async move { Ok(entry.field_edit(field)) })
+            .try_collect()
+            .await?;
+        edits.sort_by_key(|edit| edit.timestamp);
+        Ok(edits)
+    }
 }
@@ -28,7 +28,7 @@ impl Groups {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/groups/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = name_icontains {
             query_params.push(("name__icontains", p));
@@ -92,70 +92,18 @@ impl Groups {
         page_size: Option<i64>,
     ) -> impl futures::Stream<Item = Result<crate::types::Group, crate::types::error::Error>> + Unpin + '_
     {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(
-            name__icontains,
-            name__iendswith,
-            name__iexact,
-            name__istartswith,
-            ordering,
-            None,
-            page_size,
+        crate::paginator::list_items(
+            &self.client,
+            self.list(
+                name__icontains,
+                name__iendswith,
+                name__iexact,
+                name__istartswith,
+                ordering,
+                None,
+                page_size,
+            ),
         )
-        .map_ok(move |result| {
-            let items = futures::stream::iter(result.items().into_iter().map(Ok));
-            let next_pages = futures::stream::try_unfold(
-                (None, result),
-                move |(prev_page_token, new_result)| async move {
-                    if new_result.has_more_pages()
-                        && !new_result.items().is_empty()
-                        && prev_page_token != new_result.next_page_token()
-                    {
-                        async {
-                            let mut req = self.client.client.request(
-                                http::Method::GET,
-                                format!("{}/{}", self.client.base_url, "api/groups/"),
-                            );
-                            req = req
-                                .header("Authorization", format!("Token {}", &self.client.token));
-                            let mut request = req.build()?;
-                            request = new_result.next_page(request)?;
-                            let resp = self.client.client.execute(request).await?;
-                            let status = resp.status();
-                            if status.is_success() {
-                                let text = resp.text().await.unwrap_or_default();
-                                serde_json::from_str(&text).map_err(|err| {
-                                    crate::types::error::Error::from_serde_error(
-                                        format_serde_error::SerdeError::new(text.to_string(), err),
-                                        status,
-                                    )
-                                })
-                            } else {
-                                let text = resp.text().await.unwrap_or_default();
-                                Err(crate::types::error::Error::Server {
-                                    body: text.to_string(),
-                                    status,
-                                })
-                            }
-                        }
-                        .map_ok(|result: crate::types::PaginatedGroupList| {
-                            Some((
-                                futures::stream::iter(result.items().into_iter().map(Ok)),
-                                (new_result.next_page_token(), result),
-                            ))
-                        })
-                        .await
-                    } else {
-                        Ok(None)
-                    }
-                },
-            )
-            .try_flatten();
-            items.chain(next_pages)
-        })
-        .try_flatten_stream()
-        .boxed()
     }
 
     #[doc = "Perform a `POST` request to `/api/groups/`.\n\n```rust,no_run\nasync fn example_groups_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Group = client\n        .groups()\n        .create(&paperless_api_client::types::GroupRequest {\n            name: \"some-string\".to_string(),\n            permissions: vec![\"some-string\".to_string()],\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
@@ -169,7 +117,7 @@ impl Groups {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/groups/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -205,7 +153,7 @@ impl Groups {
                 "api/groups/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -241,7 +189,7 @@ impl Groups {
                 "api/groups/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -274,7 +222,7 @@ impl Groups {
                 "api/groups/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -304,7 +252,7 @@ impl Groups {
                 "api/groups/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -324,4 +272,13 @@ impl Groups {
             })
         }
     }
+
+    #[doc = "Fetch groups by id with parallel `GET` requests to `/api/groups/{id}/`.\n\nThe endpoint has no `id__in` filter, so every object is retrieved on its own. Ids not found are reported as missing.\n\n```rust,no_run\nasync fn example_groups_get_many() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::paginator::ByIds<paperless_api_client::types::Group> =\n        client.groups().get_many(&[4 as i64, 5 as i64]).await?;\n    println!(\"{:?} missing\", result.missing);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn get_many<'a>(
+        &'a self,
+        ids: &'a [i64],
+    ) -> Result<crate::paginator::ByIds<crate::types::Group>, crate::types::error::Error> {
+        crate::paginator::retrieve_many(ids, |id| self.retrieve(id)).await
+    }
 }
@@ -39,6 +39,20 @@
 //! );
 //! ```
 //!
+//! To authenticate differently, e.g. with a user name and password, or to
+//! configure the connection, use the builder:
+//!
+//! ```rust,no_run
+//! use paperless_api_client::Client;
+//!
+//! let client = Client::builder()
+//!     .credentials("username", "password")
+//!     .base_url("https://paperless.example.com")
+//!     .timeout(Some(std::time::Duration::from_secs(30)))
+//!     .build()?;
+//! # Ok::<(), paperless_api_client::types::error::Error>(())
+//! ```
+//!
 //! Alternatively, the library can search for most of the variables required for
 //! the client in the environment:
 //!
@@ -57,9 +71,12 @@
 #![allow(missing_docs)]
 #![allow(unused_imports)]
 #![allow(clippy::needless_lifetimes)]
+#![allow(clippy::result_large_err)]
 #![allow(clippy::too_many_arguments)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "requests")]
+pub mod auth;
 #[cfg(feature = "requests")]
 pub mod bulk_edit_objects;
 #[cfg(feature = "requests")]
@@ -74,20 +91,30 @@ pub mod document_types;
 pub mod documents;
 #[cfg(feature = "requests")]
 pub mod groups;
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "requests")]
+pub mod limit;
 #[cfg(feature = "requests")]
 pub mod logs;
 #[cfg(feature = "requests")]
 pub mod mail_accounts;
 #[cfg(feature = "requests")]
 pub mod mail_rules;
+pub mod matching;
 mod methods;
 #[cfg(feature = "requests")]
 pub mod oauth;
 #[cfg(feature = "requests")]
+pub mod pages;
+#[cfg(feature = "requests")]
+pub mod paginator;
+#[cfg(feature = "requests")]
 pub mod profile;
 #[cfg(feature = "requests")]
 pub mod remote_version;
 #[cfg(feature = "requests")]
+pub mod retry;
+#[cfg(feature = "requests")]
 pub mod saved_views;
 #[cfg(feature = "requests")]
 pub mod search;
@@ -103,6 +130,8 @@ pub mod storage_paths;
 pub mod tags;
 #[cfg(feature = "requests")]
 pub mod tasks;
+#[cfg(feature = "requests")]
+pub mod taxonomy;
 #[cfg(test)]
 mod tests;
 #[cfg(feature = "requests")]
@@ -114,6 +143,8 @@ pub mod types;
 pub mod ui_settings;
 #[cfg(feature = "requests")]
 pub mod users;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 #[cfg(feature = "requests")]
 pub mod workflow_actions;
 #[cfg(feature = "requests")]
@@ -132,31 +163,223 @@ static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), ".rs/", env!("CARG
 #[derive(Clone, Debug)]
 #[cfg(feature = "requests")]
 pub struct Client {
-    token: String,
+    auth: auth::Auth,
+    token_cache: std::sync::Arc<futures::lock::Mutex<Option<String>>>,
     base_url: String,
-
+    api_version: Option<u32>,
+    capabilities: std::sync::Arc<std::sync::Mutex<Option<types::ServerCapabilities>>>,
     #[cfg(feature = "retry")]
+    retry_policy: Option<retry::RetryPolicy>,
+
     client: reqwest_middleware::ClientWithMiddleware,
-    #[cfg(feature = "retry")]
     #[cfg(not(target_arch = "wasm32"))]
     #[allow(dead_code)]
     client_http1_only: reqwest_middleware::ClientWithMiddleware,
-
-    #[cfg(not(feature = "retry"))]
-    client: reqwest::Client,
-    #[cfg(not(feature = "retry"))]
-    #[cfg(not(target_arch = "wasm32"))]
-    #[allow(dead_code)]
-    client_http1_only: reqwest::Client,
 }
 
 /// A request builder.
-#[cfg(feature = "retry")]
 #[cfg(feature = "requests")]
 pub struct RequestBuilder(pub reqwest_middleware::RequestBuilder);
+
+#[cfg(feature = "requests")]
+const DEFAULT_BASE_URL: &str = "https://your-paperles.url/api";
+
+/// Wrap a reqwest client with token refreshes, tracing and retries of failed requests.
+#[cfg(feature = "retry")]
+#[cfg(feature = "requests")]
+fn with_middleware(
+    client: reqwest::Client,
+    retry_policy: retry::RetryPolicy,
+) -> reqwest_middleware::ClientBuilder {
+    reqwest_middleware::ClientBuilder::new(client)
+        // Obtain a new token for credentials when the cached one was refused.
+        .with(auth::TokenRefreshMiddleware)
+        // Trace HTTP requests. See the tracing crate to make use of these traces.
+        .with(reqwest_tracing::TracingMiddleware::default())
+        // Retry failed requests.
+        .with(retry::RetryMiddleware::new(retry_policy))
+}
+
+/// Wrap a reqwest client with token refreshes, ready for the middleware enforcing the
+/// client's limits.
 #[cfg(not(feature = "retry"))]
 #[cfg(feature = "requests")]
-pub struct RequestBuilder(pub reqwest::RequestBuilder);
+fn with_middleware(
+    client: reqwest::Client,
+    _retry_policy: retry::RetryPolicy,
+) -> reqwest_middleware::ClientBuilder {
+    // Obtain a new token for credentials when the cached one was refused.
+    reqwest_middleware::ClientBuilder::new(client).with(auth::TokenRefreshMiddleware)
+}
+
+/// Builder for a [`Client`], configuring authentication, the base URL, timeouts,
+/// the user agent, retries and rate limits in one place.
+#[derive(Clone, Debug)]
+#[cfg(feature = "requests")]
+pub struct ClientBuilder {
+    auth: auth::Auth,
+    base_url: String,
+    api_version: Option<u32>,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    user_agent: Option<String>,
+    retry_policy: retry::RetryPolicy,
+    #[cfg(not(target_arch = "wasm32"))]
+    rate_limit: limit::RateLimit,
+}
+
+#[cfg(feature = "requests")]
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            auth: auth::Auth::None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_version: None,
+            // For file conversions we need this to be long.
+            timeout: Some(std::time::Duration::from_secs(600)),
+            connect_timeout: Some(std::time::Duration::from_secs(60)),
+            user_agent: None,
+            retry_policy: Default::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            rate_limit: Default::default(),
+        }
+    }
+}
+
+#[cfg(feature = "requests")]
+impl ClientBuilder {
+    /// Create a builder without authentication and the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how requests are authenticated.
+    pub fn auth(mut self, auth: auth::Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Authenticate with an API token.
+    pub fn token<T: ToString>(self, token: T) -> Self {
+        self.auth(auth::Auth::Token(token.to_string()))
+    }
+
+    /// Authenticate with HTTP basic authentication.
+    pub fn basic_auth<U: ToString, P: ToString>(self, username: U, password: P) -> Self {
+        self.auth(auth::Auth::Basic {
+            username: username.to_string(),
+            password: password.to_string(),
+        })
+    }
+
+    /// Authenticate with an API token obtained for these credentials on the first request.
+    pub fn credentials<U: ToString, P: ToString>(self, username: U, password: P) -> Self {
+        self.auth(auth::Auth::Credentials {
+            username: username.to_string(),
+            password: password.to_string(),
+            code: None,
+        })
+    }
+
+    /// Authenticate with an existing session and its CSRF token.
+    pub fn session<S: ToString, C: ToString>(self, session_id: S, csrf_token: C) -> Self {
+        self.auth(auth::Auth::Session {
+            session_id: session_id.to_string(),
+            csrf_token: csrf_token.to_string(),
+        })
+    }
+
+    /// Set the base URL, e.g. `https://paperless.example.com`.
+    pub fn base_url<H: ToString>(mut self, base_url: H) -> Self {
+        self.base_url = base_url.to_string().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Pin the API version sent in the `Accept` header of every request. Without it
+    /// the server answers with its default version.
+    pub fn api_version(mut self, api_version: u32) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /// Set the total timeout of a request, `None` to wait indefinitely.
+    pub fn timeout(mut self, timeout: Option<std::time::Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the timeout for connecting to the server, `None` to wait indefinitely.
+    pub fn connect_timeout(mut self, timeout: Option<std::time::Duration>) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Set the user agent sent with every request.
+    pub fn user_agent<U: ToString>(mut self, user_agent: U) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Set when failed requests are retried. Only used with the `retry` feature.
+    pub fn retry_policy(mut self, retry_policy: retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limit the requests in flight and started per second. The limits are shared by all
+    /// clones of the client.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn rate_limit(mut self, rate_limit: limit::RateLimit) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn reqwest_builder(&self) -> reqwest::ClientBuilder {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(self.user_agent.as_deref().unwrap_or(APP_USER_AGENT));
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        builder
+    }
+
+    /// Build the client.
+    pub fn build(self) -> Result<Client, types::error::Error> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = self.reqwest_builder().build()?;
+        #[cfg(target_arch = "wasm32")]
+        let client = reqwest::Client::builder().build()?;
+        #[cfg(not(target_arch = "wasm32"))]
+        let client_http1_only = self.reqwest_builder().http1_only().build()?;
+        #[cfg(not(target_arch = "wasm32"))]
+        let limiter = limit::LimitMiddleware::new(&self.rate_limit);
+        Ok(Client {
+            auth: self.auth,
+            token_cache: Default::default(),
+            base_url: self.base_url,
+            api_version: self.api_version,
+            capabilities: Default::default(),
+            #[cfg(feature = "retry")]
+            retry_policy: None,
+
+            #[cfg(not(target_arch = "wasm32"))]
+            client: with_middleware(client, self.retry_policy.clone())
+                // Limit every attempt, including retries.
+                .with(limiter.clone())
+                .build(),
+            #[cfg(target_arch = "wasm32")]
+            client: with_middleware(client, self.retry_policy.clone()).build(),
+            #[cfg(not(target_arch = "wasm32"))]
+            client_http1_only: with_middleware(client_http1_only, self.retry_policy)
+                .with(limiter)
+                .build(),
+        })
+    }
+}
 
 #[cfg(feature = "requests")]
 impl Client {
@@ -174,52 +397,20 @@ impl Client {
     where
         T: ToString + std::fmt::Debug,
     {
-        #[cfg(feature = "retry")]
-        {
-            // Retry up to 3 times with increasing intervals between attempts.
-            let retry_policy =
-                reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-            match (builder_http.build(), builder_websocket.build()) {
-                (Ok(c), Ok(c1)) => {
-                    let client = reqwest_middleware::ClientBuilder::new(c)
-                        // Trace HTTP requests. See the tracing crate to make use of these traces.
-                        .with(reqwest_tracing::TracingMiddleware::default())
-                        // Retry failed requests.
-                        .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some(),
-                        ))
-                        .build();
-                    let client_http1_only = reqwest_middleware::ClientBuilder::new(c1)
-                        .with(reqwest_tracing::TracingMiddleware::default())
-                        .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some(),
-                        ))
-                        .build();
-                    Client {
-                        token: token.to_string(),
-                        base_url: "https://your-paperles.url/api".to_string(),
-
-                        client,
-                        client_http1_only,
-                    }
-                }
-                (Err(e), _) | (_, Err(e)) => panic!("creating reqwest client failed: {e:?}"),
-            }
-        }
-        #[cfg(not(feature = "retry"))]
-        {
-            match (builder_http.build(), builder_websocket.build()) {
-                (Ok(c), Ok(c1)) => Client {
-                    token: token.to_string(),
-                    base_url: "https://your-paperles.url/api".to_string(),
-
-                    client: c,
-                    client_http1_only: c1,
-                },
-                (Err(e), _) | (_, Err(e)) => panic!("creating reqwest client failed: {:?}", e),
-            }
+        match (builder_http.build(), builder_websocket.build()) {
+            (Ok(c), Ok(c1)) => Client {
+                auth: auth::Auth::Token(token.to_string()),
+                token_cache: Default::default(),
+                base_url: DEFAULT_BASE_URL.to_string(),
+                api_version: None,
+                capabilities: Default::default(),
+                #[cfg(feature = "retry")]
+                retry_policy: None,
+
+                client: with_middleware(c, Default::default()).build(),
+                client_http1_only: with_middleware(c1, Default::default()).build(),
+            },
+            (Err(e), _) | (_, Err(e)) => panic!("creating reqwest client failed: {e:?}"),
         }
     }
 
@@ -233,43 +424,19 @@ impl Client {
     where
         T: ToString + std::fmt::Debug,
     {
-        #[cfg(feature = "retry")]
-        {
-            // Retry up to 3 times with increasing intervals between attempts.
-            let retry_policy =
-                reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-            match builder_http.build() {
-                Ok(c) => {
-                    let client = reqwest_middleware::ClientBuilder::new(c)
-                        // Trace HTTP requests. See the tracing crate to make use of these traces.
-                        .with(reqwest_tracing::TracingMiddleware::default())
-                        // Retry failed requests.
-                        .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some(),
-                        ))
-                        .build();
-                    Client {
-                        token: token.to_string(),
-                        base_url: "https://your-paperles.url/api".to_string(),
-
-                        client,
-                    }
-                }
-                Err(e) => panic!("creating reqwest client failed: {:?}", e),
-            }
-        }
-        #[cfg(not(feature = "retry"))]
-        {
-            match builder_http.build() {
-                Ok(c) => Client {
-                    token: token.to_string(),
-                    base_url: "https://your-paperles.url/api".to_string(),
-
-                    client: c,
-                },
-                Err(e) => panic!("creating reqwest client failed: {:?}", e),
-            }
+        match builder_http.build() {
+            Ok(c) => Client {
+                auth: auth::Auth::Token(token.to_string()),
+                token_cache: Default::default(),
+                base_url: DEFAULT_BASE_URL.to_string(),
+                api_version: None,
+                capabilities: Default::default(),
+                #[cfg(feature = "retry")]
+                retry_policy: None,
+
+                client: with_middleware(c, Default::default()).build(),
+            },
+            Err(e) => panic!("creating reqwest client failed: {:?}", e),
         }
     }
 
@@ -281,25 +448,15 @@ impl Client {
     where
         T: ToString + std::fmt::Debug,
     {
-        #[cfg(not(target_arch = "wasm32"))]
-        let client = reqwest::Client::builder()
-            .user_agent(APP_USER_AGENT)
-            // For file conversions we need this to be long.
-            .timeout(std::time::Duration::from_secs(600))
-            .connect_timeout(std::time::Duration::from_secs(60));
-        #[cfg(target_arch = "wasm32")]
-        let client = reqwest::Client::builder();
-        #[cfg(not(target_arch = "wasm32"))]
-        let client_http1 = reqwest::Client::builder()
-            // For file conversions we need this to be long.
-            .user_agent(APP_USER_AGENT)
-            .timeout(std::time::Duration::from_secs(600))
-            .connect_timeout(std::time::Duration::from_secs(60))
-            .http1_only();
-        #[cfg(not(target_arch = "wasm32"))]
-        return Self::new_from_reqwest(token, client, client_http1);
-        #[cfg(target_arch = "wasm32")]
-        Self::new_from_reqwest(token, client)
+        match ClientBuilder::new().token(token).build() {
+            Ok(c) => c,
+            Err(e) => panic!("creating reqwest client failed: {e:?}"),
+        }
+    }
+
+    /// Create a [`ClientBuilder`] to configure authentication and connection settings.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
     }
 
     /// Set the base URL for the client to something other than the default: <https://your-paperles.url/api>.
@@ -311,13 +468,23 @@ impl Client {
         self.base_url = base_url.to_string().trim_end_matches('/').to_string();
     }
 
+    /// Return a client sending its requests with a different retry policy, e.g. to
+    /// retry a request that is known to be idempotent or to fail fast.
+    #[cfg(feature = "retry")]
+    pub fn with_retry_policy(&self, retry_policy: retry::RetryPolicy) -> Self {
+        Client {
+            retry_policy: Some(retry_policy),
+            ..self.clone()
+        }
+    }
+
     /// Create a new Client struct from the environment variable: `ENV_VARIABLE_PREFIX_API_TOKEN`.
     #[tracing::instrument]
     pub fn new_from_env() -> Self {
         let token = env::var("PAPERLESS_API_CLIENT_API_TOKEN")
             .expect("must set PAPERLESS_API_CLIENT_API_TOKEN");
-        let base_url = env::var("PAPERLESS_API_CLIENT_HOST")
-            .unwrap_or("https://your-paperles.url/api".to_string());
+        let base_url =
+            env::var("PAPERLESS_API_CLIENT_HOST").unwrap_or(DEFAULT_BASE_URL.to_string());
 
         let mut c = Client::new(token);
         c.set_base_url(base_url);
@@ -341,13 +508,9 @@ impl Client {
         let mut req = self.client.request(method, &u);
 
         // Add in our authentication.
-        req = req.bearer_auth(&self.token);
+        req = self.authenticate(req).await?;
 
         // Set the default headers.
-        req = req.header(
-            reqwest::header::ACCEPT,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
         req = req.header(
             reqwest::header::CONTENT_TYPE,
             reqwest::header::HeaderValue::from_static("application/json"),
@@ -360,6 +523,87 @@ impl Client {
         Ok(RequestBuilder(req))
     }
 
+    /// The `Accept` header, including the pinned API version.
+    fn accept_header(&self) -> reqwest::header::HeaderValue {
+        match self.api_version {
+            Some(version) => reqwest::header::HeaderValue::from_str(&format!(
+                "application/json; version={version}"
+            ))
+            .expect("a version number is a valid header value"),
+            None => reqwest::header::HeaderValue::from_static("application/json"),
+        }
+    }
+
+    /// Return what the server supports, read from the version headers and the endpoints
+    /// of the API root. The result is fetched once and shared by all clones of the client.
+    #[tracing::instrument]
+    pub async fn server_capabilities(
+        &self,
+    ) -> Result<types::ServerCapabilities, types::error::Error> {
+        if let Some(capabilities) = self.capabilities.lock().unwrap().clone() {
+            return Ok(capabilities);
+        }
+        let mut req = self
+            .client
+            .request(http::Method::GET, format!("{}/{}", self.base_url, "api/"));
+        req = self.authenticate(req).await?;
+        let resp = req.send().await?;
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            return Err(types::error::Error::Server {
+                body: text.to_string(),
+                status,
+            });
+        }
+        let header = |name: &str| {
+            resp.headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let api_version = header("X-Api-Version").and_then(|v| v.trim().parse().ok());
+        let server_version = header("X-Version");
+        let text = resp.text().await.unwrap_or_default();
+        let endpoints = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&text)
+            .map(|root| root.keys().cloned().collect())
+            .unwrap_or_default();
+        let capabilities = types::ServerCapabilities {
+            api_version,
+            server_version,
+            endpoints,
+        };
+        *self.capabilities.lock().unwrap() = Some(capabilities.clone());
+        Ok(capabilities)
+    }
+
+    /// Fail with [`types::error::Error::Unsupported`] if the server is known to lack a
+    /// capability. If the capabilities can't be determined the check passes and the
+    /// request itself reports the problem; the next check tries to fetch them again.
+    pub async fn require_capability(
+        &self,
+        capability: types::Capability,
+    ) -> Result<(), types::error::Error> {
+        match self.server_capabilities().await {
+            Ok(capabilities) => capabilities.require(capability),
+            Err(err) => {
+                tracing::debug!("server capabilities unknown, assuming {capability}: {err}");
+                Ok(())
+            }
+        }
+    }
+
+    /// Return a stream of the consumer progress messages the server pushes over its
+    /// status websocket. The connection sends the same authentication headers as
+    /// every other request, whichever way the client authenticates, and reconnects
+    /// automatically when it drops.
+    #[cfg(feature = "websocket")]
+    pub fn status_updates(
+        &self,
+    ) -> impl futures::Stream<Item = Result<types::StatusUpdate, types::error::Error>> + Unpin {
+        websocket::status_updates(self.clone())
+    }
+
     /// Return a reference to an interface that provides access to bulk_edit_objects operations.
     pub fn bulk_edit_objects(&self) -> bulk_edit_objects::BulkEditObjects {
         bulk_edit_objects::BulkEditObjects::new(self.clone())
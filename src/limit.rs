@@ -0,0 +1,122 @@
+#[doc = " Limits on the requests a client sends, shared by all its clones."]
+#[doc = ""]
+#[doc = " A request counts as in flight until its response headers arrived; retries of"]
+#[doc = " a request are limited like new requests."]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RateLimit {
+    #[doc = " The maximum number of requests in flight at once."]
+    pub max_concurrent: Option<usize>,
+    #[doc = " The maximum average number of requests started per second."]
+    pub requests_per_second: Option<f64>,
+    #[doc = " The number of requests that may start at once before `requests_per_second`"]
+    #[doc = " applies, at least one."]
+    pub burst: u32,
+}
+
+impl RateLimit {
+    #[doc = " No limits."]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[doc = " Limit the number of requests in flight at once."]
+    pub fn max_concurrent(mut self, max_concurrent: usize) -> Self {
+        self.max_concurrent = Some(max_concurrent);
+        self
+    }
+
+    #[doc = " Limit the requests started per second, allowing bursts of `burst` requests."]
+    pub fn requests_per_second(mut self, requests_per_second: f64, burst: u32) -> Self {
+        self.requests_per_second = Some(requests_per_second);
+        self.burst = burst;
+        self
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct TokenBucket {
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    pub(crate) updated: std::time::Instant,
+}
+
+impl TokenBucket {
+    pub(crate) fn new(rate: f64, burst: u32) -> Self {
+        let capacity = f64::from(burst.max(1));
+        Self {
+            rate,
+            capacity,
+            tokens: capacity,
+            updated: std::time::Instant::now(),
+        }
+    }
+
+    #[doc = " Take a token and return how long to wait until it is available."]
+    pub(crate) fn reserve(&mut self, now: std::time::Instant) -> std::time::Duration {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated = now;
+        // Tokens may go negative, queueing later callers behind earlier ones.
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            std::time::Duration::ZERO
+        } else {
+            // A tiny rate makes the wait too long for a `Duration`.
+            std::time::Duration::try_from_secs_f64(-self.tokens / self.rate)
+                .unwrap_or(std::time::Duration::MAX)
+        }
+    }
+}
+
+#[doc = " Middleware enforcing a [`RateLimit`]."]
+#[derive(Clone, Debug)]
+pub struct LimitMiddleware {
+    semaphore: Option<std::sync::Arc<tokio::sync::Semaphore>>,
+    bucket: Option<std::sync::Arc<std::sync::Mutex<TokenBucket>>>,
+}
+
+impl LimitMiddleware {
+    #[doc = " Create the middleware; its clones share the limits."]
+    pub fn new(limit: &RateLimit) -> Self {
+        Self {
+            semaphore: limit
+                .max_concurrent
+                .map(|n| std::sync::Arc::new(tokio::sync::Semaphore::new(n.max(1)))),
+            bucket: limit
+                .requests_per_second
+                .filter(|rate| *rate > 0.0)
+                .map(|rate| {
+                    std::sync::Arc::new(std::sync::Mutex::new(TokenBucket::new(rate, limit.burst)))
+                }),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for LimitMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut http::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        // Wait for a token first so sleeping requests don't hold a permit.
+        if let Some(bucket) = &self.bucket {
+            let wait = bucket.lock().unwrap().reserve(std::time::Instant::now());
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+        }
+        let _permit = match &self.semaphore {
+            Some(semaphore) => Some(
+                semaphore
+                    .acquire()
+                    .await
+                    .map_err(|e| reqwest_middleware::Error::Middleware(e.into()))?,
+            ),
+            None => None,
+        };
+        next.run(req, extensions).await
+    }
+}
@@ -19,7 +19,7 @@ impl Logs {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/logs/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -54,7 +54,7 @@ impl Logs {
                 "api/logs/{id}/".replace("{id}", id)
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
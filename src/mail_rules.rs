@@ -23,7 +23,7 @@ impl MailRules {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/mail_rules/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = page {
             query_params.push(("page", format!("{p}")));
@@ -63,70 +63,10 @@ impl MailRules {
     ) -> impl futures::Stream<Item = Result<crate::types::MailRule, crate::types::error::Error>>
            + Unpin
            + '_ {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(None, page_size)
-            .map_ok(move |result| {
-                let items = futures::stream::iter(result.items().into_iter().map(Ok));
-                let next_pages = futures::stream::try_unfold(
-                    (None, result),
-                    move |(prev_page_token, new_result)| async move {
-                        if new_result.has_more_pages()
-                            && !new_result.items().is_empty()
-                            && prev_page_token != new_result.next_page_token()
-                        {
-                            async {
-                                let mut req = self.client.client.request(
-                                    http::Method::GET,
-                                    format!("{}/{}", self.client.base_url, "api/mail_rules/"),
-                                );
-                                req = req.header(
-                                    "Authorization",
-                                    format!("Token {}", &self.client.token),
-                                );
-                                let mut request = req.build()?;
-                                request = new_result.next_page(request)?;
-                                let resp = self.client.client.execute(request).await?;
-                                let status = resp.status();
-                                if status.is_success() {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    serde_json::from_str(&text).map_err(|err| {
-                                        crate::types::error::Error::from_serde_error(
-                                            format_serde_error::SerdeError::new(
-                                                text.to_string(),
-                                                err,
-                                            ),
-                                            status,
-                                        )
-                                    })
-                                } else {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    Err(crate::types::error::Error::Server {
-                                        body: text.to_string(),
-                                        status,
-                                    })
-                                }
-                            }
-                            .map_ok(|result: crate::types::PaginatedMailRuleList| {
-                                Some((
-                                    futures::stream::iter(result.items().into_iter().map(Ok)),
-                                    (new_result.next_page_token(), result),
-                                ))
-                            })
-                            .await
-                        } else {
-                            Ok(None)
-                        }
-                    },
-                )
-                .try_flatten();
-                items.chain(next_pages)
-            })
-            .try_flatten_stream()
-            .boxed()
+        crate::paginator::list_items(&self.client, self.list(None, page_size))
     }
 
-    #[doc = "Perform a `POST` request to `/api/mail_rules/`.\n\n```rust,no_run\nasync fn example_mail_rules_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::MailRule = client\n        .mail_rules()\n        .create(&paperless_api_client::types::MailRuleRequest {\n            name: \"some-string\".to_string(),\n            account: 4 as i64,\n            enabled: Some(true),\n            folder: Some(\"some-string\".to_string()),\n            filter_from: Some(\"some-string\".to_string()),\n            filter_to: Some(\"some-string\".to_string()),\n            filter_subject: Some(\"some-string\".to_string()),\n            filter_body: Some(\"some-string\".to_string()),\n            filter_attachment_filename_include: Some(\"some-string\".to_string()),\n            filter_attachment_filename_exclude: Some(\"some-string\".to_string()),\n            maximum_age: Some(4 as i64),\n            action: Some(4 as i64),\n            action_parameter: Some(\"some-string\".to_string()),\n            assign_title_from: Some(4 as i64),\n            assign_tags: Some(vec![4 as i64]),\n            assign_correspondent_from: Some(4 as i64),\n            assign_correspondent: Some(4 as i64),\n            assign_document_type: Some(4 as i64),\n            assign_owner_from_rule: Some(true),\n            order: Some(4 as i64),\n            attachment_type: Some(4 as i64),\n            consumption_scope: Some(4 as i64),\n            pdf_layout: Some(4 as i64),\n            owner: Some(4 as i64),\n            set_permissions: Some(paperless_api_client::types::SetPermissions {\n                view: Some(paperless_api_client::types::View {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n                change: Some(paperless_api_client::types::Change {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n            }),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `POST` request to `/api/mail_rules/`.\n\n```rust,no_run\nasync fn example_mail_rules_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::MailRule = client\n        .mail_rules()\n        .create(&paperless_api_client::types::MailRuleRequest {\n            name: \"some-string\".to_string(),\n            account: 4 as i64,\n            enabled: Some(true),\n            folder: Some(\"some-string\".to_string()),\n            filter_from: Some(\"some-string\".to_string()),\n            filter_to: Some(\"some-string\".to_string()),\n            filter_subject: Some(\"some-string\".to_string()),\n            filter_body: Some(\"some-string\".to_string()),\n            filter_attachment_filename_include: Some(\"some-string\".to_string()),\n            filter_attachment_filename_exclude: Some(\"some-string\".to_string()),\n            maximum_age: Some(4 as i64),\n            action: Some(paperless_api_client::types::MailRuleAction::Flag),\n            action_parameter: Some(\"some-string\".to_string()),\n            assign_title_from: Some(paperless_api_client::types::MailTitleSource::Subject),\n            assign_tags: Some(vec![Some(4 as i64)]),\n            assign_correspondent_from: Some(paperless_api_client::types::MailCorrespondentSource::Custom),\n            assign_correspondent: Some(4 as i64),\n            assign_document_type: Some(4 as i64),\n            assign_owner_from_rule: Some(true),\n            order: Some(4 as i64),\n            attachment_type: Some(paperless_api_client::types::MailAttachmentType::Attachments),\n            consumption_scope: Some(paperless_api_client::types::MailConsumptionScope::Attachments),\n            pdf_layout: Some(paperless_api_client::types::MailPdfLayout::Default),\n            owner: Some(4 as i64),\n            set_permissions: Some(paperless_api_client::types::SetPermissions {\n                view: Some(paperless_api_client::types::View {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n                change: Some(paperless_api_client::types::Change {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n            }),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn create<'a>(
@@ -137,7 +77,7 @@ impl MailRules {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/mail_rules/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -173,7 +113,7 @@ impl MailRules {
                 "api/mail_rules/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -193,7 +133,7 @@ impl MailRules {
         }
     }
 
-    #[doc = "Perform a `PUT` request to `/api/mail_rules/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this mail rule. (required)\n\n```rust,no_run\nasync fn example_mail_rules_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::MailRule = client\n        .mail_rules()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::MailRuleRequest {\n                name: \"some-string\".to_string(),\n                account: 4 as i64,\n                enabled: Some(true),\n                folder: Some(\"some-string\".to_string()),\n                filter_from: Some(\"some-string\".to_string()),\n                filter_to: Some(\"some-string\".to_string()),\n                filter_subject: Some(\"some-string\".to_string()),\n                filter_body: Some(\"some-string\".to_string()),\n                filter_attachment_filename_include: Some(\"some-string\".to_string()),\n                filter_attachment_filename_exclude: Some(\"some-string\".to_string()),\n                maximum_age: Some(4 as i64),\n                action: Some(4 as i64),\n                action_parameter: Some(\"some-string\".to_string()),\n                assign_title_from: Some(4 as i64),\n                assign_tags: Some(vec![4 as i64]),\n                assign_correspondent_from: Some(4 as i64),\n                assign_correspondent: Some(4 as i64),\n                assign_document_type: Some(4 as i64),\n                assign_owner_from_rule: Some(true),\n                order: Some(4 as i64),\n                attachment_type: Some(4 as i64),\n                consumption_scope: Some(4 as i64),\n                pdf_layout: Some(4 as i64),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PUT` request to `/api/mail_rules/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this mail rule. (required)\n\n```rust,no_run\nasync fn example_mail_rules_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::MailRule = client\n        .mail_rules()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::MailRuleRequest {\n                name: \"some-string\".to_string(),\n                account: 4 as i64,\n                enabled: Some(true),\n                folder: Some(\"some-string\".to_string()),\n                filter_from: Some(\"some-string\".to_string()),\n                filter_to: Some(\"some-string\".to_string()),\n                filter_subject: Some(\"some-string\".to_string()),\n                filter_body: Some(\"some-string\".to_string()),\n                filter_attachment_filename_include: Some(\"some-string\".to_string()),\n                filter_attachment_filename_exclude: Some(\"some-string\".to_string()),\n                maximum_age: Some(4 as i64),\n                action: Some(paperless_api_client::types::MailRuleAction::Flag),\n                action_parameter: Some(\"some-string\".to_string()),\n                assign_title_from: Some(paperless_api_client::types::MailTitleSource::Subject),\n                assign_tags: Some(vec![Some(4 as i64)]),\n                assign_correspondent_from: Some(paperless_api_client::types::MailCorrespondentSource::Custom),\n                assign_correspondent: Some(4 as i64),\n                assign_document_type: Some(4 as i64),\n                assign_owner_from_rule: Some(true),\n                order: Some(4 as i64),\n                attachment_type: Some(paperless_api_client::types::MailAttachmentType::Attachments),\n                consumption_scope: Some(paperless_api_client::types::MailConsumptionScope::Attachments),\n                pdf_layout: Some(paperless_api_client::types::MailPdfLayout::Default),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn update<'a>(
@@ -209,7 +149,7 @@ impl MailRules {
                 "api/mail_rules/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -242,7 +182,7 @@ impl MailRules {
                 "api/mail_rules/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -256,7 +196,7 @@ impl MailRules {
         }
     }
 
-    #[doc = "Perform a `PATCH` request to `/api/mail_rules/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this mail rule. (required)\n\n```rust,no_run\nasync fn example_mail_rules_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::MailRule = client\n        .mail_rules()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedMailRuleRequest {\n                name: Some(\"some-string\".to_string()),\n                account: Some(4 as i64),\n                enabled: Some(true),\n                folder: Some(\"some-string\".to_string()),\n                filter_from: Some(\"some-string\".to_string()),\n                filter_to: Some(\"some-string\".to_string()),\n                filter_subject: Some(\"some-string\".to_string()),\n                filter_body: Some(\"some-string\".to_string()),\n                filter_attachment_filename_include: Some(\"some-string\".to_string()),\n                filter_attachment_filename_exclude: Some(\"some-string\".to_string()),\n                maximum_age: Some(4 as i64),\n                action: Some(4 as i64),\n                action_parameter: Some(\"some-string\".to_string()),\n                assign_title_from: Some(4 as i64),\n                assign_tags: Some(vec![4 as i64]),\n                assign_correspondent_from: Some(4 as i64),\n                assign_correspondent: Some(4 as i64),\n                assign_document_type: Some(4 as i64),\n                assign_owner_from_rule: Some(true),\n                order: Some(4 as i64),\n                attachment_type: Some(4 as i64),\n                consumption_scope: Some(4 as i64),\n                pdf_layout: Some(4 as i64),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PATCH` request to `/api/mail_rules/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this mail rule. (required)\n\n```rust,no_run\nasync fn example_mail_rules_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::MailRule = client\n        .mail_rules()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedMailRuleRequest {\n                name: Some(\"some-string\".to_string()),\n                account: Some(4 as i64),\n                enabled: Some(true),\n                folder: Some(\"some-string\".to_string()),\n                filter_from: Some(\"some-string\".to_string()),\n                filter_to: Some(\"some-string\".to_string()),\n                filter_subject: Some(\"some-string\".to_string()),\n                filter_body: Some(\"some-string\".to_string()),\n                filter_attachment_filename_include: Some(\"some-string\".to_string()),\n                filter_attachment_filename_exclude: Some(\"some-string\".to_string()),\n                maximum_age: Some(4 as i64),\n                action: Some(paperless_api_client::types::MailRuleAction::Flag),\n                action_parameter: Some(\"some-string\".to_string()),\n                assign_title_from: Some(paperless_api_client::types::MailTitleSource::Subject),\n                assign_tags: Some(vec![Some(4 as i64)]),\n                assign_correspondent_from: Some(paperless_api_client::types::MailCorrespondentSource::Custom),\n                assign_correspondent: Some(4 as i64),\n                assign_document_type: Some(4 as i64),\n                assign_owner_from_rule: Some(true),\n                order: Some(4 as i64),\n                attachment_type: Some(paperless_api_client::types::MailAttachmentType::Attachments),\n                consumption_scope: Some(paperless_api_client::types::MailConsumptionScope::Attachments),\n                pdf_layout: Some(paperless_api_client::types::MailPdfLayout::Default),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn partial_update<'a>(
@@ -272,7 +212,7 @@ impl MailRules {
                 "api/mail_rules/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -0,0 +1,307 @@
+#![doc = " Offline evaluation of the automatic matching of tags, correspondents, document"]
+#![doc = " types and storage paths, following the rules of the server."]
+
+use crate::types::MatchingAlgorithm;
+
+#[doc = " The minimum fuzzy similarity, in percent, the server requires for a match."]
+pub const FUZZY_THRESHOLD: f64 = 90.0;
+
+#[doc = " The match settings of an object: its pattern, algorithm and case sensitivity."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchRule {
+    #[doc = " The words, text or pattern to look for."]
+    pub pattern: String,
+    #[doc = " How `pattern` is matched."]
+    pub algorithm: MatchingAlgorithm,
+    #[doc = " Whether case is ignored."]
+    pub is_insensitive: bool,
+}
+
+#[doc = " Objects with match settings."]
+pub trait Matchable {
+    #[doc = " The match settings, with the server's defaults for unset fields."]
+    fn match_rule(&self) -> MatchRule;
+}
+
+macro_rules! impl_matchable {
+    ($($t:ty),*) => {
+        $(
+            impl Matchable for $t {
+                fn match_rule(&self) -> MatchRule {
+                    MatchRule {
+                        pattern: self.match_.clone().unwrap_or_default(),
+                        algorithm: self.matching_algorithm.unwrap_or(MatchingAlgorithm::Any),
+                        is_insensitive: self.is_insensitive.unwrap_or(true),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_matchable!(
+    crate::types::Tag,
+    crate::types::TagRequest,
+    crate::types::Correspondent,
+    crate::types::CorrespondentRequest,
+    crate::types::DocumentType,
+    crate::types::DocumentTypeRequest,
+    crate::types::StoragePath,
+    crate::types::StoragePathRequest
+);
+
+#[doc = " Why a rule did or did not match."]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MatchDetail {
+    #[doc = " The rule never matches: the algorithm is `none` or the pattern is blank."]
+    Disabled,
+    #[doc = " The algorithm can't be evaluated locally, e.g. `auto`, which uses the"]
+    #[doc = " classifier trained on the server."]
+    Unsupported(MatchingAlgorithm),
+    #[doc = " The pattern is not a valid regular expression, or uses features like"]
+    #[doc = " look-around that are only supported by the server's regex engine."]
+    InvalidPattern(String),
+    #[doc = " The words of an `any` or `all` rule that were found and those that were not."]
+    Words {
+        #[doc = " The words found."]
+        found: Vec<String>,
+        #[doc = " The words not found."]
+        missing: Vec<String>,
+    },
+    #[doc = " The text matched by a `literal` or `regex` rule, if any."]
+    Text(Option<String>),
+    #[doc = " The similarity of a `fuzzy` rule, in percent."]
+    Fuzzy(f64),
+}
+
+#[doc = " The result of evaluating a [`MatchRule`] against document content."]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchExplanation {
+    #[doc = " Whether the rule matched."]
+    pub matched: bool,
+    #[doc = " Why it did or did not match."]
+    pub detail: MatchDetail,
+}
+
+impl std::fmt::Display for MatchExplanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let verdict = if self.matched { "matched" } else { "no match" };
+        match &self.detail {
+            MatchDetail::Disabled => write!(f, "{verdict}: matching is disabled"),
+            MatchDetail::Unsupported(algorithm) => {
+                write!(
+                    f,
+                    "{verdict}: algorithm {algorithm} can't be evaluated locally"
+                )
+            }
+            MatchDetail::InvalidPattern(err) => write!(f, "{verdict}: invalid pattern: {err}"),
+            MatchDetail::Words { found, missing } => {
+                write!(f, "{verdict}: found {:?}, missing {:?}", found, missing)
+            }
+            MatchDetail::Text(Some(text)) => write!(f, "{verdict}: found {text:?}"),
+            MatchDetail::Text(None) => write!(f, "{verdict}: text not found"),
+            MatchDetail::Fuzzy(score) => write!(
+                f,
+                "{verdict}: similarity {score:.1}%, {FUZZY_THRESHOLD}% required"
+            ),
+        }
+    }
+}
+
+impl MatchRule {
+    #[doc = " A rule matching `pattern` with `algorithm`, ignoring case."]
+    pub fn new(pattern: impl Into<String>, algorithm: MatchingAlgorithm) -> Self {
+        Self {
+            pattern: pattern.into(),
+            algorithm,
+            is_insensitive: true,
+        }
+    }
+
+    #[doc = " Match case."]
+    pub fn case_sensitive(mut self) -> Self {
+        self.is_insensitive = false;
+        self
+    }
+
+    #[doc = " Returns true if the rule matches `content`."]
+    pub fn matches(&self, content: &str) -> bool {
+        self.explain(content).matched
+    }
+
+    #[doc = " Evaluate the rule against `content` and tell why it did or did not match."]
+    pub fn explain(&self, content: &str) -> MatchExplanation {
+        if self.pattern.trim().is_empty() {
+            return MatchExplanation {
+                matched: false,
+                detail: MatchDetail::Disabled,
+            };
+        }
+        match self.algorithm {
+            MatchingAlgorithm::None => MatchExplanation {
+                matched: false,
+                detail: MatchDetail::Disabled,
+            },
+            MatchingAlgorithm::Any | MatchingAlgorithm::All => self.explain_words(content),
+            MatchingAlgorithm::Literal => {
+                self.explain_regex(&format!(r"\b{}\b", regex::escape(&self.pattern)), content)
+            }
+            MatchingAlgorithm::Regex => self.explain_regex(&self.pattern, content),
+            MatchingAlgorithm::Fuzzy => {
+                let clean = |text: &str| -> Vec<char> {
+                    text.chars()
+                        .filter(|c| c.is_alphanumeric() || *c == '_' || c.is_whitespace())
+                        .flat_map(|c| {
+                            if self.is_insensitive {
+                                c.to_lowercase().collect::<Vec<_>>()
+                            } else {
+                                vec![c]
+                            }
+                        })
+                        .collect()
+                };
+                let score = partial_ratio(&clean(&self.pattern), &clean(content));
+                MatchExplanation {
+                    matched: score >= FUZZY_THRESHOLD,
+                    detail: MatchDetail::Fuzzy(score),
+                }
+            }
+            MatchingAlgorithm::Auto | MatchingAlgorithm::Unknown(_) => MatchExplanation {
+                matched: false,
+                detail: MatchDetail::Unsupported(self.algorithm),
+            },
+        }
+    }
+
+    fn regex(&self, pattern: &str) -> Result<regex::Regex, regex::Error> {
+        regex::RegexBuilder::new(pattern)
+            .case_insensitive(self.is_insensitive)
+            .build()
+    }
+
+    fn explain_regex(&self, pattern: &str, content: &str) -> MatchExplanation {
+        match self.regex(pattern) {
+            Ok(regex) => {
+                let found = regex.find(content).map(|m| m.as_str().to_string());
+                MatchExplanation {
+                    matched: found.is_some(),
+                    detail: MatchDetail::Text(found),
+                }
+            }
+            Err(err) => MatchExplanation {
+                matched: false,
+                detail: MatchDetail::InvalidPattern(err.to_string()),
+            },
+        }
+    }
+
+    fn explain_words(&self, content: &str) -> MatchExplanation {
+        let mut found = vec![];
+        let mut missing = vec![];
+        for word in split_words(&self.pattern) {
+            // Words of quoted phrases may be separated by any whitespace.
+            let pattern = word
+                .split(' ')
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join(r"\s+");
+            match self.regex(&format!(r"\b{pattern}\b")) {
+                Ok(regex) if regex.is_match(content) => found.push(word),
+                Ok(_) => missing.push(word),
+                Err(err) => {
+                    return MatchExplanation {
+                        matched: false,
+                        detail: MatchDetail::InvalidPattern(err.to_string()),
+                    }
+                }
+            }
+        }
+        let matched = match self.algorithm {
+            MatchingAlgorithm::All => missing.is_empty(),
+            _ => !found.is_empty(),
+        };
+        MatchExplanation {
+            matched,
+            detail: MatchDetail::Words { found, missing },
+        }
+    }
+}
+
+#[doc = " Split a pattern into words, keeping quoted phrases together with their spaces"]
+#[doc = " normalized, e.g. `a \"b  c\"` into `a` and `b c`."]
+fn split_words(pattern: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut rest = pattern;
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let quoted = rest.strip_prefix('"').and_then(|inner| {
+            inner
+                .find('"')
+                .filter(|end| *end > 0)
+                .map(|end| (inner, end))
+        });
+        let (word, next) = match quoted {
+            Some((inner, end)) => (&inner[..end], &inner[end + 1..]),
+            None => {
+                let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                (&rest[..end], &rest[end..])
+            }
+        };
+        let word = word.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !word.is_empty() {
+            words.push(word);
+        }
+        rest = next;
+    }
+    words
+}
+
+#[doc = " The similarity of two texts in percent, based on their longest common subsequence."]
+fn ratio(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 100.0;
+    }
+    let mut row = vec![0usize; b.len() + 1];
+    for x in a {
+        let mut diagonal = 0;
+        for (j, y) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if x == y {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    200.0 * row[b.len()] as f64 / (a.len() + b.len()) as f64
+}
+
+#[doc = " The best [`ratio`] of the shorter text against any part of the longer one of the"]
+#[doc = " same length, including parts cut off at the start or end of the longer text."]
+fn partial_ratio(a: &[char], b: &[char]) -> f64 {
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    if short.is_empty() {
+        return if long.is_empty() { 100.0 } else { 0.0 };
+    }
+    let len = short.len();
+    let mut best: f64 = 0.0;
+    let mut consider = |window: &[char]| {
+        best = best.max(ratio(short, window));
+        best >= 100.0
+    };
+    for end in 1..len {
+        if consider(&long[..end]) || consider(&long[long.len() - end..]) {
+            return 100.0;
+        }
+    }
+    for start in 0..=long.len() - len {
+        if consider(&long[start..start + len]) {
+            return 100.0;
+        }
+    }
+    best
+}
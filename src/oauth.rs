@@ -19,7 +19,7 @@ impl Oauth {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/oauth/callback/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
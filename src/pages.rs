@@ -0,0 +1,276 @@
+use crate::Client;
+use anyhow::Result;
+
+#[doc = " Page operations on a single document, built on `/api/documents/bulk_edit/`."]
+#[derive(Clone, Debug)]
+pub struct Pages {
+    pub client: Client,
+    pub doc_id: i64,
+}
+
+impl Pages {
+    #[doc(hidden)]
+    pub fn new(client: Client, doc_id: i64) -> Self {
+        Self { client, doc_id }
+    }
+
+    async fn apply(
+        &self,
+        operation: crate::types::BulkOperation,
+        documents: Vec<i64>,
+        in_place: bool,
+    ) -> Result<PageEdit, crate::types::error::Error> {
+        let since = tasks_since();
+        // Read the modification time before sending the edit, so a save of the edited
+        // document can't slip in before the snapshot.
+        let mut modified = None;
+        if in_place {
+            let document = self
+                .client
+                .documents()
+                .retrieve(self.doc_id, None, None)
+                .await?;
+            modified = Some(document.modified);
+        }
+        let merged = matches!(operation, crate::types::BulkOperation::Merge { .. });
+        self.client
+            .documents()
+            .bulk_edit(operation.on(documents.clone()))
+            .await?;
+        let mut edit = PageEdit {
+            client: self.client.clone(),
+            doc_id: self.doc_id,
+            in_place,
+            tasks: vec![],
+            modified,
+        };
+        if !in_place {
+            let tasks = self
+                .client
+                .tasks()
+                .list(
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(crate::types::ListTaskName::ConsumeFile),
+                    None,
+                )
+                .await?;
+            edit.tasks = spawned_tasks(&tasks, since, &documents, merged);
+        }
+        Ok(edit)
+    }
+
+    #[doc = "Rotate all pages of the document clockwise.\n\n```rust,no_run\nasync fn example_pages_rotate() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let edit = client\n        .documents()\n        .pages(4 as i64)\n        .rotate(paperless_api_client::types::Rotation::Degrees90)\n        .await?;\n    println!(\"{:?}\", edit);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn rotate<'a>(
+        &'a self,
+        rotation: crate::types::Rotation,
+    ) -> Result<PageEdit, crate::types::error::Error> {
+        self.apply(
+            crate::types::BulkOperation::Rotate { rotation },
+            vec![self.doc_id],
+            true,
+        )
+        .await
+    }
+
+    #[doc = "Delete pages of the document.\n\n```rust,no_run\nasync fn example_pages_delete() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let edit = client\n        .documents()\n        .pages(4 as i64)\n        .delete(vec![paperless_api_client::types::PageRange::new(2, 3)])\n        .await?;\n    println!(\"{:?}\", edit);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn delete<'a>(
+        &'a self,
+        pages: Vec<crate::types::PageRange>,
+    ) -> Result<PageEdit, crate::types::error::Error> {
+        self.apply(
+            crate::types::BulkOperation::DeletePages { pages },
+            vec![self.doc_id],
+            true,
+        )
+        .await
+    }
+
+    #[doc = "Split the document into one new document per page range.\n\n```rust,no_run\nasync fn example_pages_split() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let documents = client\n        .documents()\n        .pages(4 as i64)\n        .split(\n            vec![\n                paperless_api_client::types::PageRange::new(1, 2),\n                paperless_api_client::types::PageRange::page(3),\n            ],\n            false,\n        )\n        .await?\n        .wait(&paperless_api_client::types::TaskWaitOptions::default())\n        .await?;\n    println!(\"{:?}\", documents);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn split<'a>(
+        &'a self,
+        ranges: Vec<crate::types::PageRange>,
+        delete_original: bool,
+    ) -> Result<PageEdit, crate::types::error::Error> {
+        self.apply(
+            crate::types::BulkOperation::Split {
+                pages: ranges,
+                delete_originals: delete_original,
+            },
+            vec![self.doc_id],
+            false,
+        )
+        .await
+    }
+
+    #[doc = "Merge the document and the documents `ids`, in this order, into a new document.\n\nThe new document takes the metadata of `metadata_document_id`, or none if it is `None`.\n\n```rust,no_run\nasync fn example_pages_merge() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let edit = client\n        .documents()\n        .pages(4 as i64)\n        .merge(vec![5 as i64], Some(4 as i64), false)\n        .await?;\n    println!(\"{:?}\", edit.tasks);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn merge<'a>(
+        &'a self,
+        ids: Vec<i64>,
+        metadata_document_id: Option<i64>,
+        delete_originals: bool,
+    ) -> Result<PageEdit, crate::types::error::Error> {
+        let documents = std::iter::once(self.doc_id).chain(ids).collect();
+        self.apply(
+            crate::types::BulkOperation::Merge {
+                metadata_document_id,
+                delete_originals,
+                archive_fallback: false,
+            },
+            documents,
+            false,
+        )
+        .await
+    }
+
+    #[doc = "Rearrange, rotate and split pages with the server's PDF editor.\n\nWith `update_document` the document itself is replaced, otherwise a new document is created for every output document of the operations.\n\n```rust,no_run\nasync fn example_pages_edit() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let edit = client\n        .documents()\n        .pages(4 as i64)\n        .edit(\n            vec![\n                paperless_api_client::types::EditPdfOperation::page(2),\n                paperless_api_client::types::EditPdfOperation::page(1)\n                    .rotate(paperless_api_client::types::Rotation::Degrees180),\n            ],\n            false,\n            true,\n        )\n        .await?;\n    println!(\"{:?}\", edit);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn edit<'a>(
+        &'a self,
+        operations: Vec<crate::types::EditPdfOperation>,
+        delete_original: bool,
+        update_document: bool,
+    ) -> Result<PageEdit, crate::types::error::Error> {
+        self.apply(
+            crate::types::BulkOperation::EditPdf {
+                operations,
+                delete_original,
+                update_document,
+                include_metadata: true,
+            },
+            vec![self.doc_id],
+            update_document,
+        )
+        .await
+    }
+}
+
+#[doc = " The result of a page operation."]
+#[doc = ""]
+#[doc = " Rotations, page deletions and in-place edits change the document before the"]
+#[doc = " request returns; the server then refreshes its archived version and content in"]
+#[doc = " the background. Splits, merges and edits creating new documents spawn one"]
+#[doc = " consumption task per new document."]
+#[derive(Clone, Debug)]
+pub struct PageEdit {
+    client: Client,
+    #[doc = " The edited document, the first one of a merge."]
+    pub doc_id: i64,
+    #[doc = " Whether the document was changed in place instead of creating new documents."]
+    pub in_place: bool,
+    #[doc = " The Celery ids of the consumption tasks creating new documents."]
+    #[doc = ""]
+    #[doc = " The server doesn't report which tasks an operation spawned, so these are"]
+    #[doc = " best-effort: the consumption tasks created since the call whose file is named"]
+    #[doc = " after the source documents, e.g. `12_1.pdf` for a split of document 12. A"]
+    #[doc = " task may be missed, or one of a concurrent edit of the same documents included."]
+    pub tasks: Vec<String>,
+    modified: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl PageEdit {
+    #[doc = " Wait for the resulting documents."]
+    #[doc = ""]
+    #[doc = " For in-place changes this polls the document until its modification time moved"]
+    #[doc = " past the one read before the edit was sent, and returns it then. Its content and"]
+    #[doc = " archived version may still be refreshed in the background afterwards. If no"]
+    #[doc = " later save is seen, this blocks until the timeout of `options` passes and"]
+    #[doc = " returns the document as it is. Otherwise it waits for the documents created by"]
+    #[doc = " [`PageEdit::tasks`]."]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn wait(
+        &self,
+        options: &crate::types::TaskWaitOptions,
+    ) -> Result<Vec<crate::types::Document>, crate::types::error::Error> {
+        let documents = self.client.documents();
+        if self.in_place {
+            let started = std::time::Instant::now();
+            let mut interval = options.initial_interval;
+            loop {
+                let document = documents.retrieve(self.doc_id, None, None).await?;
+                let refreshed = self
+                    .modified
+                    .is_none_or(|modified| document.modified > modified);
+                match options.delay(started, interval) {
+                    Some(delay) if !refreshed => tokio::time::sleep(delay).await,
+                    _ => return Ok(vec![document]),
+                }
+                interval = options.next_interval(interval);
+            }
+        }
+        let mut created = Vec::with_capacity(self.tasks.len());
+        for task_id in &self.tasks {
+            let task = self.client.tasks().wait_for(task_id, options).await?;
+            match task.related_document_id() {
+                Some(doc_id) => created.push(documents.retrieve(doc_id, None, None).await?),
+                None => {
+                    return Err(crate::types::error::Error::TaskWithoutDocument {
+                        task_id: task.task_id,
+                        result: task.result,
+                    })
+                }
+            }
+        }
+        Ok(created)
+    }
+}
+
+#[doc = " How far the server's clock may run behind the client's when looking for the tasks"]
+#[doc = " an operation spawned."]
+const CLOCK_SKEW_SECS: i64 = 60;
+
+#[doc = " The earliest creation time of a task spawned by an operation starting now. The"]
+#[doc = " clock isn't available on wasm, where tasks are matched by file name only."]
+#[cfg(not(target_arch = "wasm32"))]
+fn tasks_since() -> Option<chrono::DateTime<chrono::Utc>> {
+    Some(chrono::Utc::now() - chrono::Duration::seconds(CLOCK_SKEW_SECS))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn tasks_since() -> Option<chrono::DateTime<chrono::Utc>> {
+    None
+}
+
+#[doc = " The ids of the consumption tasks among `tasks` created since `since` for files the"]
+#[doc = " server names after the source documents: `12_1.pdf` for the first part of a split"]
+#[doc = " of document 12, `12_edit_1.pdf` for an edit, and, if `merged`, `12_13_merged.pdf`"]
+#[doc = " for a merge."]
+pub(crate) fn spawned_tasks(
+    tasks: &[crate::types::TasksView],
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    documents: &[i64],
+    merged: bool,
+) -> Vec<String> {
+    let is_source = |part: &str| part.parse::<i64>().is_ok_and(|id| documents.contains(&id));
+    let named_after_source = |file_name: &str| {
+        let stem = file_name
+            .rsplit_once('.')
+            .map_or(file_name, |(stem, _)| stem);
+        match (stem.strip_suffix("_merged"), merged) {
+            (Some(ids), true) => ids.split('_').any(is_source),
+            (None, false) => stem
+                .split_once('_')
+                .is_some_and(|(first, _)| is_source(first)),
+            _ => false,
+        }
+    };
+    tasks
+        .iter()
+        .filter(|task| task.task_name == Some(crate::types::TaskName::ConsumeFile))
+        .filter(|task| {
+            since.is_none_or(|since| task.date_created.is_some_and(|created| created >= since))
+        })
+        .filter(|task| {
+            task.task_file_name
+                .as_deref()
+                .is_some_and(named_after_source)
+        })
+        .map(|task| task.task_id.clone())
+        .collect()
+}
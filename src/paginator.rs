@@ -0,0 +1,495 @@
+use crate::{types::paginate::Pagination, Client};
+use anyhow::Result;
+
+#[doc = " The position of a [`Paginator`]: the next page it fetches."]
+#[doc = ""]
+#[doc = " Cursors serialize to JSON, so a long crawl can be saved and continued later"]
+#[doc = " with [`Paginator::resume`]."]
+#[derive(
+    serde :: Serialize, serde :: Deserialize, PartialEq, Eq, Debug, Clone, schemars :: JsonSchema,
+)]
+pub struct PageCursor {
+    #[doc = " The absolute URL of the page."]
+    pub url: String,
+    #[doc = " Query parameters added to the URL. Only the first page has any, the URLs of"]
+    #[doc = " later pages come from the server and contain them already."]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub query: Vec<(String, String)>,
+}
+
+impl PageCursor {
+    #[doc = " The first page of `path`, relative to the base URL of `client`."]
+    pub fn first(client: &Client, path: &str, query: Vec<(String, String)>) -> Self {
+        Self {
+            url: format!("{}/{}", client.base_url, path.trim_start_matches('/')),
+            query,
+        }
+    }
+}
+
+impl std::fmt::Display for PageCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type PageStream<T> =
+    futures::stream::BoxStream<'static, (PageCursor, Result<T, crate::types::error::Error>)>;
+#[cfg(target_arch = "wasm32")]
+type PageStream<T> =
+    futures::stream::LocalBoxStream<'static, (PageCursor, Result<T, crate::types::error::Error>)>;
+
+#[doc = " Pages through a list endpoint, one `GET` request per page."]
+#[doc = ""]
+#[doc = " Pages are fetched lazily. [`Paginator::cursor`] tells which page comes next,"]
+#[doc = " it is `None` once every page was returned."]
+pub struct Paginator<T: Pagination> {
+    client: Client,
+    cursor: Option<PageCursor>,
+    total_count: Option<i64>,
+    #[doc = " A page fetched ahead by `total_count`, with the cursor following it."]
+    prefetched: Option<(T, Option<PageCursor>)>,
+    capability: Option<crate::types::Capability>,
+    concurrency: usize,
+    #[doc = " Pages requested ahead, in order, once their URLs are known."]
+    ahead: Option<PageStream<T>>,
+    ahead_started: bool,
+}
+
+impl<T: Pagination> std::fmt::Debug for Paginator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Paginator")
+            .field("cursor", &self.cursor)
+            .field("total_count", &self.total_count)
+            .field("concurrency", &self.concurrency)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Paginator<T>
+where
+    T: Pagination + serde::de::DeserializeOwned + Send + 'static,
+{
+    #[doc = " Start paging at `cursor`, the first page or one saved by an earlier paginator."]
+    pub fn resume(client: Client, cursor: PageCursor) -> Self {
+        Self {
+            client,
+            cursor: Some(cursor),
+            total_count: None,
+            prefetched: None,
+            capability: None,
+            concurrency: 1,
+            ahead: None,
+            ahead_started: false,
+        }
+    }
+
+    #[doc = " Continue after `first`, a page fetched already; it is returned first."]
+    pub(crate) fn after(client: Client, first: T) -> Self {
+        let following = Self::following("", &first);
+        Self {
+            client,
+            cursor: None,
+            total_count: first.total_count(),
+            prefetched: Some((first, following)),
+            capability: None,
+            concurrency: 1,
+            ahead: None,
+            ahead_started: false,
+        }
+    }
+
+    #[doc = " Fail with [`crate::types::error::Error::Unsupported`] before the first request if the"]
+    #[doc = " server lacks `capability`."]
+    pub(crate) fn require(mut self, capability: crate::types::Capability) -> Self {
+        self.capability = Some(capability);
+        self
+    }
+
+    #[doc = " Request up to `concurrency` pages at once instead of one after another."]
+    #[doc = ""]
+    #[doc = " Once a page reported the number of items, the URLs of the remaining pages are"]
+    #[doc = " computed from its `page` parameter and requested ahead; pages are still"]
+    #[doc = " returned in order. If a later page reports a different number of items, the"]
+    #[doc = " result set changed during the crawl: the pages requested ahead are dropped"]
+    #[doc = " and the paginator continues sequentially by following `next`. Endpoints"]
+    #[doc = " without numbered pages are always paged sequentially."]
+    pub fn prefetch(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    #[doc = " The page returned next, `None` once all pages were returned."]
+    pub fn cursor(&self) -> Option<&PageCursor> {
+        self.cursor.as_ref()
+    }
+
+    #[doc = " The number of items on all pages, as reported with the first page fetched."]
+    #[doc = ""]
+    #[doc = " Fetches the next page if no page was fetched yet; it is still returned by"]
+    #[doc = " the next call of [`Paginator::next_page`]."]
+    pub async fn total_count(&mut self) -> Result<Option<i64>, crate::types::error::Error> {
+        if self.total_count.is_none() && self.prefetched.is_none() {
+            if let Some(cursor) = &self.cursor {
+                if let Some(capability) = self.capability {
+                    self.client.require_capability(capability).await?;
+                    self.capability = None;
+                }
+                let page = Self::fetch(&self.client, cursor).await?;
+                let following = Self::following(&cursor.url, &page);
+                self.total_count = page.total_count();
+                self.prefetched = Some((page, following));
+            }
+        }
+        Ok(self.total_count)
+    }
+
+    #[doc = " Fetch the next page, `None` once all pages were returned."]
+    pub async fn next_page(&mut self) -> Result<Option<T>, crate::types::error::Error> {
+        use futures::StreamExt;
+        if let Some((page, following)) = self.prefetched.take() {
+            self.cursor = following;
+            self.start_ahead(&page);
+            return Ok(Some(page));
+        }
+        if let Some(ahead) = &mut self.ahead {
+            match ahead.next().await {
+                Some((cursor, Ok(page))) if page.total_count() == self.total_count => {
+                    self.cursor = Self::following(&cursor.url, &page);
+                    return Ok(Some(page));
+                }
+                Some((_, Ok(page))) => {
+                    tracing::debug!(
+                        "result set changed from {:?} to {:?} items, paging sequentially",
+                        self.total_count,
+                        page.total_count()
+                    );
+                    self.total_count = page.total_count();
+                    self.ahead = None;
+                }
+                Some((_, Err(err))) => {
+                    // The cursor still points at the failed page, so calling again retries it.
+                    self.ahead = None;
+                    return Err(err);
+                }
+                None => self.ahead = None,
+            }
+        }
+        let Some(cursor) = &self.cursor else {
+            return Ok(None);
+        };
+        if let Some(capability) = self.capability {
+            self.client.require_capability(capability).await?;
+            self.capability = None;
+        }
+        let page = Self::fetch(&self.client, cursor).await?;
+        self.cursor = Self::following(&cursor.url, &page);
+        if self.total_count.is_none() {
+            self.total_count = page.total_count();
+        }
+        self.start_ahead(&page);
+        Ok(Some(page))
+    }
+
+    #[doc = " A stream of the remaining pages."]
+    pub fn pages(
+        self,
+    ) -> impl futures::Stream<Item = Result<T, crate::types::error::Error>> + Unpin {
+        Box::pin(futures::stream::try_unfold(
+            self,
+            |mut paginator| async move {
+                Ok(paginator.next_page().await?.map(|page| (page, paginator)))
+            },
+        ))
+    }
+
+    #[doc = " A stream of the items of the remaining pages."]
+    pub fn items(
+        self,
+    ) -> impl futures::Stream<Item = Result<T::Item, crate::types::error::Error>> + Unpin {
+        use futures::TryStreamExt;
+        self.pages()
+            .map_ok(|page| futures::stream::iter(page.items().into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    #[doc = " Fetch the remaining pages and collect their items."]
+    pub async fn collect_all(mut self) -> Result<Vec<T::Item>, crate::types::error::Error> {
+        let mut items = vec![];
+        while let Some(page) = self.next_page().await? {
+            items.extend(page.items());
+        }
+        Ok(items)
+    }
+
+    #[doc = " The page after `page`, fetched from `url`."]
+    fn following(url: &str, page: &T) -> Option<PageCursor> {
+        // Stop on empty pages and on servers linking a page to itself.
+        if !page.has_more_pages() || page.items().is_empty() {
+            return None;
+        }
+        page.next_page_token()
+            .filter(|next| next != url)
+            .map(|url| PageCursor { url, query: vec![] })
+    }
+
+    #[doc = " Request the remaining pages ahead after the first page fetched, if enabled."]
+    fn start_ahead(&mut self, page: &T) {
+        use futures::StreamExt;
+        if self.concurrency <= 1 || self.ahead_started {
+            return;
+        }
+        self.ahead_started = true;
+        let Some(next) = &self.cursor else {
+            return;
+        };
+        let cursors = numbered_pages(next, page.items().len(), self.total_count);
+        if cursors.is_empty() {
+            return;
+        }
+        let client = self.client.clone();
+        let pages = futures::stream::iter(cursors)
+            .map(move |cursor| {
+                let client = client.clone();
+                async move {
+                    let result = Self::fetch(&client, &cursor).await;
+                    (cursor, result)
+                }
+            })
+            .buffered(self.concurrency);
+        #[cfg(not(target_arch = "wasm32"))]
+        let pages = pages.boxed();
+        #[cfg(target_arch = "wasm32")]
+        let pages = pages.boxed_local();
+        self.ahead = Some(pages);
+    }
+
+    async fn fetch(client: &Client, cursor: &PageCursor) -> Result<T, crate::types::error::Error> {
+        let mut req = client
+            .client
+            .request(http::Method::GET, cursor.url.as_str());
+        req = client.authenticate(req).await?;
+        if !cursor.query.is_empty() {
+            req = req.query(&cursor.query);
+        }
+        let resp = req.send().await?;
+        let status = resp.status();
+        if status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            serde_json::from_str(&text).map_err(|err| {
+                crate::types::error::Error::from_serde_error(
+                    format_serde_error::SerdeError::new(text.to_string(), err),
+                    status,
+                )
+            })
+        } else {
+            let text = resp.text().await.unwrap_or_default();
+            Err(crate::types::error::Error::Server {
+                body: text.to_string(),
+                status,
+            })
+        }
+    }
+}
+
+#[doc = " The items of all pages of a list endpoint, starting with the page returned by"]
+#[doc = " `first`. Backs the `list_stream` methods, which fetch their first page with the"]
+#[doc = " filters of `list`."]
+pub(crate) fn list_items<T, F>(
+    client: &Client,
+    first: F,
+) -> impl futures::Stream<Item = Result<T::Item, crate::types::error::Error>> + Unpin
+where
+    T: Pagination + serde::de::DeserializeOwned + Send + 'static,
+    F: std::future::Future<Output = Result<T, crate::types::error::Error>>,
+{
+    use futures::{TryFutureExt, TryStreamExt};
+    let client = client.clone();
+    Box::pin(
+        first
+            .map_ok(move |page| Paginator::after(client, page).items())
+            .try_flatten_stream(),
+    )
+}
+
+#[doc = " The cursors of the pages from `next` to the last one, given the length of a full"]
+#[doc = " page and the number of items. Empty if `next` has no `page` parameter."]
+pub(crate) fn numbered_pages(
+    next: &PageCursor,
+    page_len: usize,
+    count: Option<i64>,
+) -> Vec<PageCursor> {
+    let (Ok(url), Some(count), Ok(page_len)) =
+        (url::Url::parse(&next.url), count, i64::try_from(page_len))
+    else {
+        return vec![];
+    };
+    let Some(first) = url
+        .query_pairs()
+        .find(|(key, _)| key == "page")
+        .and_then(|(_, value)| value.parse::<i64>().ok())
+    else {
+        return vec![];
+    };
+    if page_len == 0 {
+        return vec![];
+    }
+    let last = (count + page_len - 1) / page_len;
+    (first..=last)
+        .map(|page| {
+            let mut url = url.clone();
+            let pairs: Vec<(String, String)> = url
+                .query_pairs()
+                .map(|(key, value)| {
+                    if key == "page" {
+                        (key.into_owned(), page.to_string())
+                    } else {
+                        (key.into_owned(), value.into_owned())
+                    }
+                })
+                .collect();
+            url.query_pairs_mut().clear().extend_pairs(pairs);
+            PageCursor {
+                url: url.into(),
+                query: next.query.clone(),
+            }
+        })
+        .collect()
+}
+
+#[doc = " Objects fetched by id, together with the requested ids the server did not return."]
+#[doc = ""]
+#[doc = " Dereferences to the map of the objects found."]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ByIds<T> {
+    #[doc = " The objects found, by id."]
+    pub found: std::collections::HashMap<i64, T>,
+    #[doc = " The requested ids without an object, because it was deleted or is not visible"]
+    #[doc = " to the user, in ascending order."]
+    pub missing: Vec<i64>,
+}
+
+impl<T> ByIds<T> {
+    pub(crate) fn new(ids: &[i64], found: impl IntoIterator<Item = (i64, T)>) -> Self {
+        let requested: std::collections::BTreeSet<i64> = ids.iter().copied().collect();
+        let found: std::collections::HashMap<i64, T> = found
+            .into_iter()
+            .filter(|(id, _)| requested.contains(id))
+            .collect();
+        let missing = requested
+            .into_iter()
+            .filter(|id| !found.contains_key(id))
+            .collect();
+        Self { found, missing }
+    }
+
+    #[doc = " Returns true if every requested id was found."]
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl<T> std::ops::Deref for ByIds<T> {
+    type Target = std::collections::HashMap<i64, T>;
+    fn deref(&self) -> &Self::Target {
+        &self.found
+    }
+}
+
+#[doc = " The longest `id__in` value sent in one request, keeping URLs well below the"]
+#[doc = " limits of common proxies."]
+pub(crate) const ID_BATCH_LEN: usize = 1500;
+
+#[doc = " The number of requests sent at once when fetching by id."]
+const ID_CONCURRENCY: usize = 4;
+
+#[doc = " Split the distinct `ids` into batches whose comma separated list fits into"]
+#[doc = " `ID_BATCH_LEN` characters."]
+pub(crate) fn id_batches(ids: &[i64]) -> Vec<Vec<i64>> {
+    let ids: std::collections::BTreeSet<i64> = ids.iter().copied().collect();
+    let mut batches: Vec<Vec<i64>> = vec![];
+    let mut len = 0;
+    for id in ids {
+        let id_len = id.to_string().len() + 1;
+        match batches.last_mut() {
+            Some(batch) if len + id_len <= ID_BATCH_LEN + 1 => batch.push(id),
+            _ => {
+                batches.push(vec![id]);
+                len = 0;
+            }
+        }
+        len += id_len;
+    }
+    batches
+}
+
+#[doc = " Fetch the objects of a list endpoint at `path` by id with `id__in` filters,"]
+#[doc = " sending batches in parallel."]
+pub(crate) async fn get_many<P>(
+    client: &Client,
+    path: &str,
+    ids: &[i64],
+    id: fn(&P::Item) -> i64,
+) -> Result<ByIds<P::Item>, crate::types::error::Error>
+where
+    P: Pagination + serde::de::DeserializeOwned + Send + 'static,
+{
+    use futures::{StreamExt, TryStreamExt};
+    let pages: Vec<Vec<P::Item>> = futures::stream::iter(id_batches(ids))
+        .map(|batch| {
+            let query = vec![
+                (
+                    "id__in".to_string(),
+                    batch
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ),
+                ("page_size".to_string(), batch.len().to_string()),
+            ];
+            Paginator::<P>::resume(client.clone(), PageCursor::first(client, path, query))
+                .collect_all()
+        })
+        .buffer_unordered(ID_CONCURRENCY)
+        .try_collect()
+        .await?;
+    Ok(ByIds::new(
+        ids,
+        pages.into_iter().flatten().map(|item| (id(&item), item)),
+    ))
+}
+
+#[doc = " Fetch objects one by one with `retrieve`, sending requests in parallel, for"]
+#[doc = " endpoints without an `id__in` filter."]
+pub(crate) async fn retrieve_many<T, F, Fut>(
+    ids: &[i64],
+    retrieve: F,
+) -> Result<ByIds<T>, crate::types::error::Error>
+where
+    F: Fn(i64) -> Fut,
+    Fut: std::future::Future<Output = Result<T, crate::types::error::Error>>,
+{
+    use futures::{StreamExt, TryStreamExt};
+    let distinct: std::collections::BTreeSet<i64> = ids.iter().copied().collect();
+    let found: Vec<Option<(i64, T)>> = futures::stream::iter(distinct)
+        .map(|id| {
+            let object = retrieve(id);
+            async move {
+                match object.await {
+                    Ok(object) => Ok(Some((id, object))),
+                    Err(err) if err.is_not_found() => Ok(None),
+                    Err(err) => Err(err),
+                }
+            }
+        })
+        .buffer_unordered(ID_CONCURRENCY)
+        .try_collect()
+        .await?;
+    Ok(ByIds::new(ids, found.into_iter().flatten()))
+}
@@ -21,7 +21,7 @@ impl Profile {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/profile/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -52,7 +52,7 @@ impl Profile {
             http::Method::PATCH,
             format!("{}/{}", self.client.base_url, "api/profile/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -87,7 +87,7 @@ impl Profile {
                 self.client.base_url, "api/profile/disconnect_social_account/"
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -121,7 +121,7 @@ impl Profile {
                 self.client.base_url, "api/profile/generate_auth_token/"
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -155,7 +155,7 @@ impl Profile {
                 self.client.base_url, "api/profile/social_account_providers/"
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -186,7 +186,7 @@ impl Profile {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/profile/totp/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -218,7 +218,7 @@ impl Profile {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/profile/totp/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -247,7 +247,7 @@ impl Profile {
             http::Method::DELETE,
             format!("{}/{}", self.client.base_url, "api/profile/totp/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
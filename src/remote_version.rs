@@ -22,7 +22,7 @@ impl RemoteVersion {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/remote_version/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
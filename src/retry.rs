@@ -0,0 +1,220 @@
+#[doc = " When and how often failed requests are sent again."]
+#[doc = ""]
+#[doc = " Only requests with an idempotent method are retried, so uploads and bulk edits"]
+#[doc = " are never applied twice. Responses with `429 Too Many Requests` or"]
+#[doc = " `503 Service Unavailable` are retried after their `Retry-After` delay."]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    #[doc = " The number of attempts including the first one, `1` disables retries."]
+    pub max_attempts: u32,
+    #[doc = " The delay before the first retry, doubled for every further retry."]
+    pub base_delay: std::time::Duration,
+    #[doc = " The longest delay between two attempts. A `Retry-After` asking for longer"]
+    #[doc = " ends the retries."]
+    pub max_delay: std::time::Duration,
+    #[doc = " Randomize delays between zero and the computed backoff."]
+    pub jitter: bool,
+    #[doc = " The methods that may be sent more than once."]
+    pub idempotent_methods: Vec<http::Method>,
+    #[doc = " Wait for the delay of a `Retry-After` header instead of the computed backoff."]
+    pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: std::time::Duration::from_secs(1),
+            max_delay: std::time::Duration::from_secs(30),
+            jitter: true,
+            idempotent_methods: vec![
+                http::Method::GET,
+                http::Method::HEAD,
+                http::Method::OPTIONS,
+                http::Method::PUT,
+                http::Method::DELETE,
+                http::Method::TRACE,
+            ],
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    #[doc = " A policy sending every request only once."]
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    #[doc = " Set the number of attempts including the first one."]
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    #[doc = " Set the delay before the first retry and the longest delay between attempts."]
+    pub fn delays(
+        mut self,
+        base_delay: std::time::Duration,
+        max_delay: std::time::Duration,
+    ) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    #[doc = " Enable or disable randomized delays."]
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    #[doc = " Allow retries of requests with this method, e.g. `POST` for a search endpoint."]
+    pub fn idempotent(mut self, method: http::Method) -> Self {
+        if !self.idempotent_methods.contains(&method) {
+            self.idempotent_methods.push(method);
+        }
+        self
+    }
+
+    #[doc = " Returns true if requests with this method may be retried."]
+    pub fn is_idempotent(&self, method: &http::Method) -> bool {
+        self.idempotent_methods.contains(method)
+    }
+
+    #[doc = " The backoff before retry number `retry`, starting at zero, before jitter."]
+    pub fn backoff(&self, retry: u32) -> std::time::Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay)
+    }
+
+    #[cfg(feature = "retry")]
+    fn delay(&self, retry: u32) -> std::time::Duration {
+        let backoff = self.backoff(retry);
+        if self.jitter && !backoff.is_zero() {
+            backoff.mul_f64(rand::random::<f64>())
+        } else {
+            backoff
+        }
+    }
+}
+
+#[doc = " The delay asked for by the `Retry-After` header of a response, either in seconds"]
+#[doc = " or as an HTTP date."]
+pub fn retry_after(response: &reqwest::Response) -> Option<std::time::Duration> {
+    let value = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+        .to_std()
+        .ok()
+        .or(Some(std::time::Duration::ZERO))
+}
+
+#[cfg(feature = "retry")]
+fn is_transient_status(status: reqwest::StatusCode) -> bool {
+    matches!(
+        status,
+        reqwest::StatusCode::REQUEST_TIMEOUT
+            | reqwest::StatusCode::TOO_MANY_REQUESTS
+            | reqwest::StatusCode::INTERNAL_SERVER_ERROR
+            | reqwest::StatusCode::BAD_GATEWAY
+            | reqwest::StatusCode::SERVICE_UNAVAILABLE
+            | reqwest::StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+#[doc = " Middleware retrying transient failures according to a [`RetryPolicy`]."]
+#[doc = ""]
+#[doc = " A policy in the request extensions replaces the one of the middleware."]
+#[cfg(feature = "retry")]
+#[derive(Clone, Debug)]
+pub struct RetryMiddleware {
+    policy: RetryPolicy,
+}
+
+#[cfg(feature = "retry")]
+impl RetryMiddleware {
+    #[doc = " Create the middleware with the default policy of its requests."]
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+#[cfg(feature = "retry")]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl reqwest_middleware::Middleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut http::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let policy = extensions
+            .get::<RetryPolicy>()
+            .cloned()
+            .unwrap_or_else(|| self.policy.clone());
+        if policy.max_attempts <= 1 || !policy.is_idempotent(req.method()) {
+            return next.run(req, extensions).await;
+        }
+        let mut retry = 0;
+        loop {
+            // Streaming bodies can't be sent twice.
+            let Some(attempt) = req.try_clone() else {
+                return next.run(req, extensions).await;
+            };
+            let result = next.clone().run(attempt, extensions).await;
+            if retry + 1 >= policy.max_attempts {
+                return result;
+            }
+            let delay = match &result {
+                Ok(resp) if is_transient_status(resp.status()) => {
+                    let requested = if policy.respect_retry_after
+                        && matches!(
+                            resp.status(),
+                            reqwest::StatusCode::TOO_MANY_REQUESTS
+                                | reqwest::StatusCode::SERVICE_UNAVAILABLE
+                        ) {
+                        retry_after(resp)
+                    } else {
+                        None
+                    };
+                    match requested {
+                        Some(delay) if delay > policy.max_delay => return result,
+                        Some(delay) => delay,
+                        None => policy.delay(retry),
+                    }
+                }
+                Err(reqwest_middleware::Error::Reqwest(e)) if e.is_timeout() || e.is_connect() => {
+                    policy.delay(retry)
+                }
+                _ => return result,
+            };
+            tracing::debug!(
+                "retrying {} {} in {delay:?}, attempt {}",
+                req.method(),
+                req.url(),
+                retry + 2
+            );
+            #[cfg(not(target_arch = "wasm32"))]
+            tokio::time::sleep(delay).await;
+            #[cfg(target_arch = "wasm32")]
+            wasm_timer::Delay::new(delay)
+                .await
+                .map_err(|e| reqwest_middleware::Error::Middleware(e.into()))?;
+            retry += 1;
+        }
+    }
+}
@@ -23,7 +23,7 @@ impl SavedViews {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/saved_views/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = page {
             query_params.push(("page", format!("{p}")));
@@ -63,70 +63,10 @@ impl SavedViews {
     ) -> impl futures::Stream<Item = Result<crate::types::SavedView, crate::types::error::Error>>
            + Unpin
            + '_ {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(None, page_size)
-            .map_ok(move |result| {
-                let items = futures::stream::iter(result.items().into_iter().map(Ok));
-                let next_pages = futures::stream::try_unfold(
-                    (None, result),
-                    move |(prev_page_token, new_result)| async move {
-                        if new_result.has_more_pages()
-                            && !new_result.items().is_empty()
-                            && prev_page_token != new_result.next_page_token()
-                        {
-                            async {
-                                let mut req = self.client.client.request(
-                                    http::Method::GET,
-                                    format!("{}/{}", self.client.base_url, "api/saved_views/"),
-                                );
-                                req = req.header(
-                                    "Authorization",
-                                    format!("Token {}", &self.client.token),
-                                );
-                                let mut request = req.build()?;
-                                request = new_result.next_page(request)?;
-                                let resp = self.client.client.execute(request).await?;
-                                let status = resp.status();
-                                if status.is_success() {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    serde_json::from_str(&text).map_err(|err| {
-                                        crate::types::error::Error::from_serde_error(
-                                            format_serde_error::SerdeError::new(
-                                                text.to_string(),
-                                                err,
-                                            ),
-                                            status,
-                                        )
-                                    })
-                                } else {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    Err(crate::types::error::Error::Server {
-                                        body: text.to_string(),
-                                        status,
-                                    })
-                                }
-                            }
-                            .map_ok(|result: crate::types::PaginatedSavedViewList| {
-                                Some((
-                                    futures::stream::iter(result.items().into_iter().map(Ok)),
-                                    (new_result.next_page_token(), result),
-                                ))
-                            })
-                            .await
-                        } else {
-                            Ok(None)
-                        }
-                    },
-                )
-                .try_flatten();
-                items.chain(next_pages)
-            })
-            .try_flatten_stream()
-            .boxed()
+        crate::paginator::list_items(&self.client, self.list(None, page_size))
     }
 
-    #[doc = "Perform a `POST` request to `/api/saved_views/`.\n\n```rust,no_run\nasync fn example_saved_views_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::SavedView = client\n        .saved_views()\n        .create(&paperless_api_client::types::SavedViewRequest {\n            name: \"some-string\".to_string(),\n            show_on_dashboard: true,\n            show_in_sidebar: true,\n            sort_field: Some(\"some-string\".to_string()),\n            sort_reverse: Some(true),\n            filter_rules: vec![paperless_api_client::types::SavedViewFilterRuleRequest {\n                rule_type: 4 as i64,\n                value: Some(\"some-string\".to_string()),\n            }],\n            page_size: Some(4 as i64),\n            display_mode: Some(paperless_api_client::types::DisplayMode::LargeCards),\n            display_fields: Some(serde_json::Value::String(\"some-string\".to_string())),\n            owner: Some(4 as i64),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `POST` request to `/api/saved_views/`.\n\n```rust,no_run\nasync fn example_saved_views_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::SavedView = client\n        .saved_views()\n        .create(&paperless_api_client::types::SavedViewRequest {\n            name: \"some-string\".to_string(),\n            show_on_dashboard: true,\n            show_in_sidebar: true,\n            sort_field: Some(\"some-string\".to_string()),\n            sort_reverse: Some(true),\n            filter_rules: vec![paperless_api_client::types::SavedViewFilterRuleRequest {\n                rule_type: paperless_api_client::types::FilterRuleType::TitleContains,\n                value: Some(\"some-string\".to_string()),\n            }],\n            page_size: Some(4 as i64),\n            display_mode: Some(paperless_api_client::types::DisplayMode::LargeCards),\n            display_fields: Some(serde_json::Value::String(\"some-string\".to_string())),\n            owner: Some(4 as i64),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn create<'a>(
@@ -137,7 +77,7 @@ impl SavedViews {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/saved_views/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -173,7 +113,7 @@ impl SavedViews {
                 "api/saved_views/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -193,7 +133,7 @@ impl SavedViews {
         }
     }
 
-    #[doc = "Perform a `PUT` request to `/api/saved_views/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this saved view. (required)\n\n```rust,no_run\nasync fn example_saved_views_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::SavedView = client\n        .saved_views()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::SavedViewRequest {\n                name: \"some-string\".to_string(),\n                show_on_dashboard: true,\n                show_in_sidebar: true,\n                sort_field: Some(\"some-string\".to_string()),\n                sort_reverse: Some(true),\n                filter_rules: vec![paperless_api_client::types::SavedViewFilterRuleRequest {\n                    rule_type: 4 as i64,\n                    value: Some(\"some-string\".to_string()),\n                }],\n                page_size: Some(4 as i64),\n                display_mode: Some(paperless_api_client::types::DisplayMode::LargeCards),\n                display_fields: Some(serde_json::Value::String(\"some-string\".to_string())),\n                owner: Some(4 as i64),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PUT` request to `/api/saved_views/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this saved view. (required)\n\n```rust,no_run\nasync fn example_saved_views_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::SavedView = client\n        .saved_views()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::SavedViewRequest {\n                name: \"some-string\".to_string(),\n                show_on_dashboard: true,\n                show_in_sidebar: true,\n                sort_field: Some(\"some-string\".to_string()),\n                sort_reverse: Some(true),\n                filter_rules: vec![paperless_api_client::types::SavedViewFilterRuleRequest {\n                    rule_type: paperless_api_client::types::FilterRuleType::TitleContains,\n                    value: Some(\"some-string\".to_string()),\n                }],\n                page_size: Some(4 as i64),\n                display_mode: Some(paperless_api_client::types::DisplayMode::LargeCards),\n                display_fields: Some(serde_json::Value::String(\"some-string\".to_string())),\n                owner: Some(4 as i64),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn update<'a>(
@@ -209,7 +149,7 @@ impl SavedViews {
                 "api/saved_views/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -242,7 +182,7 @@ impl SavedViews {
                 "api/saved_views/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -256,7 +196,7 @@ impl SavedViews {
         }
     }
 
-    #[doc = "Perform a `PATCH` request to `/api/saved_views/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this saved view. (required)\n\n```rust,no_run\nasync fn example_saved_views_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::SavedView = client\n        .saved_views()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedSavedViewRequest {\n                name: Some(\"some-string\".to_string()),\n                show_on_dashboard: Some(true),\n                show_in_sidebar: Some(true),\n                sort_field: Some(\"some-string\".to_string()),\n                sort_reverse: Some(true),\n                filter_rules: Some(vec![paperless_api_client::types::SavedViewFilterRuleRequest {\n                    rule_type: 4 as i64,\n                    value: Some(\"some-string\".to_string()),\n                }]),\n                page_size: Some(4 as i64),\n                display_mode: Some(paperless_api_client::types::DisplayMode::LargeCards),\n                display_fields: Some(serde_json::Value::String(\"some-string\".to_string())),\n                owner: Some(4 as i64),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PATCH` request to `/api/saved_views/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this saved view. (required)\n\n```rust,no_run\nasync fn example_saved_views_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::SavedView = client\n        .saved_views()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedSavedViewRequest {\n                name: Some(\"some-string\".to_string()),\n                show_on_dashboard: Some(true),\n                show_in_sidebar: Some(true),\n                sort_field: Some(\"some-string\".to_string()),\n                sort_reverse: Some(true),\n                filter_rules: Some(vec![paperless_api_client::types::SavedViewFilterRuleRequest {\n                    rule_type: paperless_api_client::types::FilterRuleType::TitleContains,\n                    value: Some(\"some-string\".to_string()),\n                }]),\n                page_size: Some(4 as i64),\n                display_mode: Some(paperless_api_client::types::DisplayMode::LargeCards),\n                display_fields: Some(serde_json::Value::String(\"some-string\".to_string())),\n                owner: Some(4 as i64),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn partial_update<'a>(
@@ -272,7 +212,7 @@ impl SavedViews {
                 "api/saved_views/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
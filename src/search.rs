@@ -23,7 +23,7 @@ impl Search {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/search/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![("query", query.to_string())];
         if let Some(p) = db_only {
             query_params.push(("db_only", format!("{p}")));
@@ -61,7 +61,7 @@ impl Search {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/search/autocomplete/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = limit {
             query_params.push(("limit", format!("{p}")));
@@ -46,7 +46,7 @@ impl ShareLinks {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/share_links/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = created_date_gt {
             query_params.push(("created__date__gt", format!("{p}")));
@@ -201,88 +201,36 @@ impl ShareLinks {
     ) -> impl futures::Stream<Item = Result<crate::types::ShareLink, crate::types::error::Error>>
            + Unpin
            + '_ {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(
-            created__date__gt,
-            created__date__gte,
-            created__date__lt,
-            created__date__lte,
-            created__day,
-            created__gt,
-            created__gte,
-            created__lt,
-            created__lte,
-            created__month,
-            created__year,
-            expiration__date__gt,
-            expiration__date__gte,
-            expiration__date__lt,
-            expiration__date__lte,
-            expiration__day,
-            expiration__gt,
-            expiration__gte,
-            expiration__lt,
-            expiration__lte,
-            expiration__month,
-            expiration__year,
-            ordering,
-            None,
-            page_size,
+        crate::paginator::list_items(
+            &self.client,
+            self.list(
+                created__date__gt,
+                created__date__gte,
+                created__date__lt,
+                created__date__lte,
+                created__day,
+                created__gt,
+                created__gte,
+                created__lt,
+                created__lte,
+                created__month,
+                created__year,
+                expiration__date__gt,
+                expiration__date__gte,
+                expiration__date__lt,
+                expiration__date__lte,
+                expiration__day,
+                expiration__gt,
+                expiration__gte,
+                expiration__lt,
+                expiration__lte,
+                expiration__month,
+                expiration__year,
+                ordering,
+                None,
+                page_size,
+            ),
         )
-        .map_ok(move |result| {
-            let items = futures::stream::iter(result.items().into_iter().map(Ok));
-            let next_pages = futures::stream::try_unfold(
-                (None, result),
-                move |(prev_page_token, new_result)| async move {
-                    if new_result.has_more_pages()
-                        && !new_result.items().is_empty()
-                        && prev_page_token != new_result.next_page_token()
-                    {
-                        async {
-                            let mut req = self.client.client.request(
-                                http::Method::GET,
-                                format!("{}/{}", self.client.base_url, "api/share_links/"),
-                            );
-                            req = req
-                                .header("Authorization", format!("Token {}", &self.client.token));
-                            let mut request = req.build()?;
-                            request = new_result.next_page(request)?;
-                            let resp = self.client.client.execute(request).await?;
-                            let status = resp.status();
-                            if status.is_success() {
-                                let text = resp.text().await.unwrap_or_default();
-                                serde_json::from_str(&text).map_err(|err| {
-                                    crate::types::error::Error::from_serde_error(
-                                        format_serde_error::SerdeError::new(text.to_string(), err),
-                                        status,
-                                    )
-                                })
-                            } else {
-                                let text = resp.text().await.unwrap_or_default();
-                                Err(crate::types::error::Error::Server {
-                                    body: text.to_string(),
-                                    status,
-                                })
-                            }
-                        }
-                        .map_ok(|result: crate::types::PaginatedShareLinkList| {
-                            Some((
-                                futures::stream::iter(result.items().into_iter().map(Ok)),
-                                (new_result.next_page_token(), result),
-                            ))
-                        })
-                        .await
-                    } else {
-                        Ok(None)
-                    }
-                },
-            )
-            .try_flatten();
-            items.chain(next_pages)
-        })
-        .try_flatten_stream()
-        .boxed()
     }
 
     #[doc = "Perform a `POST` request to `/api/share_links/`.\n\n```rust,no_run\nasync fn example_share_links_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::ShareLink = client\n        .share_links()\n        .create(&paperless_api_client::types::ShareLinkRequest {\n            expiration: Some(chrono::Utc::now()),\n            document: Some(4 as i64),\n            file_version: Some(paperless_api_client::types::FileVersionEnum::Original),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
@@ -296,7 +244,7 @@ impl ShareLinks {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/share_links/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -332,7 +280,7 @@ impl ShareLinks {
                 "api/share_links/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -368,7 +316,7 @@ impl ShareLinks {
                 "api/share_links/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -401,7 +349,7 @@ impl ShareLinks {
                 "api/share_links/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -431,7 +379,7 @@ impl ShareLinks {
                 "api/share_links/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
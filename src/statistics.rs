@@ -22,7 +22,7 @@ impl Statistics {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/statistics/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -21,7 +21,7 @@ impl Status {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/status/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
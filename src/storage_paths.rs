@@ -35,7 +35,7 @@ impl StoragePaths {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/storage_paths/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = full_perms {
             query_params.push(("full_perms", format!("{p}")));
@@ -135,80 +135,28 @@ impl StoragePaths {
     ) -> impl futures::Stream<Item = Result<crate::types::StoragePath, crate::types::error::Error>>
            + Unpin
            + '_ {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(
-            full_perms,
-            id,
-            id__in,
-            name__icontains,
-            name__iendswith,
-            name__iexact,
-            name__istartswith,
-            ordering,
-            None,
-            page_size,
-            path__icontains,
-            path__iendswith,
-            path__iexact,
-            path__istartswith,
+        crate::paginator::list_items(
+            &self.client,
+            self.list(
+                full_perms,
+                id,
+                id__in,
+                name__icontains,
+                name__iendswith,
+                name__iexact,
+                name__istartswith,
+                ordering,
+                None,
+                page_size,
+                path__icontains,
+                path__iendswith,
+                path__iexact,
+                path__istartswith,
+            ),
         )
-        .map_ok(move |result| {
-            let items = futures::stream::iter(result.items().into_iter().map(Ok));
-            let next_pages = futures::stream::try_unfold(
-                (None, result),
-                move |(prev_page_token, new_result)| async move {
-                    if new_result.has_more_pages()
-                        && !new_result.items().is_empty()
-                        && prev_page_token != new_result.next_page_token()
-                    {
-                        async {
-                            let mut req = self.client.client.request(
-                                http::Method::GET,
-                                format!("{}/{}", self.client.base_url, "api/storage_paths/"),
-                            );
-                            req = req
-                                .header("Authorization", format!("Token {}", &self.client.token));
-                            let mut request = req.build()?;
-                            request = new_result.next_page(request)?;
-                            let resp = self.client.client.execute(request).await?;
-                            let status = resp.status();
-                            if status.is_success() {
-                                let text = resp.text().await.unwrap_or_default();
-                                serde_json::from_str(&text).map_err(|err| {
-                                    crate::types::error::Error::from_serde_error(
-                                        format_serde_error::SerdeError::new(text.to_string(), err),
-                                        status,
-                                    )
-                                })
-                            } else {
-                                let text = resp.text().await.unwrap_or_default();
-                                Err(crate::types::error::Error::Server {
-                                    body: text.to_string(),
-                                    status,
-                                })
-                            }
-                        }
-                        .map_ok(|result: crate::types::PaginatedStoragePathList| {
-                            Some((
-                                futures::stream::iter(result.items().into_iter().map(Ok)),
-                                (new_result.next_page_token(), result),
-                            ))
-                        })
-                        .await
-                    } else {
-                        Ok(None)
-                    }
-                },
-            )
-            .try_flatten();
-            items.chain(next_pages)
-        })
-        .try_flatten_stream()
-        .boxed()
     }
 
-    #[doc = "Perform a `POST` request to `/api/storage_paths/`.\n\n```rust,no_run\nasync fn example_storage_paths_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::StoragePath = client\n        .storage_paths()\n        .create(&paperless_api_client::types::StoragePathRequest {\n            name: \"some-string\".to_string(),\n            path: \"some-string\".to_string(),\n            match_: Some(\"some-string\".to_string()),\n            matching_algorithm: Some(4 as i64),\n            is_insensitive: Some(true),\n            owner: Some(4 as i64),\n            set_permissions: Some(paperless_api_client::types::SetPermissions {\n                view: Some(paperless_api_client::types::View {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n                change: Some(paperless_api_client::types::Change {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n            }),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `POST` request to `/api/storage_paths/`.\n\n```rust,no_run\nasync fn example_storage_paths_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::StoragePath = client\n        .storage_paths()\n        .create(&paperless_api_client::types::StoragePathRequest {\n            name: \"some-string\".to_string(),\n            path: \"some-string\".to_string(),\n            match_: Some(\"some-string\".to_string()),\n            matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n            is_insensitive: Some(true),\n            owner: Some(4 as i64),\n            set_permissions: Some(paperless_api_client::types::SetPermissions {\n                view: Some(paperless_api_client::types::View {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n                change: Some(paperless_api_client::types::Change {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n            }),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn create<'a>(
@@ -219,7 +167,7 @@ impl StoragePaths {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/storage_paths/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -256,7 +204,7 @@ impl StoragePaths {
                 "api/storage_paths/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = full_perms {
             query_params.push(("full_perms", format!("{p}")));
@@ -282,7 +230,7 @@ impl StoragePaths {
         }
     }
 
-    #[doc = "Perform a `PUT` request to `/api/storage_paths/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this storage path. (required)\n\n```rust,no_run\nasync fn example_storage_paths_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::StoragePath = client\n        .storage_paths()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::StoragePathRequest {\n                name: \"some-string\".to_string(),\n                path: \"some-string\".to_string(),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(4 as i64),\n                is_insensitive: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PUT` request to `/api/storage_paths/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this storage path. (required)\n\n```rust,no_run\nasync fn example_storage_paths_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::StoragePath = client\n        .storage_paths()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::StoragePathRequest {\n                name: \"some-string\".to_string(),\n                path: \"some-string\".to_string(),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                is_insensitive: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn update<'a>(
@@ -298,7 +246,7 @@ impl StoragePaths {
                 "api/storage_paths/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -331,7 +279,7 @@ impl StoragePaths {
                 "api/storage_paths/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -345,7 +293,7 @@ impl StoragePaths {
         }
     }
 
-    #[doc = "Perform a `PATCH` request to `/api/storage_paths/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this storage path. (required)\n\n```rust,no_run\nasync fn example_storage_paths_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::StoragePath = client\n        .storage_paths()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedStoragePathRequest {\n                name: Some(\"some-string\".to_string()),\n                path: Some(\"some-string\".to_string()),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(4 as i64),\n                is_insensitive: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PATCH` request to `/api/storage_paths/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this storage path. (required)\n\n```rust,no_run\nasync fn example_storage_paths_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::StoragePath = client\n        .storage_paths()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedStoragePathRequest {\n                name: Some(\"some-string\".to_string()),\n                path: Some(\"some-string\".to_string()),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                is_insensitive: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn partial_update<'a>(
@@ -361,7 +309,7 @@ impl StoragePaths {
                 "api/storage_paths/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -382,7 +330,7 @@ impl StoragePaths {
         }
     }
 
-    #[doc = "Perform a `POST` request to `/api/storage_paths/test/`.\n\nTest storage path against a document\n\n```rust,no_run\nasync fn example_storage_paths_test_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::StoragePath = client\n        .storage_paths()\n        .test_create(&paperless_api_client::types::StoragePathRequest {\n            name: \"some-string\".to_string(),\n            path: \"some-string\".to_string(),\n            match_: Some(\"some-string\".to_string()),\n            matching_algorithm: Some(4 as i64),\n            is_insensitive: Some(true),\n            owner: Some(4 as i64),\n            set_permissions: Some(paperless_api_client::types::SetPermissions {\n                view: Some(paperless_api_client::types::View {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n                change: Some(paperless_api_client::types::Change {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n            }),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `POST` request to `/api/storage_paths/test/`.\n\nTest storage path against a document\n\n```rust,no_run\nasync fn example_storage_paths_test_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::StoragePath = client\n        .storage_paths()\n        .test_create(&paperless_api_client::types::StoragePathRequest {\n            name: \"some-string\".to_string(),\n            path: \"some-string\".to_string(),\n            match_: Some(\"some-string\".to_string()),\n            matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n            is_insensitive: Some(true),\n            owner: Some(4 as i64),\n            set_permissions: Some(paperless_api_client::types::SetPermissions {\n                view: Some(paperless_api_client::types::View {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n                change: Some(paperless_api_client::types::Change {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n            }),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn test_create<'a>(
@@ -393,7 +341,7 @@ impl StoragePaths {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/storage_paths/test/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -413,4 +361,31 @@ impl StoragePaths {
             })
         }
     }
+
+    #[doc = "Fetch storage paths by id with `GET` requests to `/api/storage_paths/` with `id__in` filters, in batches sent in parallel.\n\nIds not found, e.g. deleted or not visible to the user, are reported as missing.\n\n```rust,no_run\nasync fn example_storage_paths_get_many() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::paginator::ByIds<paperless_api_client::types::StoragePath> =\n        client.storage_paths().get_many(&[4 as i64, 5 as i64]).await?;\n    println!(\"{:?} missing\", result.missing);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn get_many<'a>(
+        &'a self,
+        ids: &'a [i64],
+    ) -> Result<crate::paginator::ByIds<crate::types::StoragePath>, crate::types::error::Error>
+    {
+        crate::paginator::get_many::<crate::types::PaginatedStoragePathList>(
+            &self.client,
+            "api/storage_paths/",
+            ids,
+            |item| item.id,
+        )
+        .await
+    }
+
+    crate::taxonomy::impl_name_lookups!(
+        owned,
+        "storage path",
+        "api/storage_paths/",
+        storage_paths,
+        StoragePath,
+        PaginatedStoragePathList,
+        StoragePathRequest,
+        "&paperless_api_client::types::StoragePathRequest {\n                name: String::new(),\n                path: \"{{ correspondent }}/{{ title }}\".to_string(),\n                match_: None,\n                matching_algorithm: None,\n                is_insensitive: None,\n                owner: None,\n                set_permissions: None,\n            }"
+    );
 }
@@ -31,7 +31,7 @@ impl Tags {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/tags/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = full_perms {
             query_params.push(("full_perms", format!("{p}")));
@@ -110,76 +110,24 @@ impl Tags {
         page_size: Option<i64>,
     ) -> impl futures::Stream<Item = Result<crate::types::Tag, crate::types::error::Error>> + Unpin + '_
     {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(
-            full_perms,
-            id,
-            id__in,
-            name__icontains,
-            name__iendswith,
-            name__iexact,
-            name__istartswith,
-            ordering,
-            None,
-            page_size,
+        crate::paginator::list_items(
+            &self.client,
+            self.list(
+                full_perms,
+                id,
+                id__in,
+                name__icontains,
+                name__iendswith,
+                name__iexact,
+                name__istartswith,
+                ordering,
+                None,
+                page_size,
+            ),
         )
-        .map_ok(move |result| {
-            let items = futures::stream::iter(result.items().into_iter().map(Ok));
-            let next_pages = futures::stream::try_unfold(
-                (None, result),
-                move |(prev_page_token, new_result)| async move {
-                    if new_result.has_more_pages()
-                        && !new_result.items().is_empty()
-                        && prev_page_token != new_result.next_page_token()
-                    {
-                        async {
-                            let mut req = self.client.client.request(
-                                http::Method::GET,
-                                format!("{}/{}", self.client.base_url, "api/tags/"),
-                            );
-                            req = req
-                                .header("Authorization", format!("Token {}", &self.client.token));
-                            let mut request = req.build()?;
-                            request = new_result.next_page(request)?;
-                            let resp = self.client.client.execute(request).await?;
-                            let status = resp.status();
-                            if status.is_success() {
-                                let text = resp.text().await.unwrap_or_default();
-                                serde_json::from_str(&text).map_err(|err| {
-                                    crate::types::error::Error::from_serde_error(
-                                        format_serde_error::SerdeError::new(text.to_string(), err),
-                                        status,
-                                    )
-                                })
-                            } else {
-                                let text = resp.text().await.unwrap_or_default();
-                                Err(crate::types::error::Error::Server {
-                                    body: text.to_string(),
-                                    status,
-                                })
-                            }
-                        }
-                        .map_ok(|result: crate::types::PaginatedTagList| {
-                            Some((
-                                futures::stream::iter(result.items().into_iter().map(Ok)),
-                                (new_result.next_page_token(), result),
-                            ))
-                        })
-                        .await
-                    } else {
-                        Ok(None)
-                    }
-                },
-            )
-            .try_flatten();
-            items.chain(next_pages)
-        })
-        .try_flatten_stream()
-        .boxed()
     }
 
-    #[doc = "Perform a `POST` request to `/api/tags/`.\n\n```rust,no_run\nasync fn example_tags_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Tag = client\n        .tags()\n        .create(&paperless_api_client::types::TagRequest {\n            name: \"some-string\".to_string(),\n            color: Some(\"some-string\".to_string()),\n            match_: Some(\"some-string\".to_string()),\n            matching_algorithm: Some(4 as i64),\n            is_insensitive: Some(true),\n            is_inbox_tag: Some(true),\n            owner: Some(4 as i64),\n            set_permissions: Some(paperless_api_client::types::SetPermissions {\n                view: Some(paperless_api_client::types::View {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n                change: Some(paperless_api_client::types::Change {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n            }),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `POST` request to `/api/tags/`.\n\n```rust,no_run\nasync fn example_tags_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Tag = client\n        .tags()\n        .create(&paperless_api_client::types::TagRequest {\n            name: \"some-string\".to_string(),\n            color: Some(\"some-string\".to_string()),\n            match_: Some(\"some-string\".to_string()),\n            matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n            is_insensitive: Some(true),\n            is_inbox_tag: Some(true),\n            owner: Some(4 as i64),\n            set_permissions: Some(paperless_api_client::types::SetPermissions {\n                view: Some(paperless_api_client::types::View {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n                change: Some(paperless_api_client::types::Change {\n                    users: Some(vec![4 as i64]),\n                    groups: Some(vec![4 as i64]),\n                }),\n            }),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn create<'a>(
@@ -190,7 +138,7 @@ impl Tags {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/tags/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -227,7 +175,7 @@ impl Tags {
                 "api/tags/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = full_perms {
             query_params.push(("full_perms", format!("{p}")));
@@ -253,7 +201,7 @@ impl Tags {
         }
     }
 
-    #[doc = "Perform a `PUT` request to `/api/tags/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this tag. (required)\n\n```rust,no_run\nasync fn example_tags_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Tag = client\n        .tags()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::TagRequest {\n                name: \"some-string\".to_string(),\n                color: Some(\"some-string\".to_string()),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(4 as i64),\n                is_insensitive: Some(true),\n                is_inbox_tag: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PUT` request to `/api/tags/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this tag. (required)\n\n```rust,no_run\nasync fn example_tags_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Tag = client\n        .tags()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::TagRequest {\n                name: \"some-string\".to_string(),\n                color: Some(\"some-string\".to_string()),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                is_insensitive: Some(true),\n                is_inbox_tag: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn update<'a>(
@@ -269,7 +217,7 @@ impl Tags {
                 "api/tags/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -302,7 +250,7 @@ impl Tags {
                 "api/tags/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -316,7 +264,7 @@ impl Tags {
         }
     }
 
-    #[doc = "Perform a `PATCH` request to `/api/tags/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this tag. (required)\n\n```rust,no_run\nasync fn example_tags_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Tag = client\n        .tags()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedTagRequest {\n                name: Some(\"some-string\".to_string()),\n                color: Some(\"some-string\".to_string()),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(4 as i64),\n                is_insensitive: Some(true),\n                is_inbox_tag: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PATCH` request to `/api/tags/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this tag. (required)\n\n```rust,no_run\nasync fn example_tags_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Tag = client\n        .tags()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedTagRequest {\n                name: Some(\"some-string\".to_string()),\n                color: Some(\"some-string\".to_string()),\n                match_: Some(\"some-string\".to_string()),\n                matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                is_insensitive: Some(true),\n                is_inbox_tag: Some(true),\n                owner: Some(4 as i64),\n                set_permissions: Some(paperless_api_client::types::SetPermissions {\n                    view: Some(paperless_api_client::types::View {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                    change: Some(paperless_api_client::types::Change {\n                        users: Some(vec![4 as i64]),\n                        groups: Some(vec![4 as i64]),\n                    }),\n                }),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn partial_update<'a>(
@@ -332,7 +280,7 @@ impl Tags {
                 "api/tags/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -352,4 +300,30 @@ impl Tags {
             })
         }
     }
+
+    #[doc = "Fetch tags by id with `GET` requests to `/api/tags/` with `id__in` filters, in batches sent in parallel.\n\nIds not found, e.g. deleted or not visible to the user, are reported as missing.\n\n```rust,no_run\nasync fn example_tags_get_many() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::paginator::ByIds<paperless_api_client::types::Tag> =\n        client.tags().get_many(&[4 as i64, 5 as i64]).await?;\n    println!(\"{:?} missing\", result.missing);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn get_many<'a>(
+        &'a self,
+        ids: &'a [i64],
+    ) -> Result<crate::paginator::ByIds<crate::types::Tag>, crate::types::error::Error> {
+        crate::paginator::get_many::<crate::types::PaginatedTagList>(
+            &self.client,
+            "api/tags/",
+            ids,
+            |item| item.id,
+        )
+        .await
+    }
+
+    crate::taxonomy::impl_name_lookups!(
+        owned,
+        "tag",
+        "api/tags/",
+        tags,
+        Tag,
+        PaginatedTagList,
+        TagRequest,
+        "&paperless_api_client::types::TagRequest {\n                name: String::new(),\n                color: Some(\"#a6cee3\".to_string()),\n                match_: None,\n                matching_algorithm: None,\n                is_insensitive: None,\n                is_inbox_tag: None,\n                owner: None,\n                set_permissions: None,\n            }"
+    );
 }
@@ -11,7 +11,7 @@ impl Tasks {
         Self { client }
     }
 
-    #[doc = "Perform a `GET` request to `/api/tasks/`.\n\n**Parameters:**\n\n- `acknowledged: Option<bool>`: Acknowledged\n- `ordering: Option<String>`: Which field to use when ordering the results.\n- `status: Option<crate::types::Status>`: Current state of the task being run\n\n* `FAILURE` - FAILURE\n* `PENDING` - PENDING\n* `RECEIVED` - RECEIVED\n* `RETRY` - RETRY\n* `REVOKED` - REVOKED\n* `STARTED` - STARTED\n* `SUCCESS` - SUCCESS\n- `task_id: Option<String>`: Filter tasks by Celery UUID\n- `task_name: Option<crate::types::ListTaskName>`: Name of the task that was run\n\n* `consume_file` - Consume File\n* `train_classifier` - Train Classifier\n* `check_sanity` - Check Sanity\n* `index_optimize` - Index Optimize\n- `type_: Option<crate::types::Type>`: The type of task that was run\n\n* `auto_task` - Auto Task\n* `scheduled_task` - Scheduled Task\n* `manual_task` - Manual Task\n\n```rust,no_run\nasync fn example_tasks_list() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: Vec<paperless_api_client::types::TasksView> = client\n        .tasks()\n        .list(\n            Some(true),\n            Some(\"some-string\".to_string()),\n            Some(paperless_api_client::types::Status::Revoked),\n            Some(\"some-string\".to_string()),\n            Some(paperless_api_client::types::OptionListTaskName::IndexOptimize),\n            Some(paperless_api_client::types::Type::ManualTask),\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `GET` request to `/api/tasks/`.\n\n**Parameters:**\n\n- `acknowledged: Option<bool>`: Acknowledged\n- `ordering: Option<String>`: Which field to use when ordering the results.\n- `status: Option<crate::types::Status>`: Current state of the task being run\n\n* `FAILURE` - FAILURE\n* `PENDING` - PENDING\n* `RECEIVED` - RECEIVED\n* `RETRY` - RETRY\n* `REVOKED` - REVOKED\n* `STARTED` - STARTED\n* `SUCCESS` - SUCCESS\n- `task_id: Option<String>`: Filter tasks by Celery UUID\n- `task_name: Option<crate::types::ListTaskName>`: Name of the task that was run\n\n* `consume_file` - Consume File\n* `train_classifier` - Train Classifier\n* `check_sanity` - Check Sanity\n* `index_optimize` - Index Optimize\n- `type_: Option<crate::types::Type>`: The type of task that was run\n\n* `auto_task` - Auto Task\n* `scheduled_task` - Scheduled Task\n* `manual_task` - Manual Task\n\n```rust,no_run\nasync fn example_tasks_list() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: Vec<paperless_api_client::types::TasksView> = client\n        .tasks()\n        .list(\n            Some(true),\n            Some(\"some-string\".to_string()),\n            Some(paperless_api_client::types::Status::Revoked),\n            Some(\"some-string\".to_string()),\n            Some(paperless_api_client::types::ListTaskName::IndexOptimize),\n            Some(paperless_api_client::types::Type::ManualTask),\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn list<'a>(
@@ -27,7 +27,7 @@ impl Tasks {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/tasks/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = acknowledged {
             query_params.push(("acknowledged", format!("{p}")));
@@ -89,7 +89,7 @@ impl Tasks {
                 "api/tasks/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = task_id {
             query_params.push(("task_id", p));
@@ -127,7 +127,7 @@ impl Tasks {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/tasks/acknowledge/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = task_id {
             query_params.push(("task_id", p));
@@ -166,7 +166,7 @@ impl Tasks {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/tasks/run/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = task_id {
             query_params.push(("task_id", p));
@@ -192,4 +192,188 @@ impl Tasks {
             })
         }
     }
+
+    #[doc = "Poll `/api/tasks/` until the task with the given Celery id finishes.\n\nReturns the finished task on success, or [`Error::TaskFailed`](crate::types::error::Error::TaskFailed) carrying the task's result message if it failed or was revoked.\n\n**Parameters:**\n\n- `task_id: &str`: Celery UUID of the task, as returned by `documents().post_create`. (required)\n- `options: &crate::types::TaskWaitOptions`: Poll interval and timeout.\n\n```rust,no_run\nasync fn example_tasks_wait_for() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::TasksView = client\n        .tasks()\n        .wait_for(\n            \"some-string\",\n            &paperless_api_client::types::TaskWaitOptions::default(),\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn wait_for<'a>(
+        &'a self,
+        task_id: &'a str,
+        options: &crate::types::TaskWaitOptions,
+    ) -> Result<crate::types::TasksView, crate::types::error::Error> {
+        let started = std::time::Instant::now();
+        let mut interval = options.initial_interval;
+        loop {
+            let task = self
+                .list(None, None, None, Some(task_id.to_string()), None, None)
+                .await?
+                .into_iter()
+                .find(|task| task.task_id == task_id);
+            if let Some(task) = task.filter(|task| task.is_finished()) {
+                return match task.status {
+                    Some(crate::types::StatusEnum::Success) => Ok(task),
+                    _ => Err(crate::types::error::Error::TaskFailed {
+                        task_id: task.task_id,
+                        result: task.result,
+                    }),
+                };
+            }
+            let Some(delay) = options.delay(started, interval) else {
+                return Err(crate::types::error::Error::TaskTimeout {
+                    task_id: task_id.to_string(),
+                });
+            };
+            tokio::time::sleep(delay).await;
+            interval = options.next_interval(interval);
+        }
+    }
+
+    #[doc = "Watch the tasks with the given Celery ids until all of them finish.\n\nSee [`TaskWatcher`] for the emitted events.\n\n```rust,no_run\nuse futures_util::TryStreamExt;\nasync fn example_tasks_watch() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let mut events = client\n        .tasks()\n        .watch([\"some-string\"])\n        .acknowledge_finished(true)\n        .into_stream();\n    while let Some(event) = events.try_next().await? {\n        println!(\"{} -> {}\", event.task.task_id, event.status);\n    }\n    Ok(())\n}\n```"]
+    pub fn watch<I, S>(&self, task_ids: I) -> TaskWatcher
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        TaskWatcher::new(
+            self.clone(),
+            Some(task_ids.into_iter().map(Into::into).collect()),
+        )
+    }
+
+    #[doc = "Watch every task that has not been acknowledged yet, including tasks started later on.\n\nThe stream of this watcher does not end on its own.\n\n```rust,no_run\nuse futures_util::TryStreamExt;\nasync fn example_tasks_watch_unacknowledged() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let mut events = client\n        .tasks()\n        .watch_unacknowledged()\n        .interval(std::time::Duration::from_secs(5))\n        .into_stream();\n    while let Some(event) = events.try_next().await? {\n        println!(\"{:?} -> {}\", event.task.task_file_name, event.status);\n    }\n    Ok(())\n}\n```"]
+    pub fn watch_unacknowledged(&self) -> TaskWatcher {
+        TaskWatcher::new(self.clone(), None)
+    }
+}
+
+#[doc = " Polls the task list and reports every status change of the watched tasks"]
+#[doc = " (`PENDING` → `STARTED` → `SUCCESS`/`FAILURE`) as a [`TaskEvent`](crate::types::TaskEvent)."]
+#[derive(Clone, Debug)]
+pub struct TaskWatcher {
+    tasks: Tasks,
+    task_ids: Option<std::collections::BTreeSet<String>>,
+    interval: std::time::Duration,
+    acknowledge: bool,
+}
+
+impl TaskWatcher {
+    fn new(tasks: Tasks, task_ids: Option<std::collections::BTreeSet<String>>) -> Self {
+        TaskWatcher {
+            tasks,
+            task_ids,
+            interval: std::time::Duration::from_secs(2),
+            acknowledge: false,
+        }
+    }
+
+    #[doc = " Set the delay between two polls of the task list. Defaults to two seconds."]
+    pub fn interval(mut self, interval: std::time::Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    #[doc = " Acknowledge tasks through `/api/tasks/acknowledge/` once they finished."]
+    pub fn acknowledge_finished(mut self, acknowledge: bool) -> Self {
+        self.acknowledge = acknowledge;
+        self
+    }
+
+    #[doc = " Poll the task list once and return the status changes since the last poll."]
+    #[doc = " `seen` holds the last known status per Celery id and is updated in place."]
+    pub async fn poll(
+        &self,
+        seen: &mut std::collections::HashMap<String, crate::types::StatusEnum>,
+    ) -> Result<Vec<crate::types::TaskEvent>, crate::types::error::Error> {
+        let tasks = match &self.task_ids {
+            Some(task_ids) => {
+                let mut tasks = vec![];
+                for task_id in task_ids {
+                    if seen
+                        .get(task_id)
+                        .is_some_and(crate::types::StatusEnum::is_finished)
+                    {
+                        continue;
+                    }
+                    tasks.extend(
+                        self.tasks
+                            .list(None, None, None, Some(task_id.clone()), None, None)
+                            .await?
+                            .into_iter()
+                            .filter(|task| &task.task_id == task_id),
+                    );
+                }
+                tasks
+            }
+            None => {
+                self.tasks
+                    .list(Some(false), None, None, None, None, None)
+                    .await?
+            }
+        };
+
+        let mut events = vec![];
+        for task in tasks {
+            let previous = seen.get(&task.task_id).cloned();
+            if let Some(event) = crate::types::TaskEvent::from_change(previous, task) {
+                seen.insert(event.task.task_id.clone(), event.status.clone());
+                events.push(event);
+            }
+        }
+
+        let finished: Vec<i64> = events
+            .iter()
+            .filter(|event| event.is_finished())
+            .map(|event| event.task.id)
+            .collect();
+        if self.acknowledge && !finished.is_empty() {
+            self.tasks
+                .acknowledge(
+                    None,
+                    &crate::types::AcknowledgeTasksRequestBody { tasks: finished },
+                )
+                .await?;
+        }
+        Ok(events)
+    }
+
+    #[doc = " Turn the watcher into a stream of status changes. When watching a fixed set"]
+    #[doc = " of tasks the stream ends once all of them finished."]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn into_stream(
+        self,
+    ) -> impl futures::Stream<Item = Result<crate::types::TaskEvent, crate::types::error::Error>> + Unpin
+    {
+        use futures::StreamExt;
+        let state = (
+            self,
+            std::collections::HashMap::new(),
+            std::collections::VecDeque::new(),
+            true,
+        );
+        futures::stream::try_unfold(
+            state,
+            |(watcher, mut seen, mut queue, mut first)| async move {
+                loop {
+                    if let Some(event) = queue.pop_front() {
+                        return Ok(Some((event, (watcher, seen, queue, first))));
+                    }
+                    if let Some(task_ids) = &watcher.task_ids {
+                        let done = task_ids.iter().all(|task_id| {
+                            seen.get(task_id)
+                                .is_some_and(crate::types::StatusEnum::is_finished)
+                        });
+                        if done {
+                            return Ok(None);
+                        }
+                    }
+                    if !first {
+                        tokio::time::sleep(watcher.interval).await;
+                    }
+                    first = false;
+                    queue.extend(watcher.poll(&mut seen).await?);
+                }
+            },
+        )
+        .boxed()
+    }
 }
@@ -0,0 +1,643 @@
+use crate::Client;
+use anyhow::Result;
+
+#[doc = " The kinds of objects documents refer to by id and a [`Taxonomy`] resolves."]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TaxonomyKind {
+    #[doc = " Tags, `/api/tags/`."]
+    Tag,
+    #[doc = " Correspondents, `/api/correspondents/`."]
+    Correspondent,
+    #[doc = " Document types, `/api/document_types/`."]
+    DocumentType,
+    #[doc = " Storage paths, `/api/storage_paths/`."]
+    StoragePath,
+}
+
+#[doc = " The objects of a [`Taxonomy`] with a name, see [`Taxonomy::id_of`]."]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NameMatch {
+    #[doc = " No object has the name."]
+    None,
+    #[doc = " Exactly one object has the name."]
+    One(i64),
+    #[doc = " Objects of different owners share the name, by ascending id."]
+    Ambiguous(Vec<i64>),
+}
+
+impl NameMatch {
+    #[doc = " The id of the object if the name is unique."]
+    pub fn id(&self) -> Option<i64> {
+        match self {
+            NameMatch::One(id) => Some(*id),
+            NameMatch::None | NameMatch::Ambiguous(_) => None,
+        }
+    }
+}
+
+#[doc = " The largest page size the server accepts, so lists load with few requests."]
+const LIST_PAGE_SIZE: &str = "100000";
+
+#[doc = " A cache of tags, correspondents, document types and storage paths, resolving"]
+#[doc = " ids to names and back."]
+#[doc = ""]
+#[doc = " Names are matched case-insensitively. The server only requires names to be"]
+#[doc = " unique per owner, so a user who can view the objects of several owners may see a"]
+#[doc = " name more than once, see [`NameMatch::Ambiguous`]. Objects the user may not view"]
+#[doc = " are missing from the cache."]
+#[derive(Clone, Debug)]
+pub struct Taxonomy {
+    client: Client,
+    tags: std::collections::BTreeMap<i64, crate::types::Tag>,
+    correspondents: std::collections::BTreeMap<i64, crate::types::Correspondent>,
+    document_types: std::collections::BTreeMap<i64, crate::types::DocumentType>,
+    storage_paths: std::collections::BTreeMap<i64, crate::types::StoragePath>,
+    #[doc = " Ids by kind and lowercase name."]
+    ids: std::collections::HashMap<(TaxonomyKind, String), Vec<i64>>,
+    ttl: std::time::Duration,
+    #[cfg(not(target_arch = "wasm32"))]
+    loaded: std::time::Instant,
+}
+
+impl Taxonomy {
+    #[doc = " How long a loaded taxonomy is fresh unless set with [`Taxonomy::ttl`]."]
+    pub const DEFAULT_TTL: std::time::Duration = std::time::Duration::from_secs(300);
+
+    #[doc = " Load all tags, correspondents, document types and storage paths.\n\n```rust,no_run\nasync fn example_taxonomy_load() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let taxonomy = paperless_api_client::taxonomy::Taxonomy::load(&client).await?;\n    let tag = taxonomy.id_of(paperless_api_client::taxonomy::TaxonomyKind::Tag, \"Inbox\");\n    println!(\"{:?}\", tag);\n    Ok(())\n}\n```"]
+    pub async fn load(client: &Client) -> Result<Self, crate::types::error::Error> {
+        let mut taxonomy = Self::empty(client);
+        taxonomy.reload().await?;
+        Ok(taxonomy)
+    }
+
+    #[doc = " A taxonomy without objects, filled by [`Taxonomy::reload`]."]
+    pub(crate) fn empty(client: &Client) -> Self {
+        Self {
+            client: client.clone(),
+            tags: Default::default(),
+            correspondents: Default::default(),
+            document_types: Default::default(),
+            storage_paths: Default::default(),
+            ids: Default::default(),
+            ttl: Self::DEFAULT_TTL,
+            #[cfg(not(target_arch = "wasm32"))]
+            loaded: std::time::Instant::now(),
+        }
+    }
+
+    #[doc = " Set how long the loaded objects are fresh, see [`Taxonomy::refresh`]."]
+    pub fn ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    async fn list<P>(&self, path: &str) -> Result<Vec<P::Item>, crate::types::error::Error>
+    where
+        P: crate::types::paginate::Pagination + serde::de::DeserializeOwned + Send + 'static,
+    {
+        crate::paginator::Paginator::<P>::resume(
+            self.client.clone(),
+            crate::paginator::PageCursor::first(
+                &self.client,
+                path,
+                vec![("page_size".to_string(), LIST_PAGE_SIZE.to_string())],
+            ),
+        )
+        .collect_all()
+        .await
+    }
+
+    #[doc = " Load all objects again, replacing the cached ones."]
+    pub async fn reload(&mut self) -> Result<(), crate::types::error::Error> {
+        let (tags, correspondents, document_types, storage_paths) = futures::try_join!(
+            self.list::<crate::types::PaginatedTagList>("api/tags/"),
+            self.list::<crate::types::PaginatedCorrespondentList>("api/correspondents/"),
+            self.list::<crate::types::PaginatedDocumentTypeList>("api/document_types/"),
+            self.list::<crate::types::PaginatedStoragePathList>("api/storage_paths/"),
+        )?;
+        self.index(tags, correspondents, document_types, storage_paths);
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.loaded = std::time::Instant::now();
+        }
+        Ok(())
+    }
+
+    pub(crate) fn index(
+        &mut self,
+        tags: Vec<crate::types::Tag>,
+        correspondents: Vec<crate::types::Correspondent>,
+        document_types: Vec<crate::types::DocumentType>,
+        storage_paths: Vec<crate::types::StoragePath>,
+    ) {
+        let names = tags
+            .iter()
+            .map(|o| ((TaxonomyKind::Tag, o.name.to_lowercase()), o.id))
+            .chain(
+                correspondents
+                    .iter()
+                    .map(|o| ((TaxonomyKind::Correspondent, o.name.to_lowercase()), o.id)),
+            )
+            .chain(
+                document_types
+                    .iter()
+                    .map(|o| ((TaxonomyKind::DocumentType, o.name.to_lowercase()), o.id)),
+            )
+            .chain(
+                storage_paths
+                    .iter()
+                    .map(|o| ((TaxonomyKind::StoragePath, o.name.to_lowercase()), o.id)),
+            );
+        self.ids.clear();
+        for (key, id) in names {
+            self.ids.entry(key).or_default().push(id);
+        }
+        for ids in self.ids.values_mut() {
+            ids.sort_unstable();
+        }
+        self.tags = tags.into_iter().map(|o| (o.id, o)).collect();
+        self.correspondents = correspondents.into_iter().map(|o| (o.id, o)).collect();
+        self.document_types = document_types.into_iter().map(|o| (o.id, o)).collect();
+        self.storage_paths = storage_paths.into_iter().map(|o| (o.id, o)).collect();
+    }
+
+    #[doc = " Returns true if the objects were loaded longer than the TTL ago."]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn is_stale(&self) -> bool {
+        self.loaded.elapsed() >= self.ttl
+    }
+
+    #[doc = " Returns true if the objects were loaded longer than the TTL ago. There is no"]
+    #[doc = " clock on wasm, so they are always stale."]
+    #[cfg(target_arch = "wasm32")]
+    pub fn is_stale(&self) -> bool {
+        true
+    }
+
+    #[doc = " Load all objects again if they are stale. Returns true if they were reloaded."]
+    pub async fn refresh(&mut self) -> Result<bool, crate::types::error::Error> {
+        if !self.is_stale() {
+            return Ok(false);
+        }
+        self.reload().await?;
+        Ok(true)
+    }
+
+    #[doc = " The tags by id."]
+    pub fn tags(&self) -> &std::collections::BTreeMap<i64, crate::types::Tag> {
+        &self.tags
+    }
+
+    #[doc = " The correspondents by id."]
+    pub fn correspondents(&self) -> &std::collections::BTreeMap<i64, crate::types::Correspondent> {
+        &self.correspondents
+    }
+
+    #[doc = " The document types by id."]
+    pub fn document_types(&self) -> &std::collections::BTreeMap<i64, crate::types::DocumentType> {
+        &self.document_types
+    }
+
+    #[doc = " The storage paths by id."]
+    pub fn storage_paths(&self) -> &std::collections::BTreeMap<i64, crate::types::StoragePath> {
+        &self.storage_paths
+    }
+
+    #[doc = " The name of the object of `kind` with `id`."]
+    pub fn name_of(&self, kind: TaxonomyKind, id: i64) -> Option<&str> {
+        match kind {
+            TaxonomyKind::Tag => self.tags.get(&id).map(|o| o.name.as_str()),
+            TaxonomyKind::Correspondent => self.correspondents.get(&id).map(|o| o.name.as_str()),
+            TaxonomyKind::DocumentType => self.document_types.get(&id).map(|o| o.name.as_str()),
+            TaxonomyKind::StoragePath => self.storage_paths.get(&id).map(|o| o.name.as_str()),
+        }
+    }
+
+    #[doc = " The objects of `kind` named `name`, ignoring case."]
+    pub fn id_of(&self, kind: TaxonomyKind, name: &str) -> NameMatch {
+        match self
+            .ids
+            .get(&(kind, name.to_lowercase()))
+            .map(Vec::as_slice)
+        {
+            None | Some([]) => NameMatch::None,
+            Some([id]) => NameMatch::One(*id),
+            Some(ids) => NameMatch::Ambiguous(ids.to_vec()),
+        }
+    }
+
+    #[doc = " The objects the server would assign to a document with `content` by their"]
+    #[doc = " match rules, see [`crate::matching`]. Objects matching by `auto` are not"]
+    #[doc = " predicted."]
+    pub fn predict(&self, content: &str) -> MatchPrediction {
+        fn matching<T: crate::matching::Matchable>(
+            objects: &std::collections::BTreeMap<i64, T>,
+            name: impl Fn(&T) -> &str,
+            content: &str,
+        ) -> Vec<i64> {
+            let mut matched: Vec<(String, i64)> = objects
+                .iter()
+                .filter(|(_, object)| object.match_rule().matches(content))
+                .map(|(id, object)| (name(object).to_lowercase(), *id))
+                .collect();
+            matched.sort();
+            matched.into_iter().map(|(_, id)| id).collect()
+        }
+        MatchPrediction {
+            tags: matching(&self.tags, |o| &o.name, content),
+            correspondents: matching(&self.correspondents, |o| &o.name, content),
+            document_types: matching(&self.document_types, |o| &o.name, content),
+            storage_paths: matching(&self.storage_paths, |o| &o.name, content),
+        }
+    }
+
+    #[doc = " Tell why the object of `kind` with `id` does or does not match `content`."]
+    pub fn explain(
+        &self,
+        kind: TaxonomyKind,
+        id: i64,
+        content: &str,
+    ) -> Option<crate::matching::MatchExplanation> {
+        use crate::matching::Matchable;
+        let rule = match kind {
+            TaxonomyKind::Tag => self.tags.get(&id)?.match_rule(),
+            TaxonomyKind::Correspondent => self.correspondents.get(&id)?.match_rule(),
+            TaxonomyKind::DocumentType => self.document_types.get(&id)?.match_rule(),
+            TaxonomyKind::StoragePath => self.storage_paths.get(&id)?.match_rule(),
+        };
+        Some(rule.explain(content))
+    }
+
+    #[doc = " A view of `document` with names instead of ids."]
+    pub fn expand(&self, document: &crate::types::Document) -> ExpandedDocument {
+        let name =
+            |kind, id: Option<i64>| id.and_then(|id| self.name_of(kind, id)).map(str::to_string);
+        ExpandedDocument {
+            correspondent: name(TaxonomyKind::Correspondent, document.correspondent),
+            document_type: name(TaxonomyKind::DocumentType, document.document_type),
+            storage_path: name(TaxonomyKind::StoragePath, document.storage_path),
+            tags: document
+                .tags
+                .iter()
+                .filter_map(|id| name(TaxonomyKind::Tag, Some(*id)))
+                .collect(),
+            document: document.clone(),
+        }
+    }
+}
+
+#[doc = " The objects matching document content, see [`Taxonomy::predict`]."]
+#[doc = ""]
+#[doc = " Every list is ordered by name, like the server orders candidates."]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MatchPrediction {
+    #[doc = " The matching tags, all of which are assigned."]
+    pub tags: Vec<i64>,
+    #[doc = " The matching correspondents."]
+    pub correspondents: Vec<i64>,
+    #[doc = " The matching document types."]
+    pub document_types: Vec<i64>,
+    #[doc = " The matching storage paths."]
+    pub storage_paths: Vec<i64>,
+}
+
+impl MatchPrediction {
+    #[doc = " The correspondent assigned: the first one if several match."]
+    pub fn correspondent(&self) -> Option<i64> {
+        self.correspondents.first().copied()
+    }
+
+    #[doc = " The document type assigned: the first one if several match."]
+    pub fn document_type(&self) -> Option<i64> {
+        self.document_types.first().copied()
+    }
+
+    #[doc = " The storage path assigned: the first one if several match."]
+    pub fn storage_path(&self) -> Option<i64> {
+        self.storage_paths.first().copied()
+    }
+}
+
+#[doc = " A document with the names of its correspondent, document type, storage path and"]
+#[doc = " tags, see [`Taxonomy::expand`]."]
+#[doc = ""]
+#[doc = " It serializes like the document with the ids replaced by names. Ids the"]
+#[doc = " taxonomy doesn't know have no name and tags without a name are left out."]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpandedDocument {
+    #[doc = " The document itself, with ids."]
+    pub document: crate::types::Document,
+    #[doc = " The name of the correspondent."]
+    pub correspondent: Option<String>,
+    #[doc = " The name of the document type."]
+    pub document_type: Option<String>,
+    #[doc = " The name of the storage path."]
+    pub storage_path: Option<String>,
+    #[doc = " The names of the tags."]
+    pub tags: Vec<String>,
+}
+
+impl serde::Serialize for ExpandedDocument {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut value = serde_json::to_value(&self.document).map_err(serde::ser::Error::custom)?;
+        if let Some(object) = value.as_object_mut() {
+            object.insert(
+                "correspondent".to_string(),
+                self.correspondent.clone().into(),
+            );
+            object.insert(
+                "document_type".to_string(),
+                self.document_type.clone().into(),
+            );
+            object.insert("storage_path".to_string(), self.storage_path.clone().into());
+            object.insert("tags".to_string(), self.tags.clone().into());
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl std::fmt::Display for ExpandedDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+#[doc = " Objects, and requests creating them, whose names are unique per owner."]
+pub(crate) trait Owned {
+    #[doc = " The id of the owner, `None` if the object has none or names are unique"]
+    #[doc = " across all users."]
+    fn owner(&self) -> Option<i64>;
+}
+
+macro_rules! impl_owned {
+    ($($ty:ident),*) => {
+        $(
+            impl Owned for crate::types::$ty {
+                fn owner(&self) -> Option<i64> {
+                    self.owner
+                }
+            }
+        )*
+    };
+}
+
+impl_owned!(
+    Tag,
+    TagRequest,
+    Correspondent,
+    CorrespondentRequest,
+    DocumentType,
+    DocumentTypeRequest,
+    StoragePath,
+    StoragePathRequest
+);
+
+// Custom fields have no owner, their names are unique across all users.
+impl Owned for crate::types::CustomField {
+    fn owner(&self) -> Option<i64> {
+        None
+    }
+}
+
+impl Owned for crate::types::CustomFieldRequest {
+    fn owner(&self) -> Option<i64> {
+        None
+    }
+}
+
+#[doc = " Find the object named `name`, ignoring case, with a `name__iexact` filter. With"]
+#[doc = " an `owner`, only objects of this owner are considered. Fails if several objects"]
+#[doc = " remain, which happens as names are only unique per owner."]
+pub(crate) async fn find_by_name<P>(
+    client: &Client,
+    path: &str,
+    name: &str,
+    owner: Option<i64>,
+    name_of: fn(&P::Item) -> &str,
+) -> Result<Option<P::Item>, crate::types::error::Error>
+where
+    P: crate::types::paginate::Pagination + serde::de::DeserializeOwned + Send + 'static,
+    P::Item: Owned,
+{
+    let query = vec![("name__iexact".to_string(), name.to_string())];
+    let found = crate::paginator::Paginator::<P>::resume(
+        client.clone(),
+        crate::paginator::PageCursor::first(client, path, query),
+    )
+    .collect_all()
+    .await?;
+    select_by_name(found, name, owner, name_of)
+}
+
+#[doc = " The object among `found` named `name`, ignoring case, of `owner` if given."]
+#[doc = " Fails if there are several."]
+pub(crate) fn select_by_name<T: Owned>(
+    found: Vec<T>,
+    name: &str,
+    owner: Option<i64>,
+    name_of: fn(&T) -> &str,
+) -> Result<Option<T>, crate::types::error::Error> {
+    let name = name.to_lowercase();
+    let mut found: Vec<T> = found
+        .into_iter()
+        .filter(|existing| name_of(existing).to_lowercase() == name)
+        .filter(|existing| owner.is_none_or(|owner| existing.owner() == Some(owner)))
+        .collect();
+    if found.len() > 1 {
+        let owners: Vec<Option<i64>> = found.iter().map(Owned::owner).collect();
+        return Err(crate::types::error::Error::InvalidRequest(format!(
+            "{} objects are named {name:?}, owned by {owners:?}; set an owner to pick one",
+            found.len()
+        )));
+    }
+    Ok(found.pop())
+}
+
+#[doc = " Return the object `find` finds, or create it. If creating fails because another"]
+#[doc = " client created it meanwhile, return the object found then."]
+pub(crate) async fn get_or_create<T, F, FF, C, CF>(
+    find: F,
+    create: C,
+) -> Result<(T, bool), crate::types::error::Error>
+where
+    F: Fn() -> FF,
+    FF: std::future::Future<Output = Result<Option<T>, crate::types::error::Error>>,
+    C: FnOnce() -> CF,
+    CF: std::future::Future<Output = Result<T, crate::types::error::Error>>,
+{
+    if let Some(existing) = find().await? {
+        return Ok((existing, false));
+    }
+    match create().await {
+        Ok(created) => Ok((created, true)),
+        Err(err) if err.is_unique_violation("name") => match find().await? {
+            Some(existing) => Ok((existing, false)),
+            None => Err(err),
+        },
+        Err(err) => Err(err),
+    }
+}
+
+#[doc = " Update the object `find` finds, or create it. If creating fails because another"]
+#[doc = " client created it meanwhile, update the object found then."]
+pub(crate) async fn upsert<T, F, FF, C, CF, U, UF>(
+    find: F,
+    create: C,
+    update: U,
+) -> Result<T, crate::types::error::Error>
+where
+    F: Fn() -> FF,
+    FF: std::future::Future<Output = Result<Option<T>, crate::types::error::Error>>,
+    C: FnOnce() -> CF,
+    CF: std::future::Future<Output = Result<T, crate::types::error::Error>>,
+    U: FnOnce(T) -> UF,
+    UF: std::future::Future<Output = Result<T, crate::types::error::Error>>,
+{
+    if let Some(existing) = find().await? {
+        return update(existing).await;
+    }
+    match create().await {
+        Ok(created) => Ok(created),
+        Err(err) if err.is_unique_violation("name") => match find().await? {
+            Some(existing) => update(existing).await,
+            None => Err(err),
+        },
+        Err(err) => Err(err),
+    }
+}
+
+#[doc = " The doc paragraph on name clashes of `find_by_name` (`find`) or of a method"]
+#[doc = " taking the object as `defaults` or `body`, for objects with names unique per"]
+#[doc = " `owned` or across all users (`global`)."]
+macro_rules! name_scope_doc {
+    (global, $arg:ident, $object:literal) => {
+        ""
+    };
+    (owned, find, $object:literal) => {
+        concat!(
+            "Fails with [`Error::InvalidRequest`](crate::types::error::Error::InvalidRequest) if several ",
+            $object, "s of different owners have the name.\n\n"
+        )
+    };
+    (owned, $arg:ident, $object:literal) => {
+        concat!(
+            "Names are only unique per owner: if `", stringify!($arg), "` sets an owner, only ", $object,
+            "s of this owner are considered, otherwise the call fails if several ", $object,
+            "s have the name.\n\n"
+        )
+    };
+}
+pub(crate) use name_scope_doc;
+
+#[doc = " Add `find_by_name`, `get_or_create` and `upsert` to the interface of an object"]
+#[doc = " with a unique name, `owned` if names are only unique per owner, else `global`."]
+macro_rules! impl_name_lookups {
+    ($scope:ident, $object:literal, $path:literal, $accessor:ident, $item:ident, $list:ident, $request:ident, $example:literal) => {
+        #[doc = concat!(
+            "Find the ", $object, " named `name`, ignoring case, with a `GET` request to `/", $path, "`.\n\n",
+            crate::taxonomy::name_scope_doc!($scope, find, $object),
+            "```rust,no_run\nasync fn example_", stringify!($accessor), "_find_by_name() -> anyhow::Result<()> {\n",
+            "    let client = paperless_api_client::Client::new_from_env();\n",
+            "    let result: Option<paperless_api_client::types::", stringify!($item), "> =\n",
+            "        client.", stringify!($accessor), "().find_by_name(\"some-string\").await?;\n",
+            "    println!(\"{:?}\", result);\n    Ok(())\n}\n```"
+        )]
+        #[tracing::instrument]
+        pub async fn find_by_name<'a>(
+            &'a self,
+            name: &'a str,
+        ) -> Result<Option<crate::types::$item>, crate::types::error::Error> {
+            crate::taxonomy::find_by_name::<crate::types::$list>(
+                &self.client,
+                $path,
+                name,
+                None,
+                |existing| &existing.name,
+            )
+            .await
+        }
+
+        #[doc = concat!(
+            "Return the ", $object, " named `name`, ignoring case, or create it from `defaults` with the name `name`.\n\n",
+            "Returns the ", $object, " and whether it was created. If another client creates the ", $object,
+            " at the same time, the server rejects the duplicate name and the ", $object,
+            " created by the other client is returned.\n\n",
+            crate::taxonomy::name_scope_doc!($scope, defaults, $object),
+            "```rust,no_run\nasync fn example_", stringify!($accessor), "_get_or_create() -> anyhow::Result<()> {\n",
+            "    let client = paperless_api_client::Client::new_from_env();\n",
+            "    let (result, created): (paperless_api_client::types::", stringify!($item), ", bool) = client\n",
+            "        .", stringify!($accessor), "()\n        .get_or_create(\n            \"some-string\",\n            ",
+            $example, ",\n        )\n        .await?;\n",
+            "    println!(\"{:?} {:?}\", result, created);\n    Ok(())\n}\n```"
+        )]
+        #[tracing::instrument]
+        pub async fn get_or_create<'a>(
+            &'a self,
+            name: &'a str,
+            defaults: &'a crate::types::$request,
+        ) -> Result<(crate::types::$item, bool), crate::types::error::Error> {
+            let body = crate::types::$request {
+                name: name.to_string(),
+                ..defaults.clone()
+            };
+            let owner = crate::taxonomy::Owned::owner(&body);
+            crate::taxonomy::get_or_create(
+                || {
+                    crate::taxonomy::find_by_name::<crate::types::$list>(
+                        &self.client,
+                        $path,
+                        name,
+                        owner,
+                        |existing| &existing.name,
+                    )
+                },
+                || self.create(&body),
+            )
+            .await
+        }
+
+        #[doc = concat!(
+            "Replace the ", $object, " named `name`, ignoring case, with `body`, or create it.\n\n",
+            "The name of `body` is replaced by `name`. If another client creates the ", $object,
+            " at the same time, the ", $object, " created by the other client is replaced.\n\n",
+            crate::taxonomy::name_scope_doc!($scope, body, $object),
+            "```rust,no_run\nasync fn example_", stringify!($accessor), "_upsert() -> anyhow::Result<()> {\n",
+            "    let client = paperless_api_client::Client::new_from_env();\n",
+            "    let result: paperless_api_client::types::", stringify!($item), " = client\n",
+            "        .", stringify!($accessor), "()\n        .upsert(\n            \"some-string\",\n            ",
+            $example, ",\n        )\n        .await?;\n",
+            "    println!(\"{:?}\", result);\n    Ok(())\n}\n```"
+        )]
+        #[tracing::instrument]
+        pub async fn upsert<'a>(
+            &'a self,
+            name: &'a str,
+            body: &'a crate::types::$request,
+        ) -> Result<crate::types::$item, crate::types::error::Error> {
+            let body = crate::types::$request {
+                name: name.to_string(),
+                ..body.clone()
+            };
+            let owner = crate::taxonomy::Owned::owner(&body);
+            crate::taxonomy::upsert(
+                || {
+                    crate::taxonomy::find_by_name::<crate::types::$list>(
+                        &self.client,
+                        $path,
+                        name,
+                        owner,
+                        |existing| &existing.name,
+                    )
+                },
+                || self.create(&body),
+                |existing| self.update(existing.id, &body),
+            )
+            .await
+        }
+    };
+}
+pub(crate) use impl_name_lookups;
@@ -1 +1,1207 @@
+use pretty_assertions::assert_eq;
 
+#[test]
+fn test_document_query_params() {
+    let query = crate::types::DocumentQuery::new()
+        .tags_all([1, 2])
+        .tags_none([3])
+        .correspondent_name("ACME")
+        .created_in(
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+                ..chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        )
+        .asn_in(100..=200)
+        .fields(["id", "title"])
+        .order_by_desc("created")
+        .page_size(25);
+    assert_eq!(
+        query.query_params(),
+        vec![
+            ("archive_serial_number__gte", "100".to_string()),
+            ("archive_serial_number__lte", "200".to_string()),
+            ("correspondent__name__iexact", "ACME".to_string()),
+            ("created__gte", "2024-01-01".to_string()),
+            ("created__lt", "2025-01-01".to_string()),
+            ("fields", "id,title".to_string()),
+            ("ordering", "-created".to_string()),
+            ("page_size", "25".to_string()),
+            ("tags__id__all", "1,2".to_string()),
+            ("tags__id__none", "3".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_document_query_empty() {
+    assert!(crate::types::DocumentQuery::new().query_params().is_empty());
+}
+
+#[test]
+fn test_task_wait_options_backoff() {
+    let options = crate::types::TaskWaitOptions {
+        initial_interval: std::time::Duration::from_secs(1),
+        max_interval: std::time::Duration::from_secs(3),
+        multiplier: 2.0,
+        timeout: None,
+    };
+    let second = options.next_interval(options.initial_interval);
+    assert_eq!(second, std::time::Duration::from_secs(2));
+    assert_eq!(
+        options.next_interval(second),
+        std::time::Duration::from_secs(3)
+    );
+    let unbounded = crate::types::TaskWaitOptions {
+        multiplier: f64::INFINITY,
+        ..options.clone()
+    };
+    assert_eq!(
+        unbounded.next_interval(std::time::Duration::MAX),
+        std::time::Duration::from_secs(3)
+    );
+    assert_eq!(
+        unbounded.next_interval(std::time::Duration::ZERO),
+        std::time::Duration::from_secs(3)
+    );
+
+    let started = std::time::Instant::now();
+    assert_eq!(
+        options.delay(started, std::time::Duration::from_secs(2)),
+        Some(std::time::Duration::from_secs(2))
+    );
+    let bounded = crate::types::TaskWaitOptions {
+        timeout: Some(std::time::Duration::from_secs(60)),
+        ..options
+    };
+    let delay = bounded
+        .delay(started, std::time::Duration::from_secs(3600))
+        .unwrap();
+    assert!(delay <= std::time::Duration::from_secs(60));
+    assert!(delay > std::time::Duration::from_secs(59));
+    let expired = started - std::time::Duration::from_secs(61);
+    assert_eq!(
+        bounded.delay(expired, std::time::Duration::from_secs(1)),
+        None
+    );
+}
+
+#[test]
+fn test_parse_content_disposition() {
+    use crate::types::download::parse_content_disposition;
+    assert_eq!(
+        parse_content_disposition(b"attachment; filename=\"scan 01.pdf\""),
+        Some("scan 01.pdf".to_string())
+    );
+    assert_eq!(
+        parse_content_disposition(
+            b"inline; filename=\"Rechnung.pdf\"; filename*=utf-8''Rechnung%20M%C3%A4rz.pdf"
+        ),
+        Some("Rechnung März.pdf".to_string())
+    );
+    assert_eq!(
+        parse_content_disposition(b"attachment; filename=\"a;b \\\"c\\\".pdf\"; size=3"),
+        Some("a;b \"c\".pdf".to_string())
+    );
+    assert_eq!(
+        parse_content_disposition(b"attachment; filename*=broken.pdf; filename=plain.pdf"),
+        Some("plain.pdf".to_string())
+    );
+    assert_eq!(
+        parse_content_disposition(b"attachment; filename=plain.pdf ; filename*=utf-8''%FF.pdf"),
+        Some("plain.pdf".to_string())
+    );
+    assert_eq!(parse_content_disposition(b"inline"), None);
+}
+
+#[test]
+fn test_upload_metadata_form_fields() {
+    let metadata = crate::types::UploadMetadata {
+        title: Some("Invoice".to_string()),
+        tags: Some(vec![1, 2]),
+        archive_serial_number: Some(42),
+        ..Default::default()
+    };
+    assert_eq!(
+        metadata.form_fields(),
+        vec![
+            ("title", "Invoice".to_string()),
+            ("tags", "1".to_string()),
+            ("tags", "2".to_string()),
+            ("archive_serial_number", "42".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_task_event_from_change() {
+    let task: crate::types::TasksView = serde_json::from_value(serde_json::json!({
+        "id": 7,
+        "task_id": "0f9a",
+        "status": "SUCCESS",
+        "result": "Success. New document id 12 created",
+        "related_document": "12"
+    }))
+    .unwrap();
+    let event =
+        crate::types::TaskEvent::from_change(Some(crate::types::StatusEnum::Started), task.clone())
+            .unwrap();
+    assert_eq!(event.status, crate::types::StatusEnum::Success);
+    assert_eq!(event.related_document, Some(12));
+    assert!(event.is_finished());
+    assert!(
+        crate::types::TaskEvent::from_change(Some(crate::types::StatusEnum::Success), task)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_status_message_parse() {
+    let message: crate::types::StatusMessage = serde_json::from_str(
+        r#"{"type": "status_update", "data": {"filename": "scan.pdf", "task_id": "0f9a",
+            "current_progress": 100, "max_progress": 100, "status": "SUCCESS",
+            "message": "finished", "document_id": 12}}"#,
+    )
+    .unwrap();
+    let crate::types::StatusMessage::StatusUpdate(update) = message else {
+        panic!("expected a status update, got {message:?}");
+    };
+    assert_eq!(update.status, crate::types::ProgressStatus::Success);
+    assert_eq!(update.document_id, Some(12));
+
+    let message: crate::types::StatusMessage =
+        serde_json::from_str(r#"{"type": "document_updated", "data": {"document_id": 12}}"#)
+            .unwrap();
+    assert!(matches!(message, crate::types::StatusMessage::Unknown(_)));
+}
+
+#[test]
+fn test_api_error_parse() {
+    let error = crate::types::error::Error::Server {
+        body: r#"{"name": ["This field must be unique."],
+            "custom_fields": [{}, {"value": ["Enter a valid date."]}],
+            "non_field_errors": ["Invalid data."]}"#
+            .to_string(),
+        status: reqwest::StatusCode::BAD_REQUEST,
+    };
+    let api_error = error.api_error().unwrap();
+    assert_eq!(
+        api_error.non_field_errors,
+        vec!["Invalid data.".to_string()]
+    );
+    assert_eq!(
+        api_error.fields.get("custom_fields.1.value"),
+        ["Enter a valid date.".to_string()]
+    );
+    assert!(error.is_validation_error());
+    assert!(error.is_unique_violation("name"));
+    assert!(!error.is_unique_violation("custom_fields"));
+    assert!(!error.is_retryable());
+
+    let error = crate::types::error::Error::Server {
+        body: r#"{"detail": "You do not have permission to perform this action."}"#.to_string(),
+        status: reqwest::StatusCode::FORBIDDEN,
+    };
+    assert!(error.is_permission_denied());
+    assert_eq!(
+        error.api_error().unwrap().detail.as_deref(),
+        Some("You do not have permission to perform this action.")
+    );
+    assert!(error.validation_errors().is_empty());
+
+    let error = crate::types::error::Error::Server {
+        body: "<html>Bad Gateway</html>".to_string(),
+        status: reqwest::StatusCode::BAD_GATEWAY,
+    };
+    assert!(error.api_error().is_none());
+    assert!(error.is_retryable());
+}
+
+#[cfg(feature = "websocket")]
+#[test]
+fn test_websocket_status_url() {
+    assert_eq!(
+        crate::websocket::status_url("https://paperless.example.com/").unwrap(),
+        "wss://paperless.example.com/ws/status/"
+    );
+    assert_eq!(
+        crate::websocket::status_url("http://localhost:8000").unwrap(),
+        "ws://localhost:8000/ws/status/"
+    );
+    assert!(matches!(
+        crate::websocket::status_url("paperless.example.com"),
+        Err(crate::types::error::Error::InvalidRequest(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_client_builder_auth_headers() {
+    let client = crate::Client::builder()
+        .basic_auth("alice", "secret")
+        .base_url("https://paperless.example.com/")
+        .build()
+        .unwrap();
+    let headers = client.auth_headers().await.unwrap();
+    assert_eq!(headers["authorization"], "Basic YWxpY2U6c2VjcmV0");
+
+    let client = crate::Client::builder()
+        .session("abc", "xyz")
+        .base_url("https://paperless.example.com/")
+        .build()
+        .unwrap();
+    let headers = client.auth_headers().await.unwrap();
+    assert_eq!(headers["cookie"], "sessionid=abc; csrftoken=xyz");
+    assert_eq!(headers["x-csrftoken"], "xyz");
+    assert_eq!(headers["referer"], "https://paperless.example.com/");
+
+    let headers = crate::Client::new("t0k3n").auth_headers().await.unwrap();
+    assert_eq!(headers["authorization"], "Token t0k3n");
+
+    let headers = crate::Client::new("t0k3n").request_headers().await.unwrap();
+    assert_eq!(headers["accept"], "application/json");
+    let pinned = crate::Client::builder()
+        .token("t0k3n")
+        .api_version(7)
+        .build()
+        .unwrap();
+    let headers = pinned.request_headers().await.unwrap();
+    assert_eq!(headers["accept"], "application/json; version=7");
+}
+
+#[tokio::test]
+async fn test_credentials_token_refresh() {
+    use std::io::{BufRead, Read, Write};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // A server refusing the first token it handed out.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let issued = std::sync::Arc::new(AtomicUsize::new(0));
+    let counter = issued.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let issued = counter.clone();
+            std::thread::spawn(move || {
+                let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let (mut authorization, mut length) = (String::new(), 0);
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    let (name, value) = line.split_once(": ").unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "authorization" => authorization = value.to_string(),
+                        "content-length" => length = value.parse().unwrap(),
+                        _ => {}
+                    }
+                }
+                reader.read_exact(&mut vec![0; length]).unwrap();
+                let (status, body) = if request_line.starts_with("POST /api/token/ ") {
+                    std::thread::sleep(std::time::Duration::from_millis(50));
+                    let n = issued.fetch_add(1, Ordering::SeqCst) + 1;
+                    ("200 OK", format!(r#"{{"token":"t{n}"}}"#))
+                } else if authorization == "Token t1" {
+                    (
+                        "401 Unauthorized",
+                        r#"{"detail":"Invalid token."}"#.to_string(),
+                    )
+                } else {
+                    ("200 OK", "{}".to_string())
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            });
+        }
+    });
+
+    let client = crate::Client::builder()
+        .credentials("alice", "secret")
+        .base_url(format!("http://{addr}"))
+        .retry_policy(crate::retry::RetryPolicy::none())
+        .build()
+        .unwrap();
+    let (first, second) = futures::join!(client.auth_headers(), client.auth_headers());
+    assert_eq!(first.unwrap()["authorization"], "Token t1");
+    assert_eq!(second.unwrap()["authorization"], "Token t1");
+    assert_eq!(issued.load(Ordering::SeqCst), 1);
+
+    client.server_capabilities().await.unwrap();
+    assert_eq!(issued.load(Ordering::SeqCst), 2);
+    let headers = client.auth_headers().await.unwrap();
+    assert_eq!(headers["authorization"], "Token t2");
+}
+
+#[test]
+fn test_retry_policy() {
+    let policy = crate::retry::RetryPolicy::default().delays(
+        std::time::Duration::from_secs(1),
+        std::time::Duration::from_secs(5),
+    );
+    assert_eq!(policy.backoff(0), std::time::Duration::from_secs(1));
+    assert_eq!(policy.backoff(2), std::time::Duration::from_secs(4));
+    assert_eq!(policy.backoff(10), std::time::Duration::from_secs(5));
+    assert!(policy.is_idempotent(&http::Method::GET));
+    assert!(!policy.is_idempotent(&http::Method::POST));
+    assert!(policy
+        .idempotent(http::Method::POST)
+        .is_idempotent(&http::Method::POST));
+
+    let response: reqwest::Response = http::Response::builder()
+        .status(429)
+        .header("Retry-After", "7")
+        .body("")
+        .unwrap()
+        .into();
+    assert_eq!(
+        crate::retry::retry_after(&response),
+        Some(std::time::Duration::from_secs(7))
+    );
+}
+
+#[test]
+fn test_token_bucket() {
+    let start = std::time::Instant::now();
+    let mut bucket = crate::limit::TokenBucket::new(2.0, 2);
+    bucket.updated = start;
+    assert_eq!(bucket.reserve(start), std::time::Duration::ZERO);
+    assert_eq!(bucket.reserve(start), std::time::Duration::ZERO);
+    assert_eq!(bucket.reserve(start), std::time::Duration::from_millis(500));
+    assert_eq!(bucket.reserve(start), std::time::Duration::from_secs(1));
+    let later = start + std::time::Duration::from_secs(10);
+    assert_eq!(bucket.reserve(later), std::time::Duration::ZERO);
+
+    let mut slow = crate::limit::TokenBucket::new(1e-300, 1);
+    slow.updated = start;
+    assert_eq!(slow.reserve(start), std::time::Duration::ZERO);
+    assert_eq!(slow.reserve(start), std::time::Duration::MAX);
+}
+
+#[test]
+fn test_server_capabilities() {
+    let capabilities = crate::types::ServerCapabilities {
+        api_version: Some(7),
+        server_version: Some("2.11.6".to_string()),
+        endpoints: vec!["documents".to_string(), "trash".to_string()],
+    };
+    assert_eq!(capabilities.version(), Some((2, 11, 6)));
+    assert!(capabilities.supports(crate::types::Capability::Trash));
+    assert!(!capabilities.supports(crate::types::Capability::Workflows));
+    assert!(!capabilities.supports(crate::types::Capability::CustomFieldQuery));
+    assert!(matches!(
+        capabilities.require(crate::types::Capability::EditPdf),
+        Err(crate::types::error::Error::Unsupported { .. })
+    ));
+
+    let unknown = crate::types::ServerCapabilities {
+        api_version: None,
+        server_version: None,
+        endpoints: vec![],
+    };
+    assert!(unknown.supports(crate::types::Capability::EditPdf));
+}
+
+#[tokio::test]
+async fn test_require_capability_unknown_server() {
+    let client = crate::Client::builder()
+        .token("t0k3n")
+        .base_url("http://[invalid")
+        .build()
+        .unwrap();
+    assert!(client.server_capabilities().await.is_err());
+    client
+        .require_capability(crate::types::Capability::EditPdf)
+        .await
+        .unwrap();
+    // A failed fetch isn't cached, the next call tries again.
+    assert!(client.server_capabilities().await.is_err());
+}
+
+#[test]
+fn test_cf_query() {
+    use crate::types::CfQuery;
+    let query = CfQuery::field("amount")
+        .gt(100)
+        .and(CfQuery::field(7).range("2024-01-01", "2024-12-31"))
+        .and(!CfQuery::field("paid").eq(true))
+        .or(CfQuery::field("invoices").contains([3, 4]));
+    assert_eq!(
+        query.to_json(),
+        serde_json::json!([
+            "OR",
+            [
+                [
+                    "AND",
+                    [
+                        ["amount", "gt", 100],
+                        [7, "range", ["2024-01-01", "2024-12-31"]],
+                        ["NOT", ["paid", "exact", true]]
+                    ]
+                ],
+                ["invoices", "contains", [3, 4]]
+            ]
+        ])
+    );
+    assert_eq!(
+        crate::types::DocumentQuery::new()
+            .custom_fields_match(&CfQuery::field("due").isnull(false))
+            .query_params(),
+        vec![(
+            "custom_field_query",
+            r#"["due","isnull",false]"#.to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_custom_field_values() {
+    use crate::types::{CustomFieldValue, DataTypeEnum, SelectOption};
+    let field = |data_type, extra_data| crate::types::CustomField {
+        id: 3,
+        name: "field".to_string(),
+        data_type,
+        extra_data,
+        document_count: 0,
+    };
+
+    let amount = field(DataTypeEnum::Monetary, None);
+    let value = amount
+        .parse_value(Some(&serde_json::json!("EUR123.45")))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        value,
+        CustomFieldValue::Monetary {
+            currency: Some("EUR".to_string()),
+            amount: "123.45".parse().unwrap(),
+        }
+    );
+    assert_eq!(
+        amount.value_to_json(&value).unwrap(),
+        serde_json::json!("EUR123.45")
+    );
+    assert!(amount
+        .value_to_json(&CustomFieldValue::parse_monetary("12.345").unwrap())
+        .is_err());
+    assert!(amount.value_to_json(&CustomFieldValue::Integer(1)).is_err());
+
+    let select = field(
+        DataTypeEnum::Select,
+        Some(serde_json::json!({"select_options": [
+            {"id": "a1", "label": "Open"},
+            {"id": "b2", "label": "Paid"}
+        ]})),
+    );
+    assert_eq!(
+        select
+            .instance(Some(&CustomFieldValue::Select(SelectOption::Label(
+                "Paid".to_string()
+            ))))
+            .unwrap()
+            .value,
+        Some(serde_json::json!("b2"))
+    );
+    assert!(select
+        .value_to_json(&CustomFieldValue::Select(SelectOption::Label(
+            "Lost".to_string()
+        )))
+        .is_err());
+
+    let links = field(DataTypeEnum::Documentlink, None);
+    assert_eq!(
+        links.parse_value(Some(&serde_json::json!([1, 2]))).unwrap(),
+        Some(CustomFieldValue::DocumentLink(vec![1, 2]))
+    );
+    assert_eq!(
+        links.parse_value(Some(&serde_json::Value::Null)).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_bulk_operation_parameters() {
+    use crate::types::{BulkOperation, EditPdfOperation, MethodEnum, PageRange, Rotation};
+    let request = BulkOperation::DeletePages {
+        pages: vec![PageRange::new(2, 4), 7.into()],
+    }
+    .on([9]);
+    assert_eq!(request.method, MethodEnum::DeletePages);
+    assert_eq!(
+        request.parameters.unwrap()["pages"],
+        serde_json::json!([2, 3, 4, 7])
+    );
+
+    let parameters = BulkOperation::EditPdf {
+        operations: vec![
+            EditPdfOperation::page(1).rotate(Rotation::Degrees90),
+            EditPdfOperation::page(2).to_document(1),
+        ],
+        delete_original: false,
+        update_document: true,
+        include_metadata: true,
+    }
+    .parameters();
+    assert_eq!(
+        parameters["operations"],
+        serde_json::json!([{"page": 1, "rotate": 90}, {"page": 2, "doc": 1}])
+    );
+
+    let parameters = BulkOperation::SetCorrespondent {
+        correspondent: None,
+    }
+    .parameters();
+    assert_eq!(parameters["correspondent"], serde_json::Value::Null);
+}
+
+#[test]
+fn test_paginator_cursor() {
+    use crate::types::paginate::Pagination;
+    let mut client = crate::Client::new("token");
+    client.set_base_url("https://paperless.example.com");
+    let history = client.documents().history_paginator(4, Some(25));
+    let cursor = history.cursor().unwrap();
+    assert_eq!(
+        cursor,
+        &crate::paginator::PageCursor {
+            url: "https://paperless.example.com/api/documents/4/history/".to_string(),
+            query: vec![("page_size".to_string(), "25".to_string())],
+        }
+    );
+    let saved = serde_json::to_string(cursor).unwrap();
+    let resumed: crate::paginator::Paginator<crate::types::PaginatedLogEntryList> =
+        crate::paginator::Paginator::resume(client.clone(), serde_json::from_str(&saved).unwrap());
+    assert_eq!(resumed.cursor(), Some(cursor));
+
+    let page = crate::types::PaginatedNotesList {
+        count: 42,
+        next: None,
+        previous: None,
+        results: vec![],
+        all: None,
+    };
+    assert_eq!(page.total_count(), Some(42));
+    assert_eq!(vec![1, 2, 3].total_count(), Some(3));
+}
+
+#[tokio::test]
+async fn test_list_items() {
+    use futures::TryStreamExt;
+    let client = crate::Client::new("token");
+    let page: crate::types::PaginatedNotesList = serde_json::from_value(serde_json::json!({
+        "count": 2,
+        "next": null,
+        "results": [
+            {"id": 1, "user": {"id": 3, "username": "jo"}},
+            {"id": 2, "user": {"id": 3, "username": "jo"}},
+        ],
+    }))
+    .unwrap();
+    let notes: Vec<crate::types::Notes> = crate::paginator::list_items(&client, async { Ok(page) })
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(notes.iter().map(|note| note.id).collect::<Vec<_>>(), [1, 2]);
+
+    let failed =
+        crate::paginator::list_items::<crate::types::PaginatedNotesList, _>(&client, async {
+            Err(crate::types::error::Error::InvalidRequest("no".to_string()))
+        })
+        .try_collect::<Vec<_>>()
+        .await;
+    assert!(matches!(
+        failed,
+        Err(crate::types::error::Error::InvalidRequest(_))
+    ));
+}
+
+#[test]
+fn test_id_batches() {
+    let ids: Vec<i64> = (1..=1000).rev().chain([5, 5]).collect();
+    let batches = crate::paginator::id_batches(&ids);
+    assert!(batches.len() > 1);
+    assert_eq!(batches.concat(), (1..=1000).collect::<Vec<i64>>());
+    for batch in &batches {
+        let value = batch
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        assert!(value.len() <= crate::paginator::ID_BATCH_LEN);
+    }
+    assert!(crate::paginator::id_batches(&[]).is_empty());
+
+    let found = crate::paginator::ByIds::new(&[1, 2, 3, 2], [(3, "c"), (1, "a"), (9, "x")]);
+    assert_eq!(found.get(&1), Some(&"a"));
+    assert_eq!(found.len(), 2);
+    assert_eq!(found.missing, vec![2]);
+    assert!(!found.is_complete());
+}
+
+#[test]
+fn test_numbered_pages() {
+    let next = crate::paginator::PageCursor {
+        url: "https://paperless.example.com/api/documents/?page=2&page_size=3&tags__id__all=4"
+            .to_string(),
+        query: vec![],
+    };
+    let urls: Vec<String> = crate::paginator::numbered_pages(&next, 3, Some(10))
+        .into_iter()
+        .map(|cursor| cursor.url)
+        .collect();
+    assert_eq!(
+        urls,
+        vec![
+            "https://paperless.example.com/api/documents/?page=2&page_size=3&tags__id__all=4",
+            "https://paperless.example.com/api/documents/?page=3&page_size=3&tags__id__all=4",
+            "https://paperless.example.com/api/documents/?page=4&page_size=3&tags__id__all=4",
+        ]
+    );
+    assert!(crate::paginator::numbered_pages(&next, 3, None).is_empty());
+    assert!(crate::paginator::numbered_pages(&next, 0, Some(10)).is_empty());
+    let unnumbered = crate::paginator::PageCursor {
+        url: "https://paperless.example.com/api/logs/?cursor=abc".to_string(),
+        query: vec![],
+    };
+    assert!(crate::paginator::numbered_pages(&unnumbered, 3, Some(10)).is_empty());
+}
+
+#[tokio::test]
+async fn test_get_or_create_race() {
+    // What the server answers for a duplicate tag, correspondent, document type or
+    // storage path.
+    let conflict = || crate::types::error::Error::Server {
+        body: r#"{"error":"Object violates owner / name unique constraint"}"#.to_string(),
+        status: reqwest::StatusCode::BAD_REQUEST,
+    };
+    // Another client creates the object between the lookup and the create.
+    let lookups = std::sync::atomic::AtomicUsize::new(0);
+    let find = || async {
+        match lookups.fetch_add(1, std::sync::atomic::Ordering::SeqCst) {
+            0 => Ok(None),
+            _ => Ok(Some("theirs")),
+        }
+    };
+    let result = crate::taxonomy::get_or_create(find, || async { Err(conflict()) }).await;
+    assert_eq!(result.unwrap(), ("theirs", false));
+
+    lookups.store(0, std::sync::atomic::Ordering::SeqCst);
+    let result = crate::taxonomy::upsert(
+        find,
+        || async { Err(conflict()) },
+        |existing| async move {
+            assert_eq!(existing, "theirs");
+            Ok("updated")
+        },
+    )
+    .await;
+    assert_eq!(result.unwrap(), "updated");
+
+    let result = crate::taxonomy::get_or_create(
+        || async { Ok(None) },
+        || async { Err::<&str, _>(conflict()) },
+    )
+    .await;
+    let err = result.unwrap_err();
+    assert!(err.is_unique_violation("name"));
+    assert!(!err.is_unique_violation("path"));
+
+    // Custom fields report the duplicate name on the field itself.
+    let err = crate::types::error::Error::Server {
+        body: r#"{"name":["custom field with this name already exists."]}"#.to_string(),
+        status: reqwest::StatusCode::BAD_REQUEST,
+    };
+    assert!(err.is_unique_violation("name"));
+}
+
+#[test]
+fn test_select_by_name() {
+    let tag = |id: i64, name: &str, owner: i64| {
+        serde_json::json!({
+            "id": id,
+            "slug": name.to_lowercase(),
+            "name": name,
+            "owner": owner,
+            "text_color": "#000000",
+            "document_count": 0,
+            "permissions": {},
+            "user_can_change": true
+        })
+    };
+    let tags: Vec<crate::types::Tag> = serde_json::from_value(serde_json::json!([
+        tag(1, "Inbox", 3),
+        tag(2, "INBOX", 4),
+        tag(3, "Inboxes", 3),
+    ]))
+    .unwrap();
+    let select = |name: &str, owner: Option<i64>| {
+        crate::taxonomy::select_by_name(tags.clone(), name, owner, |tag| &tag.name)
+            .map(|tag| tag.map(|tag| tag.id))
+    };
+    assert_eq!(select("inbox", Some(4)).unwrap(), Some(2));
+    assert_eq!(select("inbox", Some(5)).unwrap(), None);
+    assert_eq!(select("inboxes", None).unwrap(), Some(3));
+    // Names are only unique per owner, another user's tag is never picked by chance.
+    assert!(matches!(
+        select("inbox", None),
+        Err(crate::types::error::Error::InvalidRequest(_))
+    ));
+}
+
+#[test]
+fn test_integer_enums() {
+    use crate::types::{MatchingAlgorithm, WorkflowTriggerType};
+    use std::str::FromStr;
+    let tag: crate::types::TagRequest = serde_json::from_value(serde_json::json!({
+        "name": "Invoices",
+        "matching_algorithm": 4
+    }))
+    .unwrap();
+    assert_eq!(tag.matching_algorithm, Some(MatchingAlgorithm::Regex));
+    assert_eq!(
+        serde_json::to_value(MatchingAlgorithm::Unknown(42)).unwrap(),
+        serde_json::json!(42)
+    );
+    assert_eq!(
+        serde_json::from_value::<WorkflowTriggerType>(serde_json::json!(99)).unwrap(),
+        WorkflowTriggerType::Unknown(99)
+    );
+    assert_eq!(MatchingAlgorithm::Fuzzy.to_string(), "fuzzy");
+    assert_eq!(MatchingAlgorithm::Unknown(42).to_string(), "42");
+    assert_eq!(
+        WorkflowTriggerType::from_str("document_added").unwrap(),
+        WorkflowTriggerType::DocumentAdded
+    );
+    assert_eq!(
+        MatchingAlgorithm::from_str("3").unwrap(),
+        MatchingAlgorithm::Literal
+    );
+    assert!(MatchingAlgorithm::from_str("Regex").is_err());
+    assert_eq!(i64::from(MatchingAlgorithm::Auto), 6);
+}
+
+#[test]
+fn test_match_rules() {
+    use crate::matching::{MatchDetail, MatchRule};
+    use crate::types::MatchingAlgorithm;
+    let content = "Invoice 2024-17\nACME  Corporation\nTotal due: 123.45 EUR";
+
+    let any = MatchRule::new(r#"receipt "acme corporation""#, MatchingAlgorithm::Any);
+    assert_eq!(
+        any.explain(content).detail,
+        MatchDetail::Words {
+            found: vec!["acme corporation".to_string()],
+            missing: vec!["receipt".to_string()],
+        }
+    );
+    assert!(any.matches(content));
+    assert!(!MatchRule::new("invoice receipt", MatchingAlgorithm::All).matches(content));
+    assert!(MatchRule::new("invoice total", MatchingAlgorithm::All).matches(content));
+    assert!(!MatchRule::new("invoice", MatchingAlgorithm::Any)
+        .case_sensitive()
+        .matches(content));
+    // Words only match whole words.
+    assert!(!MatchRule::new("voice", MatchingAlgorithm::Any).matches(content));
+
+    assert!(MatchRule::new("due: 123.45", MatchingAlgorithm::Literal).matches(content));
+    assert!(!MatchRule::new("due 123.45", MatchingAlgorithm::Literal).matches(content));
+
+    let regex = MatchRule::new(r"invoice \d{4}-\d+", MatchingAlgorithm::Regex);
+    assert_eq!(
+        regex.explain(content).detail,
+        MatchDetail::Text(Some("Invoice 2024-17".to_string()))
+    );
+    let invalid = MatchRule::new(r"(?<=total)", MatchingAlgorithm::Regex).explain(content);
+    assert!(!invalid.matched);
+    assert!(matches!(invalid.detail, MatchDetail::InvalidPattern(_)));
+
+    let explanation = MatchRule::new("ACME Corporaton", MatchingAlgorithm::Fuzzy)
+        .explain("Sent by ACME Corporation.");
+    assert!(explanation.matched, "{explanation}");
+    assert!(!MatchRule::new("Globex Corporation", MatchingAlgorithm::Fuzzy).matches(content));
+
+    let auto = MatchRule::new("anything", MatchingAlgorithm::Auto).explain(content);
+    assert_eq!(
+        auto.detail,
+        MatchDetail::Unsupported(MatchingAlgorithm::Auto)
+    );
+    assert_eq!(
+        MatchRule::new("", MatchingAlgorithm::Any)
+            .explain(content)
+            .detail,
+        MatchDetail::Disabled
+    );
+    assert!(!MatchRule::new(" \t", MatchingAlgorithm::Regex).matches(content));
+}
+
+#[test]
+fn test_taxonomy() {
+    let mut taxonomy = crate::taxonomy::Taxonomy::empty(&crate::Client::new("token"));
+    let object = |id: i64, name: &str| {
+        serde_json::json!({
+            "id": id,
+            "slug": name.to_lowercase(),
+            "name": name,
+            "text_color": "#000000",
+            "document_count": 0,
+            "permissions": {},
+            "user_can_change": true
+        })
+    };
+    let mut paid = object(2, "Paid");
+    paid["match"] = "paid settled".into();
+    paid["matching_algorithm"] = 1.into();
+    taxonomy.index(
+        serde_json::from_value(serde_json::json!([
+            object(5, "INBOX"),
+            object(1, "Inbox"),
+            paid
+        ]))
+        .unwrap(),
+        serde_json::from_value(serde_json::json!([object(3, "ACME Inc.")])).unwrap(),
+        serde_json::from_value(serde_json::json!([object(4, "Invoice")])).unwrap(),
+        vec![],
+    );
+    assert_eq!(
+        taxonomy.id_of(crate::taxonomy::TaxonomyKind::Correspondent, "acme inc."),
+        crate::taxonomy::NameMatch::One(3)
+    );
+    assert_eq!(
+        taxonomy.id_of(crate::taxonomy::TaxonomyKind::Tag, "ACME Inc."),
+        crate::taxonomy::NameMatch::None
+    );
+    // Names are only unique per owner.
+    let inbox = taxonomy.id_of(crate::taxonomy::TaxonomyKind::Tag, "inbox");
+    assert_eq!(inbox, crate::taxonomy::NameMatch::Ambiguous(vec![1, 5]));
+    assert_eq!(inbox.id(), None);
+    assert_eq!(
+        taxonomy.name_of(crate::taxonomy::TaxonomyKind::Tag, 2),
+        Some("Paid")
+    );
+    assert!(!taxonomy.is_stale());
+
+    let prediction = taxonomy.predict("Invoice 42, paid on 2024-05-02");
+    assert_eq!(prediction.tags, vec![2]);
+    assert_eq!(prediction.correspondent(), None);
+    let explanation = taxonomy
+        .explain(crate::taxonomy::TaxonomyKind::Tag, 2, "Invoice 42, due")
+        .unwrap();
+    assert!(!explanation.matched);
+    assert_eq!(
+        explanation.to_string(),
+        r#"no match: found [], missing ["paid", "settled"]"#
+    );
+
+    let document: crate::types::Document = serde_json::from_value(serde_json::json!({
+        "id": 7,
+        "correspondent": 3,
+        "document_type": 4,
+        "storage_path": null,
+        "title": "Invoice 42",
+        "tags": [1, 2, 9],
+        "modified": "2024-05-01T12:00:00Z",
+        "added": "2024-05-01T12:00:00Z",
+        "notes": [],
+        "mime_type": "application/pdf",
+        "user_can_change": true,
+        "is_shared_by_requester": false,
+        "custom_fields": []
+    }))
+    .unwrap();
+    let expanded = serde_json::to_value(taxonomy.expand(&document)).unwrap();
+    assert_eq!(expanded["correspondent"], "ACME Inc.");
+    assert_eq!(expanded["document_type"], "Invoice");
+    assert_eq!(expanded["storage_path"], serde_json::Value::Null);
+    assert_eq!(expanded["tags"], serde_json::json!(["Inbox", "Paid"]));
+    assert_eq!(expanded["title"], "Invoice 42");
+}
+
+#[test]
+fn test_suggestion_policy() {
+    use crate::types::{FieldChange, SuggestedTags, SuggestionPolicy};
+    let document: crate::types::Document = serde_json::from_value(serde_json::json!({
+        "id": 7,
+        "correspondent": 3,
+        "document_type": null,
+        "storage_path": null,
+        "title": "Invoice 42",
+        "tags": [1, 2],
+        "created": "2024-05-01",
+        "modified": "2024-05-01T12:00:00Z",
+        "added": "2024-05-01T12:00:00Z",
+        "notes": [],
+        "mime_type": "application/pdf",
+    }))
+    .unwrap();
+    let suggestions = crate::types::Suggestions {
+        correspondents: vec![5, 6],
+        tags: vec![2, 8],
+        document_types: vec![4],
+        storage_paths: vec![],
+        dates: vec!["not a date".to_string(), "2024-04-12".to_string()],
+    };
+
+    let changes = SuggestionPolicy::default().changes(&document, &suggestions);
+    assert_eq!(changes.correspondent, None);
+    assert_eq!(
+        changes.document_type,
+        Some(FieldChange {
+            old: None,
+            new: Some(4)
+        })
+    );
+    assert_eq!(changes.storage_path, None);
+    assert_eq!(
+        changes.tags,
+        Some(FieldChange {
+            old: vec![1, 2],
+            new: vec![1, 2, 8]
+        })
+    );
+    assert_eq!(changes.created, None);
+    let patch = changes.to_patch();
+    assert_eq!(
+        serde_json::to_value(&patch).unwrap(),
+        serde_json::json!({"document_type": 4, "tags": [1, 2, 8]})
+    );
+
+    let policy = SuggestionPolicy {
+        tags: SuggestedTags::Replace,
+        ..Default::default()
+    }
+    .overwrite();
+    let changes = policy.changes(&document, &suggestions);
+    assert_eq!(changes.correspondent.unwrap().new, Some(5));
+    assert_eq!(changes.tags.unwrap().new, vec![2, 8]);
+    assert_eq!(changes.created, None);
+    let untagged = crate::types::Suggestions {
+        tags: vec![],
+        ..suggestions.clone()
+    };
+    assert_eq!(policy.changes(&document, &untagged).tags, None);
+
+    let changes = SuggestionPolicy::default()
+        .created()
+        .changes(&document, &suggestions);
+    assert_eq!(changes.correspondent, None);
+    assert_eq!(
+        changes.created,
+        Some(FieldChange {
+            old: chrono::NaiveDate::from_ymd_opt(2024, 5, 1),
+            new: chrono::NaiveDate::from_ymd_opt(2024, 4, 12)
+        })
+    );
+
+    let policy = SuggestionPolicy {
+        correspondent: false,
+        document_type: false,
+        tags: SuggestedTags::Ignore,
+        ..Default::default()
+    };
+    assert!(policy.changes(&document, &suggestions).is_empty());
+}
+
+#[test]
+fn test_document_diff() {
+    let document: crate::types::Document = serde_json::from_value(serde_json::json!({
+        "id": 7,
+        "correspondent": 3,
+        "document_type": 4,
+        "storage_path": null,
+        "title": "Invoice 42",
+        "content": "Invoice 42",
+        "tags": [1, 2],
+        "created": "2024-05-01",
+        "modified": "2024-05-01T12:00:00Z",
+        "added": "2024-05-01T12:00:00Z",
+        "notes": [],
+        "custom_fields": [{"field": 1, "value": "a"}, {"field": 2, "value": 3}],
+        "mime_type": "application/pdf",
+    }))
+    .unwrap();
+    let mut edited = document.clone();
+    edited.tags = vec![2, 1];
+    edited.notes = vec![];
+    edited.custom_fields.as_mut().unwrap().reverse();
+    assert_eq!(
+        serde_json::to_value(document.diff(&edited)).unwrap(),
+        serde_json::json!({})
+    );
+
+    edited.title = Some("Invoice 43".to_string());
+    edited.tags.push(5);
+    edited.correspondent = None;
+    edited.storage_path = Some(2);
+    edited.custom_fields = Some(vec![crate::types::CustomFieldInstance {
+        value: Some(serde_json::json!("b")),
+        field: 1,
+    }]);
+    assert_eq!(
+        serde_json::to_value(document.diff(&edited)).unwrap(),
+        serde_json::json!({
+            "storage_path": 2,
+            "title": "Invoice 43",
+            "tags": [2, 1, 5],
+            "custom_fields": [{"field": 1, "value": "b"}],
+        })
+    );
+    assert_eq!(document.cleared_fields(&edited), vec!["correspondent"]);
+}
+
+#[test]
+fn test_history_entry() {
+    use crate::types::{HistoryAction, HistoryChange, HistoryEntry};
+    let entry: crate::types::LogEntry = serde_json::from_value(serde_json::json!({
+        "id": 12,
+        "timestamp": "2024-05-02T08:30:00Z",
+        "action": "update",
+        "changes": {
+            "title": ["Scan 1", "Invoice 42"],
+            "correspondent": ["None", "3"],
+            "archive_serial_number": ["17", "None"],
+            "tags": {"type": "m2m", "operation": "add", "objects": ["Paid", "Inbox"]},
+        },
+        "actor": {"id": 1, "username": "admin"},
+    }))
+    .unwrap();
+    let entry = HistoryEntry::from(entry);
+    assert_eq!(entry.action, HistoryAction::Update);
+    assert_eq!(
+        entry.change("title"),
+        Some(&HistoryChange::Value {
+            old: Some("Scan 1".to_string()),
+            new: Some("Invoice 42".to_string()),
+        })
+    );
+    assert_eq!(
+        entry.change("correspondent"),
+        Some(&HistoryChange::Reference {
+            old: None,
+            new: Some(3)
+        })
+    );
+    assert_eq!(
+        entry.change("archive_serial_number"),
+        Some(&HistoryChange::Value {
+            old: Some("17".to_string()),
+            new: None,
+        })
+    );
+    assert_eq!(
+        entry.change("tags"),
+        Some(&HistoryChange::Tags {
+            added: vec!["Paid".to_string(), "Inbox".to_string()],
+            removed: vec![],
+        })
+    );
+    let edit = entry.field_edit("correspondent").unwrap();
+    assert_eq!(edit.actor.unwrap().username, "admin");
+    assert_eq!(edit.timestamp, entry.timestamp);
+    assert!(entry.field_edit("content").is_none());
+
+    let entry: crate::types::LogEntry = serde_json::from_value(serde_json::json!({
+        "id": 13,
+        "timestamp": "2024-05-03T08:30:00Z",
+        "action": "create",
+        "changes": {
+            "custom_fields": {"type": "custom_field", "field": "Amount", "value": "EUR12.50"},
+        },
+        "actor": null,
+    }))
+    .unwrap();
+    let entry = HistoryEntry::from(entry);
+    assert_eq!(entry.action, HistoryAction::Create);
+    assert_eq!(entry.actor, None);
+    assert_eq!(
+        entry.change("Amount"),
+        Some(&HistoryChange::CustomField {
+            field: "Amount".to_string(),
+            value: "EUR12.50".to_string(),
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(HistoryAction::Unknown("restore".to_string())).unwrap(),
+        serde_json::json!("restore")
+    );
+}
+
+#[test]
+fn test_page_edit_spawned_tasks() {
+    let task = |task_id: &str, task_name: &str, file_name: &str, created: &str| {
+        serde_json::from_value::<crate::types::TasksView>(serde_json::json!({
+            "id": 1,
+            "task_id": task_id,
+            "task_name": task_name,
+            "task_file_name": file_name,
+            "date_created": created,
+        }))
+        .unwrap()
+    };
+    let tasks = vec![
+        task(
+            "split-1",
+            "consume_file",
+            "12_1.pdf",
+            "2024-05-01T12:00:05Z",
+        ),
+        task(
+            "split-2",
+            "consume_file",
+            "12_2.pdf",
+            "2024-05-01T12:00:06Z",
+        ),
+        task(
+            "earlier",
+            "consume_file",
+            "12_1.pdf",
+            "2024-05-01T11:00:00Z",
+        ),
+        task("other", "consume_file", "7_1.pdf", "2024-05-01T12:00:05Z"),
+        task("upload", "consume_file", "12.pdf", "2024-05-01T12:00:05Z"),
+        task(
+            "merge",
+            "consume_file",
+            "13_12_merged.pdf",
+            "2024-05-01T12:00:05Z",
+        ),
+        task(
+            "scan",
+            "consume_file",
+            "scan_12.pdf",
+            "2024-05-01T12:00:05Z",
+        ),
+        task("sanity", "check_sanity", "12_1.pdf", "2024-05-01T12:00:05Z"),
+        task(
+            "edit",
+            "consume_file",
+            "12_edit_1.pdf",
+            "2024-05-01T12:00:07Z",
+        ),
+    ];
+    let since = Some("2024-05-01T12:00:00Z".parse().unwrap());
+    assert_eq!(
+        crate::pages::spawned_tasks(&tasks, since, &[12], false),
+        vec!["split-1", "split-2", "edit"]
+    );
+    // Only the first part names the source of a split, `12_1.pdf` isn't one of document 1.
+    assert!(crate::pages::spawned_tasks(&tasks, since, &[1], false).is_empty());
+    assert_eq!(
+        crate::pages::spawned_tasks(&tasks, since, &[12], true),
+        vec!["merge"]
+    );
+    // A merge is named after its documents, which need not start with the first one.
+    assert_eq!(
+        crate::pages::spawned_tasks(&tasks, since, &[13, 14], true),
+        vec!["merge"]
+    );
+    assert_eq!(
+        crate::pages::spawned_tasks(&tasks, None, &[12], false),
+        vec!["split-1", "split-2", "earlier", "edit"]
+    );
+}
@@ -22,7 +22,7 @@ impl Token {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/token/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.form(body);
         let resp = req.send().await?;
         let status = resp.status();
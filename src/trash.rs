@@ -23,7 +23,7 @@ impl Trash {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/trash/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = page {
             query_params.push(("page", format!("{p}")));
@@ -58,7 +58,7 @@ impl Trash {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/trash/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -72,4 +72,21 @@ impl Trash {
             })
         }
     }
+
+    #[doc = "Page through the documents in the trash, `/api/trash/`, with a [`Paginator`](crate::paginator::Paginator).\n\n**Parameters:**\n\n- `page_size: Option<i64>`: Number of results to return per page.\n\n```rust,no_run\nasync fn example_trash_paginator() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let documents: Vec<paperless_api_client::types::Document> =\n        client.trash().paginator(Some(100 as i64)).collect_all().await?;\n    println!(\"{:?}\", documents);\n    Ok(())\n}\n```"]
+    pub fn paginator(
+        &self,
+        page_size: Option<i64>,
+    ) -> crate::paginator::Paginator<crate::types::PaginatedDocumentList> {
+        crate::paginator::Paginator::resume(
+            self.client.clone(),
+            crate::paginator::PageCursor::first(
+                &self.client,
+                "api/trash/",
+                page_size
+                    .map(|p| vec![("page_size".to_string(), format!("{p}"))])
+                    .unwrap_or_default(),
+            ),
+        )
+    }
 }
@@ -175,6 +175,337 @@ pub mod multipart {
             })
         }
     }
+
+    #[doc = " A document file to upload. The file is streamed from its source while the"]
+    #[doc = " request is sent instead of being read into memory up front."]
+    #[derive(Debug)]
+    pub struct DocumentUpload {
+        #[doc = " The file name sent to the server."]
+        pub file_name: String,
+        #[doc = " The content type of the file."]
+        pub content_type: Option<String>,
+        #[doc = " The size of the file in bytes. Without it the upload is sent chunked,"]
+        #[doc = " which not every server setup accepts."]
+        pub content_length: Option<u64>,
+        body: reqwest::Body,
+    }
+
+    impl DocumentUpload {
+        #[doc = " Stream the file at `path`. Name, size and content type are taken from the file."]
+        #[cfg(not(target_arch = "wasm32"))]
+        pub async fn from_path<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+            let path = path.as_ref();
+            let file = tokio::fs::File::open(path).await?;
+            let content_length = file.metadata().await?.len();
+            Ok(DocumentUpload {
+                file_name: path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "document".to_string()),
+                content_type: mime_guess::from_path(path)
+                    .first_raw()
+                    .map(|s| s.to_string()),
+                content_length: Some(content_length),
+                body: file.into(),
+            })
+        }
+
+        #[doc = " Stream the contents of `reader`, sending them under `file_name`."]
+        #[cfg(not(target_arch = "wasm32"))]
+        pub fn from_reader<R, S>(reader: R, file_name: S) -> Self
+        where
+            R: futures::io::AsyncRead + Send + 'static,
+            S: Into<String>,
+        {
+            let file_name = file_name.into();
+            let chunks = futures::stream::try_unfold(Box::pin(reader), |mut reader| async move {
+                let mut buf = vec![0; 64 * 1024];
+                let n = futures::AsyncReadExt::read(&mut reader, &mut buf).await?;
+                if n == 0 {
+                    return Ok::<_, std::io::Error>(None);
+                }
+                buf.truncate(n);
+                Ok(Some((bytes::Bytes::from(buf), reader)))
+            });
+            DocumentUpload {
+                content_type: mime_guess::from_path(&file_name)
+                    .first_raw()
+                    .map(|s| s.to_string()),
+                file_name,
+                content_length: None,
+                body: reqwest::Body::wrap_stream(chunks),
+            }
+        }
+
+        #[doc = " Upload data that is already in memory, sending it under `file_name`."]
+        pub fn from_bytes<B, S>(data: B, file_name: S) -> Self
+        where
+            B: Into<bytes::Bytes>,
+            S: Into<String>,
+        {
+            let data = data.into();
+            let file_name = file_name.into();
+            DocumentUpload {
+                content_type: mime_guess::from_path(&file_name)
+                    .first_raw()
+                    .map(|s| s.to_string()),
+                file_name,
+                content_length: Some(data.len() as u64),
+                body: data.into(),
+            }
+        }
+
+        #[doc = " Override the content type guessed from the file name."]
+        pub fn with_content_type<S: Into<String>>(mut self, content_type: S) -> Self {
+            self.content_type = Some(content_type.into());
+            self
+        }
+
+        #[doc = " Announce the size of a reader upload, so it is not sent chunked."]
+        pub fn with_content_length(mut self, content_length: u64) -> Self {
+            self.content_length = Some(content_length);
+            self
+        }
+    }
+
+    impl std::convert::TryFrom<DocumentUpload> for reqwest::multipart::Part {
+        type Error = reqwest::Error;
+        fn try_from(upload: DocumentUpload) -> Result<Self, Self::Error> {
+            let mut part = match upload.content_length {
+                Some(length) => reqwest::multipart::Part::stream_with_length(upload.body, length),
+                None => reqwest::multipart::Part::stream(upload.body),
+            };
+            part = part.file_name(upload.file_name);
+            if let Some(content_type) = upload.content_type {
+                part = part.mime_str(&content_type)?;
+            }
+            Ok(part)
+        }
+    }
+}
+
+#[cfg(feature = "requests")]
+pub mod download {
+    #![doc = " Binary response bodies, such as document files, previews and thumbnails."]
+    #[doc = " A file downloaded from the server, fully buffered in memory."]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct DownloadedFile {
+        #[doc = " The content type sent by the server, e.g. `application/pdf`."]
+        pub content_type: Option<String>,
+        #[doc = " The file name from the `Content-Disposition` header."]
+        pub filename: Option<String>,
+        #[doc = " The raw body."]
+        pub data: bytes::Bytes,
+    }
+
+    impl DownloadedFile {
+        #[doc = " Read the full body of a successful response."]
+        pub async fn from_response(
+            resp: reqwest::Response,
+        ) -> Result<Self, crate::types::error::Error> {
+            let content_type = content_type(resp.headers());
+            let filename = filename(resp.headers());
+            let data = resp.bytes().await?;
+            Ok(DownloadedFile {
+                content_type,
+                filename,
+                data,
+            })
+        }
+    }
+
+    #[doc = " A file downloaded from the server as a stream of chunks, so large files"]
+    #[doc = " never have to be held in memory as a whole."]
+    #[cfg(not(feature = "js"))]
+    pub struct DownloadStream {
+        #[doc = " The content type sent by the server, e.g. `application/pdf`."]
+        pub content_type: Option<String>,
+        #[doc = " The file name from the `Content-Disposition` header."]
+        pub filename: Option<String>,
+        #[doc = " The size of the body in bytes, if the server announced it."]
+        pub content_length: Option<u64>,
+        body: std::pin::Pin<
+            Box<
+                dyn futures::Stream<Item = Result<bytes::Bytes, crate::types::error::Error>> + Send,
+            >,
+        >,
+    }
+
+    #[cfg(not(feature = "js"))]
+    impl DownloadStream {
+        #[doc = " Stream the body of a successful response."]
+        pub fn from_response(resp: reqwest::Response) -> Self {
+            let content_type = content_type(resp.headers());
+            let filename = filename(resp.headers());
+            let content_length = resp.content_length();
+            let body = futures::stream::try_unfold(resp, |mut resp| async move {
+                Ok(resp.chunk().await?.map(|chunk| (chunk, resp)))
+            });
+            DownloadStream {
+                content_type,
+                filename,
+                content_length,
+                body: Box::pin(body),
+            }
+        }
+
+        #[doc = " Copy the whole body into `writer`, returning the number of bytes written."]
+        pub async fn write_to<W>(
+            mut self,
+            writer: &mut W,
+        ) -> Result<u64, crate::types::error::Error>
+        where
+            W: futures::io::AsyncWrite + Unpin,
+        {
+            use futures::{AsyncWriteExt, TryStreamExt};
+            let mut written = 0;
+            while let Some(chunk) = self.try_next().await? {
+                writer.write_all(&chunk).await.map_err(|e| {
+                    crate::types::error::Error::InvalidRequest(format!(
+                        "failed to write download: {e}"
+                    ))
+                })?;
+                written += chunk.len() as u64;
+            }
+            writer.flush().await.map_err(|e| {
+                crate::types::error::Error::InvalidRequest(format!("failed to write download: {e}"))
+            })?;
+            Ok(written)
+        }
+    }
+
+    #[cfg(not(feature = "js"))]
+    impl futures::Stream for DownloadStream {
+        type Item = Result<bytes::Bytes, crate::types::error::Error>;
+        fn poll_next(
+            mut self: std::pin::Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Option<Self::Item>> {
+            self.body.as_mut().poll_next(cx)
+        }
+    }
+
+    #[cfg(not(feature = "js"))]
+    impl std::fmt::Debug for DownloadStream {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("DownloadStream")
+                .field("content_type", &self.content_type)
+                .field("filename", &self.filename)
+                .field("content_length", &self.content_length)
+                .finish_non_exhaustive()
+        }
+    }
+
+    fn content_type(headers: &reqwest::header::HeaderMap) -> Option<String> {
+        headers
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string())
+    }
+
+    fn filename(headers: &reqwest::header::HeaderMap) -> Option<String> {
+        headers
+            .get(reqwest::header::CONTENT_DISPOSITION)
+            .and_then(|v| parse_content_disposition(v.as_bytes()))
+    }
+
+    #[doc = " Extract the file name from a `Content-Disposition` header value, preferring"]
+    #[doc = " the RFC 5987 `filename*` parameter over the plain `filename` one. A"]
+    #[doc = " `filename*` that can't be decoded falls back to `filename`."]
+    pub fn parse_content_disposition(value: &[u8]) -> Option<String> {
+        let value = String::from_utf8_lossy(value);
+        let mut extended = None;
+        let mut plain = None;
+        for (key, val) in params(&value) {
+            match key.to_ascii_lowercase().as_str() {
+                "filename*" => extended = extended.or_else(|| decode_ext_value(&val)),
+                "filename" => plain = Some(val),
+                _ => {}
+            }
+        }
+        extended.or(plain)
+    }
+
+    #[doc = " The parameters following the type of a header value, unquoting quoted"]
+    #[doc = " strings, which may contain `;`."]
+    fn params(value: &str) -> Vec<(String, String)> {
+        let mut params = vec![];
+        let mut chars = value.chars().peekable();
+        for c in chars.by_ref() {
+            if c == ';' {
+                break;
+            }
+        }
+        while chars.peek().is_some() {
+            let mut key = String::new();
+            while let Some(c) = chars.next_if(|c| *c != '=' && *c != ';') {
+                key.push(c);
+            }
+            if chars.next() != Some('=') {
+                continue;
+            }
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            let mut val = String::new();
+            if chars.next_if_eq(&'"').is_some() {
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => val.extend(chars.next()),
+                        '"' => break,
+                        c => val.push(c),
+                    }
+                }
+                for c in chars.by_ref() {
+                    if c == ';' {
+                        break;
+                    }
+                }
+            } else {
+                for c in chars.by_ref() {
+                    if c == ';' {
+                        break;
+                    }
+                    val.push(c);
+                }
+                val.truncate(val.trim_end().len());
+            }
+            params.push((key.trim().to_string(), val));
+        }
+        params
+    }
+
+    #[doc = " Decode an RFC 5987 value like `utf-8'en'a%20b.pdf`."]
+    fn decode_ext_value(value: &str) -> Option<String> {
+        let (charset, rest) = value.split_once('\'')?;
+        let (_language, encoded) = rest.split_once('\'')?;
+        let decoded = percent_decode(encoded);
+        if charset.eq_ignore_ascii_case("utf-8") {
+            return String::from_utf8(decoded).ok();
+        }
+        Some(decoded.into_iter().map(char::from).collect())
+    }
+
+    fn percent_decode(s: &str) -> Vec<u8> {
+        let bytes = s.as_bytes();
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .and_then(|h| std::str::from_utf8(h).ok())
+                .and_then(|h| u8::from_str_radix(h, 16).ok());
+            match (bytes[i], hex) {
+                (b'%', Some(b)) => {
+                    out.push(b);
+                    i += 3;
+                }
+                (b, _) => {
+                    out.push(b);
+                    i += 1;
+                }
+            }
+        }
+        out
+    }
 }
 
 #[cfg(feature = "requests")]
@@ -196,6 +527,39 @@ pub mod paginate {
         ) -> Result<reqwest::Request, crate::types::error::Error>;
         #[doc = " Get the items from a page."]
         fn items(&self) -> Vec<Self::Item>;
+        #[doc = " The number of items on all pages, if the response tells."]
+        fn total_count(&self) -> Option<i64> {
+            None
+        }
+    }
+
+    #[doc = " A plain list is a single page holding every item."]
+    impl<T: serde::de::DeserializeOwned + Clone> Pagination for Vec<T> {
+        type Item = T;
+        fn has_more_pages(&self) -> bool {
+            false
+        }
+
+        fn next_page_token(&self) -> Option<String> {
+            None
+        }
+
+        fn next_page(
+            &self,
+            req: reqwest::Request,
+        ) -> Result<reqwest::Request, crate::types::error::Error> {
+            Err(crate::types::error::Error::InvalidRequest(format!(
+                "no next page for request: {req:?}"
+            )))
+        }
+
+        fn items(&self) -> Vec<Self::Item> {
+            self.clone()
+        }
+
+        fn total_count(&self) -> Option<i64> {
+            i64::try_from(self.len()).ok()
+        }
     }
 }
 
@@ -349,7 +713,6 @@ pub mod error {
     pub enum Error {
         #[doc = " The request did not conform to API requirements."]
         InvalidRequest(String),
-        #[cfg(feature = "retry")]
         #[doc = " A server error either due to the data, or with the connection."]
         CommunicationError(reqwest_middleware::Error),
         #[doc = " A request error, caused when building the request."]
@@ -363,12 +726,8 @@ pub mod error {
         },
         #[doc = " An expected error response."]
         InvalidResponsePayload {
-            #[cfg(feature = "retry")]
             #[doc = " The error."]
             error: reqwest_middleware::Error,
-            #[cfg(not(feature = "retry"))]
-            #[doc = " The error."]
-            error: reqwest::Error,
             #[doc = " The full response."]
             response: reqwest::Response,
         },
@@ -382,6 +741,43 @@ pub mod error {
         #[doc = " A response not listed in the API description. This may represent a"]
         #[doc = " success or failure response; check `status().is_success()`."]
         UnexpectedResponse(reqwest::Response),
+        #[doc = " A background task on the server finished without success."]
+        TaskFailed {
+            #[doc = " The Celery id of the task."]
+            task_id: String,
+            #[doc = " The result message of the task, e.g. the reason a consumption failed."]
+            result: Option<String>,
+        },
+        #[doc = " A background task on the server succeeded without creating a document,"]
+        #[doc = " e.g. because the file was split into several documents."]
+        TaskWithoutDocument {
+            #[doc = " The Celery id of the task."]
+            task_id: String,
+            #[doc = " The result message of the task."]
+            result: Option<String>,
+        },
+        #[doc = " A background task on the server did not finish in time."]
+        TaskTimeout {
+            #[doc = " The Celery id of the task."]
+            task_id: String,
+        },
+        #[doc = " The server is too old for a feature of the request."]
+        Unsupported {
+            #[doc = " The missing feature."]
+            capability: super::Capability,
+            #[doc = " The version of the server, if known."]
+            server_version: Option<String>,
+        },
+        #[doc = " The object was modified by someone else while it was being edited."]
+        Conflict {
+            #[doc = " The id of the object."]
+            id: i64,
+            #[doc = " The last modification time seen on the server."]
+            modified: chrono::DateTime<chrono::Utc>,
+        },
+        #[cfg(feature = "websocket")]
+        #[doc = " An error of the status websocket connection."]
+        WebSocket(tokio_tungstenite::tungstenite::Error),
     }
 
     impl Error {
@@ -390,17 +786,102 @@ pub mod error {
             match self {
                 Error::InvalidRequest(_) => None,
                 Error::RequestError(e) => e.status(),
-                #[cfg(feature = "retry")]
                 Error::CommunicationError(reqwest_middleware::Error::Reqwest(e)) => e.status(),
-                #[cfg(feature = "retry")]
                 Error::CommunicationError(reqwest_middleware::Error::Middleware(_)) => None,
                 Error::SerdeError { error: _, status } => Some(*status),
                 Error::InvalidResponsePayload { error: _, response } => Some(response.status()),
                 Error::Server { body: _, status } => Some(*status),
                 Error::UnexpectedResponse(r) => Some(r.status()),
+                Error::TaskFailed { .. } => None,
+                Error::TaskWithoutDocument { .. } => None,
+                Error::TaskTimeout { .. } => None,
+                Error::Unsupported { .. } => None,
+                Error::Conflict { .. } => None,
+                #[cfg(feature = "websocket")]
+                Error::WebSocket(tokio_tungstenite::tungstenite::Error::Http(r)) => {
+                    Some(r.status())
+                }
+                #[cfg(feature = "websocket")]
+                Error::WebSocket(_) => None,
             }
         }
 
+        #[doc = " Returns the parsed error body, if the error is a server response with a JSON body."]
+        pub fn api_error(&self) -> Option<ApiError> {
+            match self {
+                Error::Server { body, status: _ } => ApiError::parse(body),
+                _ => None,
+            }
+        }
+
+        #[doc = " Returns the field errors of a rejected request, empty if there are none."]
+        pub fn validation_errors(&self) -> ValidationErrors {
+            self.api_error().map(|e| e.fields).unwrap_or_default()
+        }
+
+        #[doc = " Returns true if the requested object does not exist."]
+        pub fn is_not_found(&self) -> bool {
+            self.status() == Some(reqwest::StatusCode::NOT_FOUND)
+        }
+
+        #[doc = " Returns true if the credentials are missing, invalid or lack the needed permissions."]
+        pub fn is_permission_denied(&self) -> bool {
+            matches!(
+                self.status(),
+                Some(reqwest::StatusCode::UNAUTHORIZED) | Some(reqwest::StatusCode::FORBIDDEN)
+            )
+        }
+
+        #[doc = " Returns true if the server rejected the request data."]
+        pub fn is_validation_error(&self) -> bool {
+            self.status() == Some(reqwest::StatusCode::BAD_REQUEST)
+        }
+
+        #[doc = " Returns true if the request was rejected because `field` has to be unique,"]
+        #[doc = " e.g. when creating a tag with a name that is already taken."]
+        #[doc = ""]
+        #[doc = " Besides errors of the field itself this recognizes messages naming the field,"]
+        #[doc = " like the `{\"error\": \"Object violates owner / name unique constraint\"}` the"]
+        #[doc = " server sends for duplicate tags, correspondents, document types and storage paths."]
+        pub fn is_unique_violation(&self, field: &str) -> bool {
+            let Some(error) = self.api_error() else {
+                return false;
+            };
+            let is_unique = |message: &String| {
+                let message = message.to_lowercase();
+                message.contains("unique") || message.contains("already exists")
+            };
+            error.fields.get(field).iter().any(is_unique)
+                || error
+                    .non_field_errors
+                    .iter()
+                    .chain(error.fields.get("error"))
+                    .any(|m| is_unique(m) && m.to_lowercase().contains(&field.to_lowercase()))
+        }
+
+        #[doc = " Returns true if the request may succeed when sent again, e.g. after a timeout,"]
+        #[doc = " a connection failure, rate limiting or an unavailable server."]
+        pub fn is_retryable(&self) -> bool {
+            let transport = match self {
+                Error::RequestError(e) => e.is_timeout() || e.is_connect(),
+                Error::CommunicationError(reqwest_middleware::Error::Reqwest(e)) => {
+                    e.is_timeout() || e.is_connect()
+                }
+                _ => false,
+            };
+            transport
+                || matches!(
+                    self.status(),
+                    Some(
+                        reqwest::StatusCode::REQUEST_TIMEOUT
+                            | reqwest::StatusCode::TOO_MANY_REQUESTS
+                            | reqwest::StatusCode::BAD_GATEWAY
+                            | reqwest::StatusCode::SERVICE_UNAVAILABLE
+                            | reqwest::StatusCode::GATEWAY_TIMEOUT
+                    )
+                )
+        }
+
         #[doc = " Creates a new error from a response status and a serde error."]
         pub fn from_serde_error(
             e: format_serde_error::SerdeError,
@@ -410,7 +891,99 @@ pub mod error {
         }
     }
 
-    #[cfg(feature = "retry")]
+    #[doc = " Field errors of a rejected request, keyed by field name."]
+    #[doc = ""]
+    #[doc = " Errors of nested objects and lists are keyed by their path, e.g. `custom_fields.0.value`."]
+    #[derive(PartialEq, Eq, Debug, Clone, Default)]
+    pub struct ValidationErrors(pub std::collections::BTreeMap<String, Vec<String>>);
+
+    impl ValidationErrors {
+        #[doc = " Returns the messages for a field."]
+        pub fn get(&self, field: &str) -> &[String] {
+            self.0.get(field).map(Vec::as_slice).unwrap_or_default()
+        }
+
+        #[doc = " Returns true if there are no field errors."]
+        pub fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        #[doc = " Iterates over the fields and their messages."]
+        pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+            self.0.iter().map(|(k, v)| (k.as_str(), v.as_slice()))
+        }
+
+        fn collect(&mut self, path: String, value: &serde_json::Value) {
+            match value {
+                serde_json::Value::Null => {}
+                serde_json::Value::String(s) => self.0.entry(path).or_default().push(s.clone()),
+                serde_json::Value::Array(items) => {
+                    for (i, item) in items.iter().enumerate() {
+                        match item {
+                            serde_json::Value::String(_) => self.collect(path.clone(), item),
+                            _ => self.collect(format!("{path}.{i}"), item),
+                        }
+                    }
+                }
+                serde_json::Value::Object(map) => {
+                    for (k, v) in map {
+                        self.collect(format!("{path}.{k}"), v);
+                    }
+                }
+                other => self.0.entry(path).or_default().push(other.to_string()),
+            }
+        }
+    }
+
+    #[doc = " The parsed body of an error response."]
+    #[derive(PartialEq, Eq, Debug, Clone, Default)]
+    pub struct ApiError {
+        #[doc = " The `detail` message, e.g. for authentication or permission problems."]
+        pub detail: Option<String>,
+        #[doc = " Errors not tied to a single field."]
+        pub non_field_errors: Vec<String>,
+        #[doc = " Errors of single fields."]
+        pub fields: ValidationErrors,
+    }
+
+    impl ApiError {
+        #[doc = " Parses an error body, returns `None` if it is not a JSON error."]
+        pub fn parse(body: &str) -> Option<Self> {
+            let value: serde_json::Value = serde_json::from_str(body).ok()?;
+            let mut error = Self::default();
+            match value {
+                serde_json::Value::Object(map) => {
+                    for (k, v) in map {
+                        match (k.as_str(), v) {
+                            ("detail", serde_json::Value::String(s)) => error.detail = Some(s),
+                            ("non_field_errors", v) => {
+                                let mut errors = ValidationErrors::default();
+                                errors.collect(k, &v);
+                                error.non_field_errors = errors.0.into_values().flatten().collect();
+                            }
+                            (_, v) => error.fields.collect(k, &v),
+                        }
+                    }
+                }
+                serde_json::Value::Array(items) => {
+                    error.non_field_errors = items
+                        .into_iter()
+                        .filter_map(|v| v.as_str().map(str::to_string))
+                        .collect();
+                }
+                serde_json::Value::String(s) => error.detail = Some(s),
+                _ => return None,
+            }
+            if error.detail.is_none()
+                && error.non_field_errors.is_empty()
+                && error.fields.is_empty()
+            {
+                return None;
+            }
+            Some(error)
+        }
+    }
+
     impl From<reqwest_middleware::Error> for Error {
         fn from(e: reqwest_middleware::Error) -> Self {
             Self::CommunicationError(e)
@@ -423,6 +996,13 @@ pub mod error {
         }
     }
 
+    #[cfg(feature = "websocket")]
+    impl From<tokio_tungstenite::tungstenite::Error> for Error {
+        fn from(e: tokio_tungstenite::tungstenite::Error) -> Self {
+            Self::WebSocket(e)
+        }
+    }
+
     impl From<serde_json::Error> for Error {
         fn from(e: serde_json::Error) -> Self {
             Self::SerdeError {
@@ -438,7 +1018,6 @@ pub mod error {
                 Error::InvalidRequest(s) => {
                     write!(f, "Invalid Request: {s}")
                 }
-                #[cfg(feature = "retry")]
                 Error::CommunicationError(e) => {
                     write!(f, "Communication Error: {e}")
                 }
@@ -457,6 +1036,40 @@ pub mod error {
                 Error::UnexpectedResponse(r) => {
                     write!(f, "Unexpected Response: {r:?}")
                 }
+                Error::TaskFailed { task_id, result } => {
+                    write!(
+                        f,
+                        "Task Failed: {task_id} {}",
+                        result.as_deref().unwrap_or("no result")
+                    )
+                }
+                Error::TaskWithoutDocument { task_id, result } => {
+                    write!(
+                        f,
+                        "Task Without Document: {task_id} {}",
+                        result.as_deref().unwrap_or("no result")
+                    )
+                }
+                Error::TaskTimeout { task_id } => {
+                    write!(f, "Task Timeout: {task_id}")
+                }
+                Error::Unsupported {
+                    capability,
+                    server_version,
+                } => {
+                    write!(
+                        f,
+                        "Unsupported: {capability} is not available on server version {}",
+                        server_version.as_deref().unwrap_or("unknown")
+                    )
+                }
+                Error::Conflict { id, modified } => {
+                    write!(f, "Conflict: {id} was modified concurrently at {modified}")
+                }
+                #[cfg(feature = "websocket")]
+                Error::WebSocket(e) => {
+                    write!(f, "WebSocket Error: {e}")
+                }
             }
         }
     }
@@ -470,10 +1083,11 @@ pub mod error {
     impl std::error::Error for Error {
         fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
             match self {
-                #[cfg(feature = "retry")]
                 Error::CommunicationError(e) => Some(e),
                 Error::SerdeError { error, status: _ } => Some(error),
                 Error::InvalidResponsePayload { error, response: _ } => Some(error),
+                #[cfg(feature = "websocket")]
+                Error::WebSocket(e) => Some(e),
                 _ => None,
             }
         }
@@ -1788,7 +2402,7 @@ pub struct Correspondent {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     pub document_count: i64,
@@ -1958,7 +2572,7 @@ pub struct CorrespondentRequest {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2765,7 +3379,7 @@ pub struct DocumentType {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     pub document_count: i64,
@@ -2878,7 +3492,7 @@ pub struct DocumentTypeRequest {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3155,7 +3769,8 @@ pub struct LogEntry {
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub action: String,
     pub changes: std::collections::HashMap<String, serde_json::Value>,
-    pub actor: Actor,
+    #[serde(default)]
+    pub actor: Option<Actor>,
 }
 
 impl std::fmt::Display for LogEntry {
@@ -3204,7 +3819,7 @@ pub struct MailAccount {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub imap_port: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub imap_security: Option<i64>,
+    pub imap_security: Option<ImapSecurity>,
     pub username: String,
     pub password: String,
     #[doc = "The character set to use when communicating with the mail server, such as 'UTF-8' or 'US-ASCII'."]
@@ -3216,7 +3831,7 @@ pub struct MailAccount {
     pub owner: Option<i64>,
     pub user_can_change: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub account_type: Option<i64>,
+    pub account_type: Option<MailAccountType>,
     #[doc = "The expiration date of the refresh token. "]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub expiration: Option<chrono::DateTime<chrono::Utc>>,
@@ -3346,7 +3961,7 @@ pub struct MailAccountRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub imap_port: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub imap_security: Option<i64>,
+    pub imap_security: Option<ImapSecurity>,
     pub username: String,
     pub password: String,
     #[doc = "The character set to use when communicating with the mail server, such as 'UTF-8' or 'US-ASCII'."]
@@ -3359,7 +3974,7 @@ pub struct MailAccountRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub set_permissions: Option<SetPermissions>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub account_type: Option<i64>,
+    pub account_type: Option<MailAccountType>,
     #[doc = "The expiration date of the refresh token. "]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub expiration: Option<chrono::DateTime<chrono::Utc>>,
@@ -3506,15 +4121,15 @@ pub struct MailRule {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub maximum_age: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<i64>,
+    pub action: Option<MailRuleAction>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action_parameter: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub assign_title_from: Option<i64>,
+    pub assign_title_from: Option<MailTitleSource>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assign_tags: Option<Vec<Option<i64>>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub assign_correspondent_from: Option<i64>,
+    pub assign_correspondent_from: Option<MailCorrespondentSource>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assign_correspondent: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3525,11 +4140,11 @@ pub struct MailRule {
     pub order: Option<i64>,
     #[doc = "Inline attachments include embedded images, so it's best to combine this option with a filename filter.\n\n* `1` - Only process attachments.\n* `2` - Process all files, including 'inline' attachments."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub attachment_type: Option<i64>,
+    pub attachment_type: Option<MailAttachmentType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub consumption_scope: Option<i64>,
+    pub consumption_scope: Option<MailConsumptionScope>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub pdf_layout: Option<i64>,
+    pub pdf_layout: Option<MailPdfLayout>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner: Option<i64>,
     pub user_can_change: bool,
@@ -3733,15 +4348,15 @@ pub struct MailRuleRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub maximum_age: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<i64>,
+    pub action: Option<MailRuleAction>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action_parameter: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub assign_title_from: Option<i64>,
+    pub assign_title_from: Option<MailTitleSource>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assign_tags: Option<Vec<Option<i64>>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub assign_correspondent_from: Option<i64>,
+    pub assign_correspondent_from: Option<MailCorrespondentSource>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assign_correspondent: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3752,11 +4367,11 @@ pub struct MailRuleRequest {
     pub order: Option<i64>,
     #[doc = "Inline attachments include embedded images, so it's best to combine this option with a filename filter.\n\n* `1` - Only process attachments.\n* `2` - Process all files, including 'inline' attachments."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub attachment_type: Option<i64>,
+    pub attachment_type: Option<MailAttachmentType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub consumption_scope: Option<i64>,
+    pub consumption_scope: Option<MailConsumptionScope>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub pdf_layout: Option<i64>,
+    pub pdf_layout: Option<MailPdfLayout>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4396,6 +5011,10 @@ impl crate::types::paginate::Pagination for PaginatedCorrespondentList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -4491,6 +5110,10 @@ impl crate::types::paginate::Pagination for PaginatedCustomFieldList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -4586,6 +5209,10 @@ impl crate::types::paginate::Pagination for PaginatedDocumentList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -4681,6 +5308,10 @@ impl crate::types::paginate::Pagination for PaginatedDocumentTypeList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -4776,6 +5407,10 @@ impl crate::types::paginate::Pagination for PaginatedGroupList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -4871,6 +5506,10 @@ impl crate::types::paginate::Pagination for PaginatedLogEntryList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -4966,6 +5605,10 @@ impl crate::types::paginate::Pagination for PaginatedMailAccountList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -5061,6 +5704,10 @@ impl crate::types::paginate::Pagination for PaginatedMailRuleList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -5156,6 +5803,10 @@ impl crate::types::paginate::Pagination for PaginatedNotesList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -5251,6 +5902,10 @@ impl crate::types::paginate::Pagination for PaginatedSavedViewList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -5346,6 +6001,10 @@ impl crate::types::paginate::Pagination for PaginatedShareLinkList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -5441,6 +6100,10 @@ impl crate::types::paginate::Pagination for PaginatedStoragePathList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -5536,6 +6199,10 @@ impl crate::types::paginate::Pagination for PaginatedTagList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -5631,6 +6298,10 @@ impl crate::types::paginate::Pagination for PaginatedUserList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -5726,6 +6397,10 @@ impl crate::types::paginate::Pagination for PaginatedWorkflowActionList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -5821,6 +6496,10 @@ impl crate::types::paginate::Pagination for PaginatedWorkflowList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -5916,6 +6595,10 @@ impl crate::types::paginate::Pagination for PaginatedWorkflowTriggerList {
     fn items(&self) -> Vec<Self::Item> {
         self.results.clone()
     }
+
+    fn total_count(&self) -> Option<i64> {
+        Some(self.count)
+    }
 }
 
 #[cfg(feature = "tabled")]
@@ -6274,7 +6957,7 @@ pub struct PatchedCorrespondentRequest {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -6553,7 +7236,7 @@ pub struct PatchedDocumentTypeRequest {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -6679,7 +7362,7 @@ pub struct PatchedMailAccountRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub imap_port: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub imap_security: Option<i64>,
+    pub imap_security: Option<ImapSecurity>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -6694,7 +7377,7 @@ pub struct PatchedMailAccountRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub set_permissions: Option<SetPermissions>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub account_type: Option<i64>,
+    pub account_type: Option<MailAccountType>,
     #[doc = "The expiration date of the refresh token. "]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub expiration: Option<chrono::DateTime<chrono::Utc>>,
@@ -6828,15 +7511,15 @@ pub struct PatchedMailRuleRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub maximum_age: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<i64>,
+    pub action: Option<MailRuleAction>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action_parameter: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub assign_title_from: Option<i64>,
+    pub assign_title_from: Option<MailTitleSource>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assign_tags: Option<Vec<Option<i64>>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub assign_correspondent_from: Option<i64>,
+    pub assign_correspondent_from: Option<MailCorrespondentSource>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assign_correspondent: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -6847,11 +7530,11 @@ pub struct PatchedMailRuleRequest {
     pub order: Option<i64>,
     #[doc = "Inline attachments include embedded images, so it's best to combine this option with a filename filter.\n\n* `1` - Only process attachments.\n* `2` - Process all files, including 'inline' attachments."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub attachment_type: Option<i64>,
+    pub attachment_type: Option<MailAttachmentType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub consumption_scope: Option<i64>,
+    pub consumption_scope: Option<MailConsumptionScope>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub pdf_layout: Option<i64>,
+    pub pdf_layout: Option<MailPdfLayout>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -7306,7 +7989,7 @@ pub struct PatchedStoragePathRequest {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -7393,7 +8076,7 @@ pub struct PatchedTagRequest {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     #[doc = "Marks this tag as an inbox tag: All newly consumed documents will be tagged with inbox tags."]
@@ -7609,7 +8292,7 @@ pub struct PatchedWorkflowActionRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
-    pub type_: Option<i64>,
+    pub type_: Option<WorkflowActionType>,
     #[doc = "Assign a document title, can include some placeholders, see documentation."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assign_title: Option<String>,
@@ -7977,9 +8660,9 @@ pub struct PatchedWorkflowTriggerRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
     #[serde(default)]
-    pub sources: Vec<i64>,
+    pub sources: Vec<DocumentSource>,
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
-    pub type_: Option<i64>,
+    pub type_: Option<WorkflowTriggerType>,
     #[doc = "Only consume documents with a path that matches this if specified. Wildcards specified as * are allowed. Case insensitive."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_path: Option<String>,
@@ -7989,7 +8672,7 @@ pub struct PatchedWorkflowTriggerRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_mailrule: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -8457,7 +9140,7 @@ impl tabled::Tabled for SavedView {
 )]
 #[allow(non_snake_case)]
 pub struct SavedViewFilterRule {
-    pub rule_type: i64,
+    pub rule_type: FilterRuleType,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
@@ -8496,7 +9179,7 @@ impl tabled::Tabled for SavedViewFilterRule {
 )]
 #[allow(non_snake_case)]
 pub struct SavedViewFilterRuleRequest {
-    pub rule_type: i64,
+    pub rule_type: FilterRuleType,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
@@ -9020,6 +9703,16 @@ pub enum StatusEnum {
     Success,
 }
 
+impl StatusEnum {
+    #[doc = " Returns true for the states a task does not leave anymore."]
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            StatusEnum::Success | StatusEnum::Failure | StatusEnum::Revoked
+        )
+    }
+}
+
 #[derive(
     serde :: Serialize, serde :: Deserialize, PartialEq, Debug, Clone, schemars :: JsonSchema,
 )]
@@ -9066,7 +9759,7 @@ pub struct StoragePath {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     pub document_count: i64,
@@ -9179,7 +9872,7 @@ pub struct StoragePathRequest {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -9364,7 +10057,7 @@ pub struct Tag {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     #[doc = "Marks this tag as an inbox tag: All newly consumed documents will be tagged with inbox tags."]
@@ -9494,7 +10187,7 @@ pub struct TagRequest {
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_insensitive: Option<bool>,
     #[doc = "Marks this tag as an inbox tag: All newly consumed documents will be tagged with inbox tags."]
@@ -9713,6 +10406,18 @@ pub struct TasksView {
     pub owner: Option<i64>,
 }
 
+impl TasksView {
+    #[doc = " Returns true once the task will not change its status anymore."]
+    pub fn is_finished(&self) -> bool {
+        self.status.as_ref().is_some_and(StatusEnum::is_finished)
+    }
+
+    #[doc = " The id of the document the task created or worked on, if any."]
+    pub fn related_document_id(&self) -> Option<i64> {
+        self.related_document.as_deref()?.trim().parse().ok()
+    }
+}
+
 impl std::fmt::Display for TasksView {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         write!(
@@ -10417,7 +11122,7 @@ pub struct WorkflowAction {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
-    pub type_: Option<i64>,
+    pub type_: Option<WorkflowActionType>,
     #[doc = "Assign a document title, can include some placeholders, see documentation."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assign_title: Option<String>,
@@ -10832,7 +11537,7 @@ pub struct WorkflowActionRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
-    pub type_: Option<i64>,
+    pub type_: Option<WorkflowActionType>,
     #[doc = "Assign a document title, can include some placeholders, see documentation."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assign_title: Option<String>,
@@ -11373,9 +12078,9 @@ pub struct WorkflowTrigger {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
     #[serde(default)]
-    pub sources: Vec<i64>,
+    pub sources: Vec<DocumentSource>,
     #[serde(rename = "type")]
-    pub type_: i64,
+    pub type_: WorkflowTriggerType,
     #[doc = "Only consume documents with a path that matches this if specified. Wildcards specified as * are allowed. Case insensitive."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_path: Option<String>,
@@ -11385,7 +12090,7 @@ pub struct WorkflowTrigger {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_mailrule: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -11538,9 +12243,9 @@ pub struct WorkflowTriggerRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
     #[serde(default)]
-    pub sources: Vec<i64>,
+    pub sources: Vec<DocumentSource>,
     #[serde(rename = "type")]
-    pub type_: i64,
+    pub type_: WorkflowTriggerType,
     #[doc = "Only consume documents with a path that matches this if specified. Wildcards specified as * are allowed. Case insensitive."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_path: Option<String>,
@@ -11550,7 +12255,7 @@ pub struct WorkflowTriggerRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_mailrule: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub matching_algorithm: Option<i64>,
+    pub matching_algorithm: Option<MatchingAlgorithm>,
     #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -11938,3 +12643,3264 @@ impl tabled::Tabled for AcknowledgeTasksRequestBody {
         vec!["tasks".into()]
     }
 }
+
+#[doc = " Filters, ordering and paging for `/api/documents/`."]
+#[doc = ""]
+#[doc = " Every field maps onto one query parameter of the endpoint. Fields can be set"]
+#[doc = " directly or through the chainable helpers, which group related parameters"]
+#[doc = " (date ranges, tag sets, object references) so a call site names what it filters on."]
+#[derive(
+    serde :: Serialize,
+    serde :: Deserialize,
+    PartialEq,
+    Debug,
+    Clone,
+    Default,
+    schemars :: JsonSchema,
+)]
+#[allow(non_snake_case)]
+pub struct DocumentQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_date_gt: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_date_gte: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_date_lt: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_date_lte: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_day: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_gt: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_gte: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_lt: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_lte: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_month: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_year: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_serial_number: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_serial_number_gt: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_serial_number_gte: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_serial_number_isnull: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_serial_number_lt: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_serial_number_lte: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum_icontains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum_iendswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum_iexact: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum_istartswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_icontains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_iendswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_iexact: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_istartswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correspondent_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correspondent_id_in: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correspondent_id_none: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correspondent_isnull: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correspondent_name_icontains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correspondent_name_iendswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correspondent_name_iexact: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correspondent_name_istartswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_date_gt: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_date_gte: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_date_lt: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_date_lte: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_day: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_gt: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_gte: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_lt: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_lte: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_month: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_year: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_field_query: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields_icontains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields_id_all: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields_id_in: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields_id_none: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type_id_in: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type_id_none: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type_isnull: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type_name_icontains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type_name_iendswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type_name_iexact: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type_name_istartswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_perms: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_custom_fields: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_in: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_in_inbox: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_tagged: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_date_gt: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_date_gte: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_date_lt: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_date_lte: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_day: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_gt: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_gte: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_lt: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_lte: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_month: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_year: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ordering: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_filename_icontains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_filename_iendswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_filename_iexact: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_filename_istartswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id_in: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id_none: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_isnull: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_by_id: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path_id_in: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path_id_none: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path_isnull: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path_name_icontains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path_name_iendswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path_name_iexact: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path_name_istartswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags_id_all: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags_id_in: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags_id_none: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags_name_icontains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags_name_iendswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags_name_iexact: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags_name_istartswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_icontains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_iendswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_iexact: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_istartswith: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_content: Option<String>,
+}
+
+impl std::fmt::Display for DocumentQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+impl DocumentQuery {
+    #[doc = " Create an empty query, matching every document visible to the user."]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[doc = " Full text search using the server's search index."]
+    pub fn full_text<S: Into<String>>(mut self, query: S) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    #[doc = " Case-insensitive substring match on title or content."]
+    pub fn title_content<S: Into<String>>(mut self, text: S) -> Self {
+        self.title_content = Some(text.into());
+        self
+    }
+
+    #[doc = " Case-insensitive substring match on the title."]
+    pub fn title_contains<S: Into<String>>(mut self, text: S) -> Self {
+        self.title_icontains = Some(text.into());
+        self
+    }
+
+    #[doc = " Case-insensitive substring match on the content."]
+    pub fn content_contains<S: Into<String>>(mut self, text: S) -> Self {
+        self.content_icontains = Some(text.into());
+        self
+    }
+
+    #[doc = " Only documents with one of the given ids."]
+    pub fn ids<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.id_in = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents with the given mime type, e.g. `application/pdf`."]
+    pub fn mime_type<S: Into<String>>(mut self, mime_type: S) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    #[doc = " Only documents created within `range`, e.g. `from..to` or `from..`."]
+    pub fn created_in<R: std::ops::RangeBounds<chrono::NaiveDate>>(mut self, range: R) -> Self {
+        match range.start_bound() {
+            std::ops::Bound::Included(d) => self.created_gte = Some(*d),
+            std::ops::Bound::Excluded(d) => self.created_gt = Some(*d),
+            std::ops::Bound::Unbounded => {}
+        }
+        match range.end_bound() {
+            std::ops::Bound::Included(d) => self.created_lte = Some(*d),
+            std::ops::Bound::Excluded(d) => self.created_lt = Some(*d),
+            std::ops::Bound::Unbounded => {}
+        }
+        self
+    }
+
+    #[doc = " Only documents added on a date within `range`."]
+    pub fn added_in<R: std::ops::RangeBounds<chrono::NaiveDate>>(mut self, range: R) -> Self {
+        match range.start_bound() {
+            std::ops::Bound::Included(d) => self.added_date_gte = Some(*d),
+            std::ops::Bound::Excluded(d) => self.added_date_gt = Some(*d),
+            std::ops::Bound::Unbounded => {}
+        }
+        match range.end_bound() {
+            std::ops::Bound::Included(d) => self.added_date_lte = Some(*d),
+            std::ops::Bound::Excluded(d) => self.added_date_lt = Some(*d),
+            std::ops::Bound::Unbounded => {}
+        }
+        self
+    }
+
+    #[doc = " Only documents last modified on a date within `range`."]
+    pub fn modified_in<R: std::ops::RangeBounds<chrono::NaiveDate>>(mut self, range: R) -> Self {
+        match range.start_bound() {
+            std::ops::Bound::Included(d) => self.modified_date_gte = Some(*d),
+            std::ops::Bound::Excluded(d) => self.modified_date_gt = Some(*d),
+            std::ops::Bound::Unbounded => {}
+        }
+        match range.end_bound() {
+            std::ops::Bound::Included(d) => self.modified_date_lte = Some(*d),
+            std::ops::Bound::Excluded(d) => self.modified_date_lt = Some(*d),
+            std::ops::Bound::Unbounded => {}
+        }
+        self
+    }
+
+    #[doc = " Only the document with the given archive serial number."]
+    pub fn asn(mut self, asn: i64) -> Self {
+        self.archive_serial_number = Some(asn);
+        self
+    }
+
+    #[doc = " Only documents whose archive serial number lies within `range`."]
+    pub fn asn_in<R: std::ops::RangeBounds<i64>>(mut self, range: R) -> Self {
+        match range.start_bound() {
+            std::ops::Bound::Included(n) => self.archive_serial_number_gte = Some(*n),
+            std::ops::Bound::Excluded(n) => self.archive_serial_number_gt = Some(*n),
+            std::ops::Bound::Unbounded => {}
+        }
+        match range.end_bound() {
+            std::ops::Bound::Included(n) => self.archive_serial_number_lte = Some(*n),
+            std::ops::Bound::Excluded(n) => self.archive_serial_number_lt = Some(*n),
+            std::ops::Bound::Unbounded => {}
+        }
+        self
+    }
+
+    #[doc = " Only documents that do (`true`) or do not (`false`) have an archive serial number."]
+    pub fn has_asn(mut self, has_asn: bool) -> Self {
+        self.archive_serial_number_isnull = Some(!has_asn);
+        self
+    }
+
+    #[doc = " Only documents carrying every one of the given tags."]
+    pub fn tags_all<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.tags_id_all = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents carrying at least one of the given tags."]
+    pub fn tags_any<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.tags_id_in = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents carrying none of the given tags."]
+    pub fn tags_none<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.tags_id_none = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents carrying a tag with the given name (case-insensitive)."]
+    pub fn tag_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.tags_name_iexact = Some(name.into());
+        self
+    }
+
+    #[doc = " Only documents that do (`true`) or do not (`false`) carry any tag."]
+    pub fn tagged(mut self, tagged: bool) -> Self {
+        self.is_tagged = Some(tagged);
+        self
+    }
+
+    #[doc = " Only documents that do (`true`) or do not (`false`) carry an inbox tag."]
+    pub fn in_inbox(mut self, in_inbox: bool) -> Self {
+        self.is_in_inbox = Some(in_inbox);
+        self
+    }
+
+    #[doc = " Only documents from the given correspondent."]
+    pub fn correspondent(mut self, id: i64) -> Self {
+        self.correspondent_id = Some(id);
+        self
+    }
+
+    #[doc = " Only documents from the correspondent with the given name (case-insensitive)."]
+    pub fn correspondent_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.correspondent_name_iexact = Some(name.into());
+        self
+    }
+
+    #[doc = " Only documents from one of the given correspondents."]
+    pub fn correspondent_any<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.correspondent_id_in = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents from none of the given correspondents."]
+    pub fn correspondent_none<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.correspondent_id_none = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents without a correspondent."]
+    pub fn without_correspondent(mut self) -> Self {
+        self.correspondent_isnull = Some(true);
+        self
+    }
+
+    #[doc = " Only documents of the given document type."]
+    pub fn document_type(mut self, id: i64) -> Self {
+        self.document_type_id = Some(id);
+        self
+    }
+
+    #[doc = " Only documents of the document type with the given name (case-insensitive)."]
+    pub fn document_type_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.document_type_name_iexact = Some(name.into());
+        self
+    }
+
+    #[doc = " Only documents of one of the given document types."]
+    pub fn document_type_any<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.document_type_id_in = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents of none of the given document types."]
+    pub fn document_type_none<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.document_type_id_none = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents without a document type."]
+    pub fn without_document_type(mut self) -> Self {
+        self.document_type_isnull = Some(true);
+        self
+    }
+
+    #[doc = " Only documents in the given storage path."]
+    pub fn storage_path(mut self, id: i64) -> Self {
+        self.storage_path_id = Some(id);
+        self
+    }
+
+    #[doc = " Only documents in the storage path with the given name (case-insensitive)."]
+    pub fn storage_path_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.storage_path_name_iexact = Some(name.into());
+        self
+    }
+
+    #[doc = " Only documents in one of the given storage paths."]
+    pub fn storage_path_any<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.storage_path_id_in = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents in none of the given storage paths."]
+    pub fn storage_path_none<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.storage_path_id_none = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents without a storage path."]
+    pub fn without_storage_path(mut self) -> Self {
+        self.storage_path_isnull = Some(true);
+        self
+    }
+
+    #[doc = " Only documents owned by the given user."]
+    pub fn owner(mut self, id: i64) -> Self {
+        self.owner_id = Some(id);
+        self
+    }
+
+    #[doc = " Only documents without an owner."]
+    pub fn without_owner(mut self) -> Self {
+        self.owner_isnull = Some(true);
+        self
+    }
+
+    #[doc = " Only documents that have every one of the given custom fields."]
+    pub fn custom_fields_all<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.custom_fields_id_all = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents that have at least one of the given custom fields."]
+    pub fn custom_fields_any<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.custom_fields_id_in = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents that have none of the given custom fields."]
+    pub fn custom_fields_none<I: IntoIterator<Item = i64>>(mut self, ids: I) -> Self {
+        self.custom_fields_id_none = Some(ids.into_iter().collect());
+        self
+    }
+
+    #[doc = " Only documents with a custom field value containing `text` (case-insensitive)."]
+    pub fn custom_fields_contains<S: Into<String>>(mut self, text: S) -> Self {
+        self.custom_fields_icontains = Some(text.into());
+        self
+    }
+
+    #[doc = " Filter on custom field values with a [`CfQuery`] expression."]
+    pub fn custom_fields_match(mut self, query: &CfQuery) -> Self {
+        self.custom_field_query = Some(query.to_string());
+        self
+    }
+
+    #[doc = " Filter on custom field values with a raw custom field query expression."]
+    pub fn custom_field_query<S: Into<String>>(mut self, query: S) -> Self {
+        self.custom_field_query = Some(query.into());
+        self
+    }
+
+    #[doc = " Sort ascending by `field`, e.g. `created` or `title`."]
+    pub fn order_by<S: std::fmt::Display>(mut self, field: S) -> Self {
+        self.ordering = Some(field.to_string());
+        self
+    }
+
+    #[doc = " Sort descending by `field`."]
+    pub fn order_by_desc<S: std::fmt::Display>(mut self, field: S) -> Self {
+        self.ordering = Some(format!("-{field}"));
+        self
+    }
+
+    #[doc = " Only return the given fields of each document."]
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    #[doc = " Include the full permission sets of each document."]
+    pub fn full_perms(mut self, full_perms: bool) -> Self {
+        self.full_perms = Some(full_perms);
+        self
+    }
+
+    #[doc = " Request the given page of the result set."]
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    #[doc = " Number of documents returned per page."]
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    #[doc = " The query parameters this query sends to the server."]
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        let mut query_params = vec![];
+        if let Some(p) = &self.added_date_gt {
+            query_params.push(("added__date__gt", format!("{p}")));
+        }
+
+        if let Some(p) = &self.added_date_gte {
+            query_params.push(("added__date__gte", format!("{p}")));
+        }
+
+        if let Some(p) = &self.added_date_lt {
+            query_params.push(("added__date__lt", format!("{p}")));
+        }
+
+        if let Some(p) = &self.added_date_lte {
+            query_params.push(("added__date__lte", format!("{p}")));
+        }
+
+        if let Some(p) = &self.added_day {
+            query_params.push(("added__day", format!("{p}")));
+        }
+
+        if let Some(p) = &self.added_gt {
+            query_params.push(("added__gt", p.to_rfc3339()));
+        }
+
+        if let Some(p) = &self.added_gte {
+            query_params.push(("added__gte", p.to_rfc3339()));
+        }
+
+        if let Some(p) = &self.added_lt {
+            query_params.push(("added__lt", p.to_rfc3339()));
+        }
+
+        if let Some(p) = &self.added_lte {
+            query_params.push(("added__lte", p.to_rfc3339()));
+        }
+
+        if let Some(p) = &self.added_month {
+            query_params.push(("added__month", format!("{p}")));
+        }
+
+        if let Some(p) = &self.added_year {
+            query_params.push(("added__year", format!("{p}")));
+        }
+
+        if let Some(p) = &self.archive_serial_number {
+            query_params.push(("archive_serial_number", format!("{p}")));
+        }
+
+        if let Some(p) = &self.archive_serial_number_gt {
+            query_params.push(("archive_serial_number__gt", format!("{p}")));
+        }
+
+        if let Some(p) = &self.archive_serial_number_gte {
+            query_params.push(("archive_serial_number__gte", format!("{p}")));
+        }
+
+        if let Some(p) = &self.archive_serial_number_isnull {
+            query_params.push(("archive_serial_number__isnull", format!("{p}")));
+        }
+
+        if let Some(p) = &self.archive_serial_number_lt {
+            query_params.push(("archive_serial_number__lt", format!("{p}")));
+        }
+
+        if let Some(p) = &self.archive_serial_number_lte {
+            query_params.push(("archive_serial_number__lte", format!("{p}")));
+        }
+
+        if let Some(p) = &self.checksum_icontains {
+            query_params.push(("checksum__icontains", p.clone()));
+        }
+
+        if let Some(p) = &self.checksum_iendswith {
+            query_params.push(("checksum__iendswith", p.clone()));
+        }
+
+        if let Some(p) = &self.checksum_iexact {
+            query_params.push(("checksum__iexact", p.clone()));
+        }
+
+        if let Some(p) = &self.checksum_istartswith {
+            query_params.push(("checksum__istartswith", p.clone()));
+        }
+
+        if let Some(p) = &self.content_icontains {
+            query_params.push(("content__icontains", p.clone()));
+        }
+
+        if let Some(p) = &self.content_iendswith {
+            query_params.push(("content__iendswith", p.clone()));
+        }
+
+        if let Some(p) = &self.content_iexact {
+            query_params.push(("content__iexact", p.clone()));
+        }
+
+        if let Some(p) = &self.content_istartswith {
+            query_params.push(("content__istartswith", p.clone()));
+        }
+
+        if let Some(p) = &self.correspondent_id {
+            query_params.push(("correspondent__id", format!("{p}")));
+        }
+
+        if let Some(p) = &self.correspondent_id_in {
+            query_params.push(("correspondent__id__in", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.correspondent_id_none {
+            query_params.push(("correspondent__id__none", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.correspondent_isnull {
+            query_params.push(("correspondent__isnull", format!("{p}")));
+        }
+
+        if let Some(p) = &self.correspondent_name_icontains {
+            query_params.push(("correspondent__name__icontains", p.clone()));
+        }
+
+        if let Some(p) = &self.correspondent_name_iendswith {
+            query_params.push(("correspondent__name__iendswith", p.clone()));
+        }
+
+        if let Some(p) = &self.correspondent_name_iexact {
+            query_params.push(("correspondent__name__iexact", p.clone()));
+        }
+
+        if let Some(p) = &self.correspondent_name_istartswith {
+            query_params.push(("correspondent__name__istartswith", p.clone()));
+        }
+
+        if let Some(p) = &self.created_date_gt {
+            query_params.push(("created__date__gt", format!("{p}")));
+        }
+
+        if let Some(p) = &self.created_date_gte {
+            query_params.push(("created__date__gte", format!("{p}")));
+        }
+
+        if let Some(p) = &self.created_date_lt {
+            query_params.push(("created__date__lt", format!("{p}")));
+        }
+
+        if let Some(p) = &self.created_date_lte {
+            query_params.push(("created__date__lte", format!("{p}")));
+        }
+
+        if let Some(p) = &self.created_day {
+            query_params.push(("created__day", format!("{p}")));
+        }
+
+        if let Some(p) = &self.created_gt {
+            query_params.push(("created__gt", format!("{p}")));
+        }
+
+        if let Some(p) = &self.created_gte {
+            query_params.push(("created__gte", format!("{p}")));
+        }
+
+        if let Some(p) = &self.created_lt {
+            query_params.push(("created__lt", format!("{p}")));
+        }
+
+        if let Some(p) = &self.created_lte {
+            query_params.push(("created__lte", format!("{p}")));
+        }
+
+        if let Some(p) = &self.created_month {
+            query_params.push(("created__month", format!("{p}")));
+        }
+
+        if let Some(p) = &self.created_year {
+            query_params.push(("created__year", format!("{p}")));
+        }
+
+        if let Some(p) = &self.custom_field_query {
+            query_params.push(("custom_field_query", p.clone()));
+        }
+
+        if let Some(p) = &self.custom_fields_icontains {
+            query_params.push(("custom_fields__icontains", p.clone()));
+        }
+
+        if let Some(p) = &self.custom_fields_id_all {
+            query_params.push(("custom_fields__id__all", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.custom_fields_id_in {
+            query_params.push(("custom_fields__id__in", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.custom_fields_id_none {
+            query_params.push(("custom_fields__id__none", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.document_type_id {
+            query_params.push(("document_type__id", format!("{p}")));
+        }
+
+        if let Some(p) = &self.document_type_id_in {
+            query_params.push(("document_type__id__in", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.document_type_id_none {
+            query_params.push(("document_type__id__none", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.document_type_isnull {
+            query_params.push(("document_type__isnull", format!("{p}")));
+        }
+
+        if let Some(p) = &self.document_type_name_icontains {
+            query_params.push(("document_type__name__icontains", p.clone()));
+        }
+
+        if let Some(p) = &self.document_type_name_iendswith {
+            query_params.push(("document_type__name__iendswith", p.clone()));
+        }
+
+        if let Some(p) = &self.document_type_name_iexact {
+            query_params.push(("document_type__name__iexact", p.clone()));
+        }
+
+        if let Some(p) = &self.document_type_name_istartswith {
+            query_params.push(("document_type__name__istartswith", p.clone()));
+        }
+
+        if let Some(p) = &self.fields {
+            query_params.push(("fields", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.full_perms {
+            query_params.push(("full_perms", format!("{p}")));
+        }
+
+        if let Some(p) = &self.has_custom_fields {
+            query_params.push(("has_custom_fields", format!("{p}")));
+        }
+
+        if let Some(p) = &self.id {
+            query_params.push(("id", format!("{p}")));
+        }
+
+        if let Some(p) = &self.id_in {
+            query_params.push(("id__in", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.is_in_inbox {
+            query_params.push(("is_in_inbox", format!("{p}")));
+        }
+
+        if let Some(p) = &self.is_tagged {
+            query_params.push(("is_tagged", format!("{p}")));
+        }
+
+        if let Some(p) = &self.mime_type {
+            query_params.push(("mime_type", p.clone()));
+        }
+
+        if let Some(p) = &self.modified_date_gt {
+            query_params.push(("modified__date__gt", format!("{p}")));
+        }
+
+        if let Some(p) = &self.modified_date_gte {
+            query_params.push(("modified__date__gte", format!("{p}")));
+        }
+
+        if let Some(p) = &self.modified_date_lt {
+            query_params.push(("modified__date__lt", format!("{p}")));
+        }
+
+        if let Some(p) = &self.modified_date_lte {
+            query_params.push(("modified__date__lte", format!("{p}")));
+        }
+
+        if let Some(p) = &self.modified_day {
+            query_params.push(("modified__day", format!("{p}")));
+        }
+
+        if let Some(p) = &self.modified_gt {
+            query_params.push(("modified__gt", p.to_rfc3339()));
+        }
+
+        if let Some(p) = &self.modified_gte {
+            query_params.push(("modified__gte", p.to_rfc3339()));
+        }
+
+        if let Some(p) = &self.modified_lt {
+            query_params.push(("modified__lt", p.to_rfc3339()));
+        }
+
+        if let Some(p) = &self.modified_lte {
+            query_params.push(("modified__lte", p.to_rfc3339()));
+        }
+
+        if let Some(p) = &self.modified_month {
+            query_params.push(("modified__month", format!("{p}")));
+        }
+
+        if let Some(p) = &self.modified_year {
+            query_params.push(("modified__year", format!("{p}")));
+        }
+
+        if let Some(p) = &self.ordering {
+            query_params.push(("ordering", p.clone()));
+        }
+
+        if let Some(p) = &self.original_filename_icontains {
+            query_params.push(("original_filename__icontains", p.clone()));
+        }
+
+        if let Some(p) = &self.original_filename_iendswith {
+            query_params.push(("original_filename__iendswith", p.clone()));
+        }
+
+        if let Some(p) = &self.original_filename_iexact {
+            query_params.push(("original_filename__iexact", p.clone()));
+        }
+
+        if let Some(p) = &self.original_filename_istartswith {
+            query_params.push(("original_filename__istartswith", p.clone()));
+        }
+
+        if let Some(p) = &self.owner_id {
+            query_params.push(("owner__id", format!("{p}")));
+        }
+
+        if let Some(p) = &self.owner_id_in {
+            query_params.push(("owner__id__in", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.owner_id_none {
+            query_params.push(("owner__id__none", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.owner_isnull {
+            query_params.push(("owner__isnull", format!("{p}")));
+        }
+
+        if let Some(p) = &self.page {
+            query_params.push(("page", format!("{p}")));
+        }
+
+        if let Some(p) = &self.page_size {
+            query_params.push(("page_size", format!("{p}")));
+        }
+
+        if let Some(p) = &self.query {
+            query_params.push(("query", p.clone()));
+        }
+
+        if let Some(p) = &self.search {
+            query_params.push(("search", p.clone()));
+        }
+
+        if let Some(p) = &self.shared_by_id {
+            query_params.push(("shared_by__id", format!("{p}")));
+        }
+
+        if let Some(p) = &self.storage_path_id {
+            query_params.push(("storage_path__id", format!("{p}")));
+        }
+
+        if let Some(p) = &self.storage_path_id_in {
+            query_params.push(("storage_path__id__in", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.storage_path_id_none {
+            query_params.push(("storage_path__id__none", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.storage_path_isnull {
+            query_params.push(("storage_path__isnull", format!("{p}")));
+        }
+
+        if let Some(p) = &self.storage_path_name_icontains {
+            query_params.push(("storage_path__name__icontains", p.clone()));
+        }
+
+        if let Some(p) = &self.storage_path_name_iendswith {
+            query_params.push(("storage_path__name__iendswith", p.clone()));
+        }
+
+        if let Some(p) = &self.storage_path_name_iexact {
+            query_params.push(("storage_path__name__iexact", p.clone()));
+        }
+
+        if let Some(p) = &self.storage_path_name_istartswith {
+            query_params.push(("storage_path__name__istartswith", p.clone()));
+        }
+
+        if let Some(p) = &self.tags_id {
+            query_params.push(("tags__id", format!("{p}")));
+        }
+
+        if let Some(p) = &self.tags_id_all {
+            query_params.push(("tags__id__all", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.tags_id_in {
+            query_params.push(("tags__id__in", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.tags_id_none {
+            query_params.push(("tags__id__none", itertools::join(p, ",")));
+        }
+
+        if let Some(p) = &self.tags_name_icontains {
+            query_params.push(("tags__name__icontains", p.clone()));
+        }
+
+        if let Some(p) = &self.tags_name_iendswith {
+            query_params.push(("tags__name__iendswith", p.clone()));
+        }
+
+        if let Some(p) = &self.tags_name_iexact {
+            query_params.push(("tags__name__iexact", p.clone()));
+        }
+
+        if let Some(p) = &self.tags_name_istartswith {
+            query_params.push(("tags__name__istartswith", p.clone()));
+        }
+
+        if let Some(p) = &self.title_icontains {
+            query_params.push(("title__icontains", p.clone()));
+        }
+
+        if let Some(p) = &self.title_iendswith {
+            query_params.push(("title__iendswith", p.clone()));
+        }
+
+        if let Some(p) = &self.title_iexact {
+            query_params.push(("title__iexact", p.clone()));
+        }
+
+        if let Some(p) = &self.title_istartswith {
+            query_params.push(("title__istartswith", p.clone()));
+        }
+
+        if let Some(p) = &self.title_content {
+            query_params.push(("title_content", p.clone()));
+        }
+
+        query_params
+    }
+}
+
+#[doc = " How to poll a background task until it finishes."]
+#[doc = ""]
+#[doc = " The delay between two polls starts at `initial_interval` and is multiplied by"]
+#[doc = " `multiplier` after each poll, up to `max_interval`."]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskWaitOptions {
+    #[doc = " Delay before the second poll."]
+    pub initial_interval: std::time::Duration,
+    #[doc = " Upper bound for the delay between two polls."]
+    pub max_interval: std::time::Duration,
+    #[doc = " Factor the delay grows by after every poll."]
+    pub multiplier: f64,
+    #[doc = " Give up after this long. `None` waits forever."]
+    pub timeout: Option<std::time::Duration>,
+}
+
+impl Default for TaskWaitOptions {
+    fn default() -> Self {
+        TaskWaitOptions {
+            initial_interval: std::time::Duration::from_millis(500),
+            max_interval: std::time::Duration::from_secs(10),
+            multiplier: 1.5,
+            timeout: Some(std::time::Duration::from_secs(600)),
+        }
+    }
+}
+
+impl TaskWaitOptions {
+    #[doc = " The delay following a poll that was preceded by a delay of `interval`."]
+    pub fn next_interval(&self, interval: std::time::Duration) -> std::time::Duration {
+        // A huge or infinite multiplier saturates at `max_interval` instead of panicking.
+        std::time::Duration::try_from_secs_f64(interval.as_secs_f64() * self.multiplier.max(1.0))
+            .map_or(self.max_interval, |next| next.min(self.max_interval))
+    }
+
+    #[doc = " The delay before the next poll of a wait that started at `started`: `interval`,"]
+    #[doc = " shortened so the last poll happens at the timeout. `None` once it passed."]
+    #[cfg(feature = "requests")]
+    pub(crate) fn delay(
+        &self,
+        started: std::time::Instant,
+        interval: std::time::Duration,
+    ) -> Option<std::time::Duration> {
+        match self.timeout {
+            Some(timeout) => timeout
+                .checked_sub(started.elapsed())
+                .filter(|remaining| !remaining.is_zero())
+                .map(|remaining| remaining.min(interval)),
+            None => Some(interval),
+        }
+    }
+}
+
+#[doc = " The metadata sent along with an uploaded document. Every field is optional;"]
+#[doc = " the server fills in what is left out through its matching rules."]
+#[derive(
+    serde :: Serialize,
+    serde :: Deserialize,
+    PartialEq,
+    Debug,
+    Clone,
+    Default,
+    schemars :: JsonSchema,
+)]
+pub struct UploadMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correspondent: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_serial_number: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<i64>>,
+}
+
+impl std::fmt::Display for UploadMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+impl UploadMetadata {
+    #[doc = " The multipart form fields for this metadata. List fields repeat their key"]
+    #[doc = " once per value, the way the upload endpoint expects them."]
+    pub fn form_fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields = vec![];
+        if let Some(p) = &self.title {
+            fields.push(("title", p.clone()));
+        }
+
+        if let Some(p) = &self.created {
+            fields.push(("created", p.to_rfc3339()));
+        }
+
+        if let Some(p) = &self.correspondent {
+            fields.push(("correspondent", format!("{p}")));
+        }
+
+        if let Some(p) = &self.document_type {
+            fields.push(("document_type", format!("{p}")));
+        }
+
+        if let Some(p) = &self.storage_path {
+            fields.push(("storage_path", format!("{p}")));
+        }
+
+        for p in self.tags.iter().flatten() {
+            fields.push(("tags", format!("{p}")));
+        }
+
+        if let Some(p) = &self.archive_serial_number {
+            fields.push(("archive_serial_number", format!("{p}")));
+        }
+
+        for p in self.custom_fields.iter().flatten() {
+            fields.push(("custom_fields", format!("{p}")));
+        }
+
+        fields
+    }
+}
+
+impl From<&PostDocumentRequest> for UploadMetadata {
+    fn from(body: &PostDocumentRequest) -> Self {
+        UploadMetadata {
+            title: body.title.clone(),
+            created: body.created,
+            correspondent: body.correspondent,
+            document_type: body.document_type,
+            storage_path: body.storage_path,
+            tags: body.tags.clone(),
+            archive_serial_number: body.archive_serial_number,
+            custom_fields: body.custom_fields.clone(),
+        }
+    }
+}
+
+#[doc = " A status change of a background task, as reported by a task watcher."]
+#[derive(
+    serde :: Serialize, serde :: Deserialize, PartialEq, Debug, Clone, schemars :: JsonSchema,
+)]
+pub struct TaskEvent {
+    #[doc = " The status the task had when it was last seen, `None` on the first sighting."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<StatusEnum>,
+    #[doc = " The current status of the task."]
+    pub status: StatusEnum,
+    #[doc = " The result message of the task, e.g. the reason a consumption failed."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+    #[doc = " The id of the document the task created or worked on, if any."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_document: Option<i64>,
+    #[doc = " The task as returned by the server."]
+    pub task: TasksView,
+}
+
+impl std::fmt::Display for TaskEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+impl TaskEvent {
+    #[doc = " Build the event for `task`, or `None` if its status did not change."]
+    pub fn from_change(previous: Option<StatusEnum>, task: TasksView) -> Option<Self> {
+        let status = task.status.clone().unwrap_or(StatusEnum::Pending);
+        if previous.as_ref() == Some(&status) {
+            return None;
+        }
+        Some(TaskEvent {
+            previous,
+            status,
+            result: task.result.clone(),
+            related_document: task.related_document_id(),
+            task,
+        })
+    }
+
+    #[doc = " Returns true if the task will not change its status anymore."]
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+#[doc = "Stage of a document consumption reported over the status websocket.\n\n* `STARTED` - STARTED\n* `WORKING` - WORKING\n* `SUCCESS` - SUCCESS\n* `FAILED` - FAILED"]
+#[derive(
+    serde :: Serialize,
+    serde :: Deserialize,
+    PartialEq,
+    Hash,
+    Debug,
+    Clone,
+    schemars :: JsonSchema,
+    parse_display :: FromStr,
+    parse_display :: Display,
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "tabled", derive(tabled::Tabled))]
+pub enum ProgressStatus {
+    #[serde(rename = "STARTED")]
+    #[display("STARTED")]
+    Started,
+    #[serde(rename = "WORKING")]
+    #[display("WORKING")]
+    Working,
+    #[serde(rename = "SUCCESS")]
+    #[display("SUCCESS")]
+    Success,
+    #[serde(rename = "FAILED")]
+    #[display("FAILED")]
+    Failed,
+}
+
+#[doc = " Progress of a document consumption, pushed by the server over `/ws/status/`."]
+#[derive(
+    serde :: Serialize, serde :: Deserialize, PartialEq, Debug, Clone, schemars :: JsonSchema,
+)]
+#[allow(non_snake_case)]
+pub struct StatusUpdate {
+    #[doc = "Name of the file being consumed"]
+    #[serde(default)]
+    pub filename: Option<String>,
+    #[doc = "Celery ID of the consumption task"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    #[serde(default)]
+    pub current_progress: i64,
+    #[serde(default)]
+    pub max_progress: i64,
+    pub status: ProgressStatus,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[doc = "The id of the created document, once consumption succeeded"]
+    #[serde(default)]
+    pub document_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub users_can_view: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups_can_view: Option<Vec<i64>>,
+}
+
+impl std::fmt::Display for StatusUpdate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+#[doc = " A message pushed by the server over `/ws/status/`."]
+#[derive(
+    serde :: Serialize, serde :: Deserialize, PartialEq, Debug, Clone, schemars :: JsonSchema,
+)]
+#[serde(tag = "type", content = "data")]
+pub enum StatusMessage {
+    #[serde(rename = "status_update")]
+    StatusUpdate(StatusUpdate),
+    #[serde(rename = "documents_deleted")]
+    DocumentsDeleted { documents: Vec<i64> },
+    #[doc = " A message type this client does not know about, kept as sent."]
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+#[doc = "A feature of the API that older servers lack.\n\n* `custom_field_query` - custom_field_query\n* `edit_pdf` - edit_pdf\n* `share_links` - share_links\n* `trash` - trash\n* `workflows` - workflows"]
+#[derive(
+    serde :: Serialize,
+    serde :: Deserialize,
+    PartialEq,
+    Hash,
+    Debug,
+    Clone,
+    Copy,
+    schemars :: JsonSchema,
+    parse_display :: FromStr,
+    parse_display :: Display,
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "tabled", derive(tabled::Tabled))]
+pub enum Capability {
+    #[serde(rename = "custom_field_query")]
+    #[display("custom_field_query")]
+    CustomFieldQuery,
+    #[serde(rename = "edit_pdf")]
+    #[display("edit_pdf")]
+    EditPdf,
+    #[serde(rename = "share_links")]
+    #[display("share_links")]
+    ShareLinks,
+    #[serde(rename = "trash")]
+    #[display("trash")]
+    Trash,
+    #[serde(rename = "workflows")]
+    #[display("workflows")]
+    Workflows,
+}
+
+impl Capability {
+    #[doc = " The first server version supporting the capability."]
+    pub fn min_server_version(&self) -> (u64, u64, u64) {
+        match self {
+            Capability::CustomFieldQuery => (2, 13, 0),
+            Capability::EditPdf => (2, 17, 0),
+            Capability::ShareLinks => (1, 17, 0),
+            Capability::Trash => (2, 10, 0),
+            Capability::Workflows => (2, 0, 0),
+        }
+    }
+
+    #[doc = " The endpoint listed in the API root if the server supports the capability."]
+    pub fn endpoint(&self) -> Option<&'static str> {
+        match self {
+            Capability::ShareLinks => Some("share_links"),
+            Capability::Trash => Some("trash"),
+            Capability::Workflows => Some("workflows"),
+            Capability::CustomFieldQuery | Capability::EditPdf => None,
+        }
+    }
+}
+
+#[doc = " What a server supports, as reported by its version headers and API root."]
+#[derive(
+    serde :: Serialize, serde :: Deserialize, PartialEq, Debug, Clone, schemars :: JsonSchema,
+)]
+#[allow(non_snake_case)]
+pub struct ServerCapabilities {
+    #[doc = "The newest API version the server supports, from `X-Api-Version`"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<i64>,
+    #[doc = "The Paperless-ngx version, from `X-Version`"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_version: Option<String>,
+    #[doc = "The endpoints listed in the API root, e.g. `documents` or `trash`"]
+    #[serde(default)]
+    pub endpoints: Vec<String>,
+}
+
+impl std::fmt::Display for ServerCapabilities {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+impl ServerCapabilities {
+    #[doc = " The server version as `(major, minor, patch)`, if it is known."]
+    pub fn version(&self) -> Option<(u64, u64, u64)> {
+        let version = self
+            .server_version
+            .as_deref()?
+            .trim()
+            .trim_start_matches('v');
+        let mut parts = version.split('.').map(|part| {
+            part.chars()
+                .take_while(char::is_ascii_digit)
+                .collect::<String>()
+                .parse::<u64>()
+                .ok()
+        });
+        let major = parts.next().flatten()?;
+        let minor = parts.next().flatten().unwrap_or(0);
+        let patch = parts.next().flatten().unwrap_or(0);
+        Some((major, minor, patch))
+    }
+
+    #[doc = " Returns true if the server lists `endpoint` in its API root."]
+    pub fn has_endpoint(&self, endpoint: &str) -> bool {
+        self.endpoints.iter().any(|e| e == endpoint)
+    }
+
+    #[doc = " Returns true if the server supports the capability. A server that reports"]
+    #[doc = " neither its version nor its endpoints is assumed to support everything."]
+    pub fn supports(&self, capability: Capability) -> bool {
+        if let Some(endpoint) = capability.endpoint() {
+            if !self.endpoints.is_empty() {
+                return self.has_endpoint(endpoint);
+            }
+        }
+        match self.version() {
+            Some(version) => version >= capability.min_server_version(),
+            None => true,
+        }
+    }
+
+    #[doc = " Fails with [`error::Error::Unsupported`] if the server lacks the capability."]
+    #[cfg(feature = "requests")]
+    pub fn require(&self, capability: Capability) -> Result<(), error::Error> {
+        if self.supports(capability) {
+            Ok(())
+        } else {
+            Err(error::Error::Unsupported {
+                capability,
+                server_version: self.server_version.clone(),
+            })
+        }
+    }
+}
+
+#[doc = "Comparison of a custom field query.\n\n* `exact` - exact\n* `in` - in\n* `isnull` - isnull\n* `exists` - exists\n* `icontains` - icontains\n* `istartswith` - istartswith\n* `iendswith` - iendswith\n* `gt` - gt\n* `gte` - gte\n* `lt` - lt\n* `lte` - lte\n* `range` - range\n* `contains` - contains"]
+#[derive(
+    serde :: Serialize,
+    serde :: Deserialize,
+    PartialEq,
+    Hash,
+    Debug,
+    Clone,
+    Copy,
+    schemars :: JsonSchema,
+    parse_display :: FromStr,
+    parse_display :: Display,
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "tabled", derive(tabled::Tabled))]
+pub enum CfOperator {
+    #[serde(rename = "exact")]
+    #[display("exact")]
+    Exact,
+    #[serde(rename = "in")]
+    #[display("in")]
+    In,
+    #[serde(rename = "isnull")]
+    #[display("isnull")]
+    Isnull,
+    #[serde(rename = "exists")]
+    #[display("exists")]
+    Exists,
+    #[serde(rename = "icontains")]
+    #[display("icontains")]
+    Icontains,
+    #[serde(rename = "istartswith")]
+    #[display("istartswith")]
+    Istartswith,
+    #[serde(rename = "iendswith")]
+    #[display("iendswith")]
+    Iendswith,
+    #[serde(rename = "gt")]
+    #[display("gt")]
+    Gt,
+    #[serde(rename = "gte")]
+    #[display("gte")]
+    Gte,
+    #[serde(rename = "lt")]
+    #[display("lt")]
+    Lt,
+    #[serde(rename = "lte")]
+    #[display("lte")]
+    Lte,
+    #[serde(rename = "range")]
+    #[display("range")]
+    Range,
+    #[serde(rename = "contains")]
+    #[display("contains")]
+    Contains,
+}
+
+#[doc = " A custom field addressed by its name or its id."]
+#[derive(serde :: Serialize, PartialEq, Eq, Debug, Clone)]
+#[serde(untagged)]
+pub enum CfField {
+    Name(String),
+    Id(i64),
+}
+
+impl From<&str> for CfField {
+    fn from(name: &str) -> Self {
+        CfField::Name(name.to_string())
+    }
+}
+
+impl From<String> for CfField {
+    fn from(name: String) -> Self {
+        CfField::Name(name)
+    }
+}
+
+impl From<i64> for CfField {
+    fn from(id: i64) -> Self {
+        CfField::Id(id)
+    }
+}
+
+#[doc = " A custom field query, the expression language of the `custom_field_query`"]
+#[doc = " filter of documents."]
+#[doc = ""]
+#[doc = " ```"]
+#[doc = " use paperless_api_client::types::CfQuery;"]
+#[doc = ""]
+#[doc = " let query = CfQuery::field(\"amount\")"]
+#[doc = "     .gt(100)"]
+#[doc = "     .and(CfQuery::field(\"paid\").eq(false));"]
+#[doc = " assert_eq!("]
+#[doc = "     query.to_string(),"]
+#[doc = "     r#\"[\"AND\",[[\"amount\",\"gt\",100],[\"paid\",\"exact\",false]]]\"#"]
+#[doc = " );"]
+#[doc = " ```"]
+#[derive(PartialEq, Debug, Clone)]
+pub enum CfQuery {
+    #[doc = " Compare one field."]
+    Atom {
+        field: CfField,
+        operator: CfOperator,
+        value: serde_json::Value,
+    },
+    #[doc = " Every query matches."]
+    And(Vec<CfQuery>),
+    #[doc = " At least one query matches."]
+    Or(Vec<CfQuery>),
+    #[doc = " The query does not match."]
+    Not(Box<CfQuery>),
+}
+
+#[doc = " A custom field to compare, see [`CfQuery::field`]."]
+#[derive(PartialEq, Debug, Clone)]
+pub struct CfFieldQuery {
+    field: CfField,
+}
+
+fn cf_value<T: serde::Serialize>(value: T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+}
+
+impl CfFieldQuery {
+    fn compare(self, operator: CfOperator, value: serde_json::Value) -> CfQuery {
+        CfQuery::Atom {
+            field: self.field,
+            operator,
+            value,
+        }
+    }
+
+    #[doc = " The value equals `value`."]
+    pub fn exact<T: serde::Serialize>(self, value: T) -> CfQuery {
+        self.compare(CfOperator::Exact, cf_value(value))
+    }
+
+    #[doc = " The value equals `value`, an alias of [`CfFieldQuery::exact`]."]
+    pub fn eq<T: serde::Serialize>(self, value: T) -> CfQuery {
+        self.exact(value)
+    }
+
+    #[doc = " The value is one of `values`, e.g. the ids of select options."]
+    pub fn is_in<T: serde::Serialize, I: IntoIterator<Item = T>>(self, values: I) -> CfQuery {
+        let values = values.into_iter().map(cf_value).collect();
+        self.compare(CfOperator::In, serde_json::Value::Array(values))
+    }
+
+    #[doc = " The value is empty (`true`) or set (`false`)."]
+    pub fn isnull(self, isnull: bool) -> CfQuery {
+        self.compare(CfOperator::Isnull, isnull.into())
+    }
+
+    #[doc = " The field is attached to the document (`true`) or not (`false`)."]
+    pub fn exists(self, exists: bool) -> CfQuery {
+        self.compare(CfOperator::Exists, exists.into())
+    }
+
+    #[doc = " The value contains `text`, ignoring case."]
+    pub fn icontains<S: Into<String>>(self, text: S) -> CfQuery {
+        self.compare(CfOperator::Icontains, text.into().into())
+    }
+
+    #[doc = " The value starts with `text`, ignoring case."]
+    pub fn istartswith<S: Into<String>>(self, text: S) -> CfQuery {
+        self.compare(CfOperator::Istartswith, text.into().into())
+    }
+
+    #[doc = " The value ends with `text`, ignoring case."]
+    pub fn iendswith<S: Into<String>>(self, text: S) -> CfQuery {
+        self.compare(CfOperator::Iendswith, text.into().into())
+    }
+
+    #[doc = " The value is greater than `value`."]
+    pub fn gt<T: serde::Serialize>(self, value: T) -> CfQuery {
+        self.compare(CfOperator::Gt, cf_value(value))
+    }
+
+    #[doc = " The value is greater than or equal to `value`."]
+    pub fn gte<T: serde::Serialize>(self, value: T) -> CfQuery {
+        self.compare(CfOperator::Gte, cf_value(value))
+    }
+
+    #[doc = " The value is less than `value`."]
+    pub fn lt<T: serde::Serialize>(self, value: T) -> CfQuery {
+        self.compare(CfOperator::Lt, cf_value(value))
+    }
+
+    #[doc = " The value is less than or equal to `value`."]
+    pub fn lte<T: serde::Serialize>(self, value: T) -> CfQuery {
+        self.compare(CfOperator::Lte, cf_value(value))
+    }
+
+    #[doc = " The value lies between `from` and `to`, both included."]
+    pub fn range<T: serde::Serialize>(self, from: T, to: T) -> CfQuery {
+        let value = serde_json::Value::Array(vec![cf_value(from), cf_value(to)]);
+        self.compare(CfOperator::Range, value)
+    }
+
+    #[doc = " The document link field links all of the given documents."]
+    pub fn contains<I: IntoIterator<Item = i64>>(self, document_ids: I) -> CfQuery {
+        let ids = document_ids.into_iter().map(Into::into).collect();
+        self.compare(CfOperator::Contains, serde_json::Value::Array(ids))
+    }
+}
+
+impl CfQuery {
+    #[doc = " Start a comparison of the custom field with this name or id."]
+    pub fn field<F: Into<CfField>>(field: F) -> CfFieldQuery {
+        CfFieldQuery {
+            field: field.into(),
+        }
+    }
+
+    #[doc = " Every one of the queries matches."]
+    pub fn all<I: IntoIterator<Item = CfQuery>>(queries: I) -> Self {
+        CfQuery::And(queries.into_iter().collect())
+    }
+
+    #[doc = " At least one of the queries matches."]
+    pub fn any<I: IntoIterator<Item = CfQuery>>(queries: I) -> Self {
+        CfQuery::Or(queries.into_iter().collect())
+    }
+
+    #[doc = " Both this and the other query match."]
+    pub fn and(self, other: CfQuery) -> Self {
+        match self {
+            CfQuery::And(mut queries) => {
+                queries.push(other);
+                CfQuery::And(queries)
+            }
+            query => CfQuery::And(vec![query, other]),
+        }
+    }
+
+    #[doc = " This or the other query matches."]
+    pub fn or(self, other: CfQuery) -> Self {
+        match self {
+            CfQuery::Or(mut queries) => {
+                queries.push(other);
+                CfQuery::Or(queries)
+            }
+            query => CfQuery::Or(vec![query, other]),
+        }
+    }
+
+    #[doc = " The expression as sent to the server."]
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            CfQuery::Atom {
+                field,
+                operator,
+                value,
+            } => serde_json::json!([field, operator, value]),
+            CfQuery::And(queries) => {
+                let queries: Vec<_> = queries.iter().map(CfQuery::to_json).collect();
+                serde_json::json!(["AND", queries])
+            }
+            CfQuery::Or(queries) => {
+                let queries: Vec<_> = queries.iter().map(CfQuery::to_json).collect();
+                serde_json::json!(["OR", queries])
+            }
+            CfQuery::Not(query) => serde_json::json!(["NOT", query.to_json()]),
+        }
+    }
+}
+
+impl std::ops::Not for CfQuery {
+    type Output = CfQuery;
+
+    fn not(self) -> CfQuery {
+        match self {
+            CfQuery::Not(query) => *query,
+            query => CfQuery::Not(Box::new(query)),
+        }
+    }
+}
+
+impl serde::Serialize for CfQuery {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+impl std::fmt::Display for CfQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.to_json())
+    }
+}
+
+#[doc = " The value of a custom field, typed by the field's [`DataTypeEnum`]."]
+#[derive(PartialEq, Debug, Clone)]
+pub enum CustomFieldValue {
+    String(String),
+    Url(String),
+    Date(chrono::NaiveDate),
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    #[doc = " An amount, sent as e.g. `EUR123.45`; without currency the server's default applies."]
+    Monetary {
+        currency: Option<String>,
+        amount: bigdecimal::BigDecimal,
+    },
+    #[doc = " The ids of the linked documents."]
+    DocumentLink(Vec<i64>),
+    Select(SelectOption),
+}
+
+#[doc = " An option of a select custom field, by id or by label."]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SelectOption {
+    Id(String),
+    Label(String),
+}
+
+impl CustomFieldValue {
+    #[doc = " The data type a field needs to hold this value."]
+    pub fn data_type(&self) -> DataTypeEnum {
+        match self {
+            CustomFieldValue::String(_) => DataTypeEnum::String,
+            CustomFieldValue::Url(_) => DataTypeEnum::Url,
+            CustomFieldValue::Date(_) => DataTypeEnum::Date,
+            CustomFieldValue::Boolean(_) => DataTypeEnum::Boolean,
+            CustomFieldValue::Integer(_) => DataTypeEnum::Integer,
+            CustomFieldValue::Float(_) => DataTypeEnum::Float,
+            CustomFieldValue::Monetary { .. } => DataTypeEnum::Monetary,
+            CustomFieldValue::DocumentLink(_) => DataTypeEnum::Documentlink,
+            CustomFieldValue::Select(_) => DataTypeEnum::Select,
+        }
+    }
+
+    #[doc = " Parse a monetary value such as `EUR123.45` or `123.45`."]
+    pub fn parse_monetary(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let (currency, amount) = match value.char_indices().find(|(_, c)| !c.is_ascii_alphabetic())
+        {
+            Some((0, _)) => (None, value),
+            Some((i, _)) => (Some(value[..i].to_string()), &value[i..]),
+            None => return None,
+        };
+        Some(CustomFieldValue::Monetary {
+            currency,
+            amount: amount.parse().ok()?,
+        })
+    }
+}
+
+#[doc = " A custom field value that doesn't fit the field's definition."]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct InvalidCustomField {
+    #[doc = " The name of the field."]
+    pub field: String,
+    #[doc = " What is wrong with the value."]
+    pub message: String,
+}
+
+impl std::fmt::Display for InvalidCustomField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "custom field {:?}: {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for InvalidCustomField {}
+
+#[cfg(feature = "requests")]
+impl From<InvalidCustomField> for error::Error {
+    fn from(e: InvalidCustomField) -> Self {
+        error::Error::InvalidRequest(e.to_string())
+    }
+}
+
+fn invalid_custom_field(field: &CustomField, message: String) -> InvalidCustomField {
+    InvalidCustomField {
+        field: field.name.clone(),
+        message,
+    }
+}
+
+impl CustomField {
+    fn select_option_values(&self) -> Vec<(serde_json::Value, String)> {
+        let options = self
+            .extra_data
+            .as_ref()
+            .and_then(|extra| extra.get("select_options"))
+            .and_then(|options| options.as_array());
+        let Some(options) = options else {
+            return vec![];
+        };
+        options
+            .iter()
+            .enumerate()
+            .filter_map(|(index, option)| match option {
+                // Servers before API version 7 store the index of a plain label.
+                serde_json::Value::String(label) => Some((index.into(), label.clone())),
+                option => Some((
+                    option.get("id")?.clone(),
+                    option.get("label")?.as_str()?.to_string(),
+                )),
+            })
+            .collect()
+    }
+
+    #[doc = " The options of a select field as `(id, label)`."]
+    pub fn select_options(&self) -> Vec<(String, String)> {
+        self.select_option_values()
+            .into_iter()
+            .map(|(id, label)| match id {
+                serde_json::Value::String(id) => (id, label),
+                id => (id.to_string(), label),
+            })
+            .collect()
+    }
+
+    #[doc = " The label of a select option, if the field has it."]
+    pub fn select_label(&self, option: &SelectOption) -> Option<String> {
+        self.select_options()
+            .into_iter()
+            .find(|(id, label)| match option {
+                SelectOption::Id(i) => i == id,
+                SelectOption::Label(l) => l == label,
+            })
+            .map(|(_, label)| label)
+    }
+
+    #[doc = " Read a stored value of this field, `None` if it is empty."]
+    pub fn parse_value(
+        &self,
+        value: Option<&serde_json::Value>,
+    ) -> Result<Option<CustomFieldValue>, InvalidCustomField> {
+        let value = match value {
+            None | Some(serde_json::Value::Null) => return Ok(None),
+            Some(value) => value,
+        };
+        let invalid = || {
+            invalid_custom_field(
+                self,
+                format!("unexpected {:?} value {value}", self.data_type),
+            )
+        };
+        let parsed = match self.data_type {
+            DataTypeEnum::String => {
+                CustomFieldValue::String(value.as_str().ok_or_else(invalid)?.to_string())
+            }
+            DataTypeEnum::Url => {
+                CustomFieldValue::Url(value.as_str().ok_or_else(invalid)?.to_string())
+            }
+            DataTypeEnum::Date => CustomFieldValue::Date(
+                value
+                    .as_str()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(invalid)?,
+            ),
+            DataTypeEnum::Boolean => {
+                CustomFieldValue::Boolean(value.as_bool().ok_or_else(invalid)?)
+            }
+            DataTypeEnum::Integer => CustomFieldValue::Integer(value.as_i64().ok_or_else(invalid)?),
+            DataTypeEnum::Float => CustomFieldValue::Float(value.as_f64().ok_or_else(invalid)?),
+            DataTypeEnum::Monetary => match value {
+                serde_json::Value::String(s) if s.is_empty() => return Ok(None),
+                serde_json::Value::String(s) => {
+                    CustomFieldValue::parse_monetary(s).ok_or_else(invalid)?
+                }
+                serde_json::Value::Number(n) => CustomFieldValue::Monetary {
+                    currency: None,
+                    amount: n.to_string().parse().map_err(|_| invalid())?,
+                },
+                _ => return Err(invalid()),
+            },
+            DataTypeEnum::Documentlink => CustomFieldValue::DocumentLink(
+                value
+                    .as_array()
+                    .ok_or_else(invalid)?
+                    .iter()
+                    .map(|id| id.as_i64().ok_or_else(invalid))
+                    .collect::<Result<_, _>>()?,
+            ),
+            DataTypeEnum::Select => CustomFieldValue::Select(SelectOption::Id(match value {
+                serde_json::Value::String(id) => id.clone(),
+                serde_json::Value::Number(index) => index.to_string(),
+                _ => return Err(invalid()),
+            })),
+        };
+        Ok(Some(parsed))
+    }
+
+    #[doc = " Check a value against this field and return it as sent to the server."]
+    #[doc = ""]
+    #[doc = " Fails if the value has the wrong type, a string is longer than 128 characters,"]
+    #[doc = " a URL does not parse, a monetary amount has more than two decimals or an invalid"]
+    #[doc = " currency, or a select option does not exist."]
+    pub fn value_to_json(
+        &self,
+        value: &CustomFieldValue,
+    ) -> Result<serde_json::Value, InvalidCustomField> {
+        if value.data_type() != self.data_type {
+            return Err(invalid_custom_field(
+                self,
+                format!("expected a {} value, got {value:?}", self.data_type),
+            ));
+        }
+        Ok(match value {
+            CustomFieldValue::String(s) => {
+                if s.chars().count() > 128 {
+                    return Err(invalid_custom_field(
+                        self,
+                        "longer than 128 characters".to_string(),
+                    ));
+                }
+                s.clone().into()
+            }
+            CustomFieldValue::Url(u) => {
+                url::Url::parse(u)
+                    .map_err(|e| invalid_custom_field(self, format!("invalid URL {u:?}: {e}")))?;
+                u.clone().into()
+            }
+            CustomFieldValue::Date(d) => d.to_string().into(),
+            CustomFieldValue::Boolean(b) => (*b).into(),
+            CustomFieldValue::Integer(i) => (*i).into(),
+            CustomFieldValue::Float(f) => serde_json::Number::from_f64(*f)
+                .ok_or_else(|| invalid_custom_field(self, format!("{f} is not a finite number")))?
+                .into(),
+            CustomFieldValue::Monetary { currency, amount } => {
+                if amount.with_scale(2) != *amount {
+                    return Err(invalid_custom_field(
+                        self,
+                        format!("{amount} has more than two decimal places"),
+                    ));
+                }
+                let amount = amount.with_scale(2);
+                match currency {
+                    Some(c) if c.len() == 3 && c.chars().all(|c| c.is_ascii_uppercase()) => {
+                        format!("{c}{amount}").into()
+                    }
+                    Some(c) => {
+                        return Err(invalid_custom_field(
+                            self,
+                            format!("invalid currency code {c:?}"),
+                        ))
+                    }
+                    None => amount.to_string().into(),
+                }
+            }
+            CustomFieldValue::DocumentLink(ids) => ids.clone().into(),
+            CustomFieldValue::Select(option) => self
+                .select_option_values()
+                .into_iter()
+                .find(|(id, label)| match option {
+                    SelectOption::Id(i) => {
+                        id.as_str() == Some(i) || (id.is_i64() && id.as_i64() == i.parse().ok())
+                    }
+                    SelectOption::Label(l) => l == label,
+                })
+                .map(|(id, _)| id)
+                .ok_or_else(|| {
+                    invalid_custom_field(self, format!("no select option {option:?}"))
+                })?,
+        })
+    }
+
+    #[doc = " Check a value against this field and build the instance of a document update."]
+    pub fn instance(
+        &self,
+        value: Option<&CustomFieldValue>,
+    ) -> Result<CustomFieldInstanceRequest, InvalidCustomField> {
+        Ok(CustomFieldInstanceRequest {
+            value: value.map(|v| self.value_to_json(v)).transpose()?,
+            field: self.id,
+        })
+    }
+}
+
+impl Document {
+    #[doc = " The typed value of a custom field, `None` if the field is missing or empty."]
+    pub fn custom_field_value(
+        &self,
+        field: &CustomField,
+    ) -> Result<Option<CustomFieldValue>, InvalidCustomField> {
+        let instance = self
+            .custom_fields
+            .iter()
+            .flatten()
+            .find(|instance| instance.field == field.id);
+        match instance {
+            Some(instance) => field.parse_value(instance.value.as_ref()),
+            None => Ok(None),
+        }
+    }
+
+    #[doc = " The minimal request turning `self` into `other`, setting only the editable fields"]
+    #[doc = " that differ. Read-only fields like `notes` or `modified` are never sent."]
+    #[doc = ""]
+    #[doc = " The request can't clear a field; see [`Document::cleared_fields`] for those."]
+    pub fn diff(&self, other: &Document) -> PatchedDocumentRequest {
+        fn changed<T: PartialEq + Clone>(old: &Option<T>, new: &Option<T>) -> Option<T> {
+            if old != new {
+                new.clone()
+            } else {
+                None
+            }
+        }
+        let sorted = |tags: &[i64]| {
+            let mut tags = tags.to_vec();
+            tags.sort_unstable();
+            tags.dedup();
+            tags
+        };
+        // Sorted by field, as the server doesn't keep the order of the instances.
+        let custom_fields = |document: &Document| {
+            let mut fields = document
+                .custom_fields
+                .iter()
+                .flatten()
+                .map(|instance| CustomFieldInstanceRequest {
+                    value: instance.value.clone(),
+                    field: instance.field,
+                })
+                .collect::<Vec<_>>();
+            fields.sort_by_key(|instance| instance.field);
+            fields
+        };
+        let fields = custom_fields(other);
+        #[allow(deprecated)]
+        PatchedDocumentRequest {
+            correspondent: changed(&self.correspondent, &other.correspondent),
+            document_type: changed(&self.document_type, &other.document_type),
+            storage_path: changed(&self.storage_path, &other.storage_path),
+            title: changed(&self.title, &other.title),
+            content: changed(&self.content, &other.content),
+            tags: (sorted(&self.tags) != sorted(&other.tags)).then(|| other.tags.clone()),
+            created: changed(&self.created, &other.created),
+            created_date: None,
+            deleted_at: None,
+            archive_serial_number: changed(
+                &self.archive_serial_number,
+                &other.archive_serial_number,
+            ),
+            owner: changed(&self.owner, &other.owner),
+            set_permissions: None,
+            custom_fields: (custom_fields(self) != fields).then_some(fields),
+            remove_inbox_tags: None,
+        }
+    }
+
+    #[doc = " The nullable fields set on `self` that are empty on `other`, which a"]
+    #[doc = " [`Document::diff`] can't express and have to be sent as `null`."]
+    pub fn cleared_fields(&self, other: &Document) -> Vec<&'static str> {
+        [
+            ("correspondent", self.correspondent, other.correspondent),
+            ("document_type", self.document_type, other.document_type),
+            ("storage_path", self.storage_path, other.storage_path),
+            (
+                "archive_serial_number",
+                self.archive_serial_number,
+                other.archive_serial_number,
+            ),
+            ("owner", self.owner, other.owner),
+        ]
+        .into_iter()
+        .filter(|(_, old, new)| old.is_some() && new.is_none())
+        .map(|(field, _, _)| field)
+        .collect()
+    }
+}
+
+#[doc = " An inclusive range of 1-based page numbers, written as `3` or `2-5`."]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct PageRange {
+    pub first: u32,
+    pub last: u32,
+}
+
+impl PageRange {
+    #[doc = " A single page."]
+    pub fn page(page: u32) -> Self {
+        Self {
+            first: page,
+            last: page,
+        }
+    }
+
+    #[doc = " The pages from `first` to `last`, both included."]
+    pub fn new(first: u32, last: u32) -> Self {
+        Self { first, last }
+    }
+
+    #[doc = " The page numbers of the range."]
+    pub fn pages(&self) -> std::ops::RangeInclusive<u32> {
+        self.first..=self.last
+    }
+}
+
+impl From<u32> for PageRange {
+    fn from(page: u32) -> Self {
+        Self::page(page)
+    }
+}
+
+impl From<std::ops::RangeInclusive<u32>> for PageRange {
+    fn from(range: std::ops::RangeInclusive<u32>) -> Self {
+        Self::new(*range.start(), *range.end())
+    }
+}
+
+impl std::fmt::Display for PageRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        if self.first == self.last {
+            write!(f, "{}", self.first)
+        } else {
+            write!(f, "{}-{}", self.first, self.last)
+        }
+    }
+}
+
+#[doc = " A clockwise rotation of pages."]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Rotation {
+    Degrees90,
+    Degrees180,
+    Degrees270,
+}
+
+impl Rotation {
+    #[doc = " The rotation in degrees."]
+    pub fn degrees(&self) -> i64 {
+        match self {
+            Rotation::Degrees90 => 90,
+            Rotation::Degrees180 => 180,
+            Rotation::Degrees270 => 270,
+        }
+    }
+}
+
+#[doc = " A page of the edited document in an `edit_pdf` bulk operation."]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct EditPdfOperation {
+    #[doc = " The 1-based page number in the original document."]
+    pub page: u32,
+    #[doc = " Rotate the page clockwise."]
+    pub rotate: Option<Rotation>,
+    #[doc = " The 0-based index of the output document the page goes to, to split the document."]
+    pub doc: Option<u32>,
+}
+
+impl EditPdfOperation {
+    #[doc = " Keep a page unchanged."]
+    pub fn page(page: u32) -> Self {
+        Self {
+            page,
+            rotate: None,
+            doc: None,
+        }
+    }
+
+    #[doc = " Rotate the page clockwise."]
+    pub fn rotate(mut self, rotation: Rotation) -> Self {
+        self.rotate = Some(rotation);
+        self
+    }
+
+    #[doc = " Move the page to the output document with this 0-based index."]
+    pub fn to_document(mut self, doc: u32) -> Self {
+        self.doc = Some(doc);
+        self
+    }
+}
+
+#[doc = " A bulk edit of documents, one variant with typed parameters per [`MethodEnum`]."]
+#[doc = ""]
+#[doc = " ```"]
+#[doc = " use paperless_api_client::types::{BulkOperation, MethodEnum, PageRange};"]
+#[doc = ""]
+#[doc = " let request = BulkOperation::Split {"]
+#[doc = "     pages: vec![PageRange::new(1, 2), PageRange::page(3)],"]
+#[doc = "     delete_originals: false,"]
+#[doc = " }"]
+#[doc = " .on([42]);"]
+#[doc = " assert_eq!(request.method, MethodEnum::Split);"]
+#[doc = " assert_eq!(request.parameters.unwrap()[\"pages\"], \"1-2,3\");"]
+#[doc = " ```"]
+#[derive(PartialEq, Debug, Clone)]
+pub enum BulkOperation {
+    #[doc = " Set the correspondent, `None` to remove it."]
+    SetCorrespondent {
+        correspondent: Option<i64>,
+    },
+    #[doc = " Set the document type, `None` to remove it."]
+    SetDocumentType {
+        document_type: Option<i64>,
+    },
+    #[doc = " Set the storage path, `None` to remove it."]
+    SetStoragePath {
+        storage_path: Option<i64>,
+    },
+    AddTag {
+        tag: i64,
+    },
+    RemoveTag {
+        tag: i64,
+    },
+    ModifyTags {
+        add_tags: Vec<i64>,
+        remove_tags: Vec<i64>,
+    },
+    #[doc = " Add custom fields, with a value or empty, and remove others."]
+    ModifyCustomFields {
+        add_custom_fields: Vec<CustomFieldInstanceRequest>,
+        remove_custom_fields: Vec<i64>,
+    },
+    Delete,
+    #[doc = " Consume the original files again."]
+    Reprocess,
+    #[doc = " Set the owner and permissions; with `merge` existing permissions are kept."]
+    SetPermissions {
+        set_permissions: Option<SetPermissions>,
+        owner: Option<i64>,
+        merge: bool,
+    },
+    Rotate {
+        rotation: Rotation,
+    },
+    #[doc = " Merge the documents into a new one, taking the metadata of `metadata_document_id`."]
+    Merge {
+        metadata_document_id: Option<i64>,
+        delete_originals: bool,
+        archive_fallback: bool,
+    },
+    #[doc = " Split a single document into one new document per range."]
+    Split {
+        pages: Vec<PageRange>,
+        delete_originals: bool,
+    },
+    #[doc = " Delete pages of a single document."]
+    DeletePages {
+        pages: Vec<PageRange>,
+    },
+    #[doc = " Rearrange, rotate and split the pages of a single document."]
+    EditPdf {
+        operations: Vec<EditPdfOperation>,
+        delete_original: bool,
+        update_document: bool,
+        include_metadata: bool,
+    },
+}
+
+impl BulkOperation {
+    #[doc = " The method of the operation."]
+    pub fn method(&self) -> MethodEnum {
+        match self {
+            BulkOperation::SetCorrespondent { .. } => MethodEnum::SetCorrespondent,
+            BulkOperation::SetDocumentType { .. } => MethodEnum::SetDocumentType,
+            BulkOperation::SetStoragePath { .. } => MethodEnum::SetStoragePath,
+            BulkOperation::AddTag { .. } => MethodEnum::AddTag,
+            BulkOperation::RemoveTag { .. } => MethodEnum::RemoveTag,
+            BulkOperation::ModifyTags { .. } => MethodEnum::ModifyTags,
+            BulkOperation::ModifyCustomFields { .. } => MethodEnum::ModifyCustomFields,
+            BulkOperation::Delete => MethodEnum::Delete,
+            BulkOperation::Reprocess => MethodEnum::Reprocess,
+            BulkOperation::SetPermissions { .. } => MethodEnum::SetPermissions,
+            BulkOperation::Rotate { .. } => MethodEnum::Rotate,
+            BulkOperation::Merge { .. } => MethodEnum::Merge,
+            BulkOperation::Split { .. } => MethodEnum::Split,
+            BulkOperation::DeletePages { .. } => MethodEnum::DeletePages,
+            BulkOperation::EditPdf { .. } => MethodEnum::EditPdf,
+        }
+    }
+
+    #[doc = " The parameters of the operation as sent to the server."]
+    pub fn parameters(&self) -> std::collections::HashMap<String, serde_json::Value> {
+        let parameters = match self {
+            BulkOperation::SetCorrespondent { correspondent } => {
+                serde_json::json!({ "correspondent": correspondent })
+            }
+            BulkOperation::SetDocumentType { document_type } => {
+                serde_json::json!({ "document_type": document_type })
+            }
+            BulkOperation::SetStoragePath { storage_path } => {
+                serde_json::json!({ "storage_path": storage_path })
+            }
+            BulkOperation::AddTag { tag } | BulkOperation::RemoveTag { tag } => {
+                serde_json::json!({ "tag": tag })
+            }
+            BulkOperation::ModifyTags {
+                add_tags,
+                remove_tags,
+            } => serde_json::json!({ "add_tags": add_tags, "remove_tags": remove_tags }),
+            BulkOperation::ModifyCustomFields {
+                add_custom_fields,
+                remove_custom_fields,
+            } => {
+                let add: serde_json::Map<String, serde_json::Value> = add_custom_fields
+                    .iter()
+                    .map(|i| (i.field.to_string(), i.value.clone().unwrap_or_default()))
+                    .collect();
+                serde_json::json!({
+                    "add_custom_fields": add,
+                    "remove_custom_fields": remove_custom_fields,
+                })
+            }
+            BulkOperation::Delete | BulkOperation::Reprocess => serde_json::json!({}),
+            BulkOperation::SetPermissions {
+                set_permissions,
+                owner,
+                merge,
+            } => {
+                let mut parameters = serde_json::json!({ "owner": owner, "merge": merge });
+                if let Some(set_permissions) = set_permissions {
+                    parameters["set_permissions"] = serde_json::json!(set_permissions);
+                }
+                parameters
+            }
+            BulkOperation::Rotate { rotation } => {
+                serde_json::json!({ "degrees": rotation.degrees() })
+            }
+            BulkOperation::Merge {
+                metadata_document_id,
+                delete_originals,
+                archive_fallback,
+            } => serde_json::json!({
+                "metadata_document_id": metadata_document_id,
+                "delete_originals": delete_originals,
+                "archive_fallback": archive_fallback,
+            }),
+            BulkOperation::Split {
+                pages,
+                delete_originals,
+            } => serde_json::json!({
+                "pages": itertools::join(pages, ","),
+                "delete_originals": delete_originals,
+            }),
+            BulkOperation::DeletePages { pages } => {
+                let pages: Vec<u32> = pages.iter().flat_map(PageRange::pages).collect();
+                serde_json::json!({ "pages": pages })
+            }
+            BulkOperation::EditPdf {
+                operations,
+                delete_original,
+                update_document,
+                include_metadata,
+            } => {
+                let operations: Vec<serde_json::Value> = operations
+                    .iter()
+                    .map(|op| {
+                        let mut operation = serde_json::json!({ "page": op.page });
+                        if let Some(rotation) = op.rotate {
+                            operation["rotate"] = rotation.degrees().into();
+                        }
+                        if let Some(doc) = op.doc {
+                            operation["doc"] = doc.into();
+                        }
+                        operation
+                    })
+                    .collect();
+                serde_json::json!({
+                    "operations": operations,
+                    "delete_original": delete_original,
+                    "update_document": update_document,
+                    "include_metadata": include_metadata,
+                })
+            }
+        };
+        match parameters {
+            serde_json::Value::Object(map) => map.into_iter().collect(),
+            _ => Default::default(),
+        }
+    }
+
+    #[doc = " The request applying the operation to the given documents."]
+    pub fn on<I: IntoIterator<Item = i64>>(&self, documents: I) -> BulkEditRequest {
+        BulkEditRequest {
+            documents: documents.into_iter().collect(),
+            method: self.method(),
+            parameters: Some(self.parameters()),
+        }
+    }
+}
+
+impl From<&BulkEditRequest> for BulkEditRequest {
+    fn from(request: &BulkEditRequest) -> Self {
+        request.clone()
+    }
+}
+
+#[doc = " How [`Documents::apply_suggestions`](crate::documents::Documents::apply_suggestions)"]
+#[doc = " treats the suggested tags."]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum SuggestedTags {
+    #[doc = " Leave the tags alone."]
+    Ignore,
+    #[doc = " Add the suggested tags to those of the document."]
+    #[default]
+    Add,
+    #[doc = " Replace the tags of the document, including inbox tags, with the suggested ones."]
+    #[doc = " The tags are kept if no tags are suggested."]
+    Replace,
+}
+
+#[doc = " Which suggestions [`Documents::apply_suggestions`](crate::documents::Documents::apply_suggestions)"]
+#[doc = " applies to a document."]
+#[doc = ""]
+#[doc = " The first suggested correspondent, document type, storage path and date are"]
+#[doc = " taken, the way the web UI offers them. By default the suggestions except the"]
+#[doc = " date are applied, but only to fields that are not set yet."]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SuggestionPolicy {
+    #[doc = " Apply the first suggested correspondent."]
+    pub correspondent: bool,
+    #[doc = " Apply the first suggested document type."]
+    pub document_type: bool,
+    #[doc = " Apply the first suggested storage path."]
+    pub storage_path: bool,
+    #[doc = " Replace `created` with the first suggested date that can be parsed. Every"]
+    #[doc = " document has a creation date, so this is off by default and doesn't need"]
+    #[doc = " `overwrite`."]
+    pub created: bool,
+    #[doc = " How to treat the suggested tags."]
+    pub tags: SuggestedTags,
+    #[doc = " Replace values that are already set instead of only filling in empty fields."]
+    pub overwrite: bool,
+    #[doc = " Only report the changes without updating the document."]
+    pub dry_run: bool,
+}
+
+impl Default for SuggestionPolicy {
+    fn default() -> Self {
+        SuggestionPolicy {
+            correspondent: true,
+            document_type: true,
+            storage_path: true,
+            created: false,
+            tags: SuggestedTags::Add,
+            overwrite: false,
+            dry_run: false,
+        }
+    }
+}
+
+impl SuggestionPolicy {
+    #[doc = " Apply the suggested date to `created`."]
+    pub fn created(mut self) -> Self {
+        self.created = true;
+        self
+    }
+
+    #[doc = " Replace values that are already set."]
+    pub fn overwrite(mut self) -> Self {
+        self.overwrite = true;
+        self
+    }
+
+    #[doc = " Only report the changes."]
+    pub fn dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+
+    #[doc = " The changes applying `suggestions` to `document` makes."]
+    pub fn changes(&self, document: &Document, suggestions: &Suggestions) -> DocumentChanges {
+        let pick = |enabled: bool, current: Option<i64>, suggested: &[i64]| {
+            let new = suggested.first().copied();
+            (enabled && new.is_some() && new != current && (self.overwrite || current.is_none()))
+                .then_some(FieldChange { old: current, new })
+        };
+        let mut changes = DocumentChanges {
+            correspondent: pick(
+                self.correspondent,
+                document.correspondent,
+                &suggestions.correspondents,
+            ),
+            document_type: pick(
+                self.document_type,
+                document.document_type,
+                &suggestions.document_types,
+            ),
+            storage_path: pick(
+                self.storage_path,
+                document.storage_path,
+                &suggestions.storage_paths,
+            ),
+            ..Default::default()
+        };
+        let tags = match self.tags {
+            SuggestedTags::Ignore => document.tags.clone(),
+            SuggestedTags::Add => {
+                let mut tags = document.tags.clone();
+                for tag in &suggestions.tags {
+                    if !tags.contains(tag) {
+                        tags.push(*tag);
+                    }
+                }
+                tags
+            }
+            // No suggested tags is no suggestion, not a request to remove all tags.
+            SuggestedTags::Replace if suggestions.tags.is_empty() => document.tags.clone(),
+            SuggestedTags::Replace => suggestions.tags.clone(),
+        };
+        let mut sorted = (document.tags.clone(), tags.clone());
+        sorted.0.sort_unstable();
+        sorted.1.sort_unstable();
+        sorted.1.dedup();
+        if sorted.0 != sorted.1 {
+            changes.tags = Some(FieldChange {
+                old: document.tags.clone(),
+                new: tags,
+            });
+        }
+        let date = suggestions.dates.iter().find_map(|date| parse_date(date));
+        if self.created && date.is_some() && date != document.created {
+            changes.created = Some(FieldChange {
+                old: document.created,
+                new: date,
+            });
+        }
+        changes
+    }
+}
+
+#[doc = " Parse a suggested date, given either as a date or as a timestamp."]
+fn parse_date(date: &str) -> Option<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .or_else(|| {
+            chrono::DateTime::parse_from_rfc3339(date)
+                .ok()
+                .map(|date| date.date_naive())
+        })
+}
+
+#[doc = " The old and new value of a changed field."]
+#[derive(serde :: Serialize, serde :: Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct FieldChange<T> {
+    #[doc = " The value before the change."]
+    pub old: T,
+    #[doc = " The value after the change."]
+    pub new: T,
+}
+
+#[doc = " The fields of a document that changed, with their old and new values."]
+#[derive(serde :: Serialize, serde :: Deserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub struct DocumentChanges {
+    #[doc = " The id of the correspondent, if it changed."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correspondent: Option<FieldChange<Option<i64>>>,
+    #[doc = " The id of the document type, if it changed."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type: Option<FieldChange<Option<i64>>>,
+    #[doc = " The id of the storage path, if it changed."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path: Option<FieldChange<Option<i64>>>,
+    #[doc = " The ids of the tags, if the set of tags changed."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<FieldChange<Vec<i64>>>,
+    #[doc = " The creation date, if it changed."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<FieldChange<Option<chrono::NaiveDate>>>,
+}
+
+impl std::fmt::Display for DocumentChanges {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+impl DocumentChanges {
+    #[doc = " Returns true if nothing changed."]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    #[doc = " The request setting the new values of the changed fields."]
+    pub fn to_patch(&self) -> PatchedDocumentRequest {
+        #[allow(deprecated)]
+        PatchedDocumentRequest {
+            correspondent: self.correspondent.as_ref().and_then(|c| c.new),
+            document_type: self.document_type.as_ref().and_then(|c| c.new),
+            storage_path: self.storage_path.as_ref().and_then(|c| c.new),
+            title: None,
+            content: None,
+            tags: self.tags.as_ref().map(|c| c.new.clone()),
+            created: self.created.as_ref().and_then(|c| c.new),
+            created_date: None,
+            deleted_at: None,
+            archive_serial_number: None,
+            owner: None,
+            set_permissions: None,
+            custom_fields: None,
+            remove_inbox_tags: None,
+        }
+    }
+}
+
+#[doc = " The outcome of applying suggestions to a document."]
+#[derive(Debug, Clone)]
+pub struct AppliedSuggestions {
+    #[doc = " The document, updated unless nothing changed or it was a dry run."]
+    pub document: Document,
+    #[doc = " The changes made, or that would have been made on a dry run."]
+    pub changes: DocumentChanges,
+    #[doc = " Whether the document was updated."]
+    pub applied: bool,
+}
+
+#[doc = " What happened to an object in an audit log entry."]
+#[derive(serde :: Serialize, serde :: Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
+#[serde(from = "String", into = "String")]
+pub enum HistoryAction {
+    #[doc = " The object was created."]
+    Create,
+    #[doc = " The object was changed."]
+    Update,
+    #[doc = " The object was deleted."]
+    Delete,
+    #[doc = " An action this client doesn't know."]
+    Unknown(String),
+}
+
+impl From<String> for HistoryAction {
+    fn from(action: String) -> Self {
+        match action.as_str() {
+            "create" => HistoryAction::Create,
+            "update" => HistoryAction::Update,
+            "delete" => HistoryAction::Delete,
+            _ => HistoryAction::Unknown(action),
+        }
+    }
+}
+
+impl From<HistoryAction> for String {
+    fn from(action: HistoryAction) -> Self {
+        action.to_string()
+    }
+}
+
+impl std::fmt::Display for HistoryAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            HistoryAction::Create => write!(f, "create"),
+            HistoryAction::Update => write!(f, "update"),
+            HistoryAction::Delete => write!(f, "delete"),
+            HistoryAction::Unknown(action) => write!(f, "{action}"),
+        }
+    }
+}
+
+#[doc = " The change of one field in an audit log entry."]
+#[derive(serde :: Serialize, serde :: Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryChange {
+    #[doc = " A plain field, with the values as the server records them. Empty values are `None`."]
+    Value {
+        #[doc = " The value before the change."]
+        old: Option<String>,
+        #[doc = " The value after the change."]
+        new: Option<String>,
+    },
+    #[doc = " The correspondent, document type, storage path or owner, by id."]
+    Reference {
+        #[doc = " The id before the change."]
+        old: Option<i64>,
+        #[doc = " The id after the change."]
+        new: Option<i64>,
+    },
+    #[doc = " Tags added to or removed from the document, by name."]
+    Tags {
+        #[doc = " The names of the added tags."]
+        added: Vec<String>,
+        #[doc = " The names of the removed tags."]
+        removed: Vec<String>,
+    },
+    #[doc = " A custom field of the document was set, changed or removed."]
+    CustomField {
+        #[doc = " The name of the custom field."]
+        field: String,
+        #[doc = " The value as the server records it."]
+        value: String,
+    },
+    #[doc = " A change in a format this client doesn't know."]
+    Other(serde_json::Value),
+}
+
+impl HistoryChange {
+    #[doc = " Parse the change the server recorded for `field`."]
+    pub fn parse(field: &str, value: &serde_json::Value) -> Self {
+        // The audit log stores plain values as strings, with `None` for null.
+        let text = |value: &serde_json::Value| match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(s) if s == "None" => None,
+            serde_json::Value::String(s) => Some(s.clone()),
+            other => Some(other.to_string()),
+        };
+        let names = |value: Option<&serde_json::Value>| {
+            value
+                .and_then(|v| v.as_array())
+                .map(|items| items.iter().filter_map(text).collect::<Vec<_>>())
+                .unwrap_or_default()
+        };
+        match value {
+            serde_json::Value::Array(pair) if pair.len() == 2 => {
+                let (old, new) = (text(&pair[0]), text(&pair[1]));
+                if matches!(
+                    field,
+                    "correspondent" | "document_type" | "storage_path" | "owner"
+                ) {
+                    let id = |value: &Option<String>| value.as_deref().map(str::parse).transpose();
+                    if let (Ok(old), Ok(new)) = (id(&old), id(&new)) {
+                        return HistoryChange::Reference { old, new };
+                    }
+                }
+                HistoryChange::Value { old, new }
+            }
+            serde_json::Value::Object(map) => {
+                let kind = map.get("type").and_then(|v| v.as_str());
+                let operation = map.get("operation").and_then(|v| v.as_str());
+                match (kind, operation) {
+                    (Some("m2m"), Some("add")) => HistoryChange::Tags {
+                        added: names(map.get("objects")),
+                        removed: vec![],
+                    },
+                    (Some("m2m"), Some("delete")) => HistoryChange::Tags {
+                        added: vec![],
+                        removed: names(map.get("objects")),
+                    },
+                    (Some("custom_field"), _) => HistoryChange::CustomField {
+                        field: map.get("field").and_then(text).unwrap_or_default(),
+                        value: map.get("value").and_then(text).unwrap_or_default(),
+                    },
+                    _ => HistoryChange::Other(value.clone()),
+                }
+            }
+            _ => HistoryChange::Other(value.clone()),
+        }
+    }
+}
+
+#[doc = " An entry of the audit log of a document, with typed changes."]
+#[derive(serde :: Serialize, serde :: Deserialize, PartialEq, Debug, Clone)]
+pub struct HistoryEntry {
+    pub id: i64,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub action: HistoryAction,
+    #[doc = " The user who made the change, `None` for changes made by the server itself."]
+    pub actor: Option<Actor>,
+    #[doc = " The changes, keyed by field. Custom field changes are keyed by `custom_fields`."]
+    pub changes: std::collections::BTreeMap<String, HistoryChange>,
+}
+
+impl std::fmt::Display for HistoryEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+impl From<LogEntry> for HistoryEntry {
+    fn from(entry: LogEntry) -> Self {
+        HistoryEntry {
+            id: entry.id,
+            timestamp: entry.timestamp,
+            action: entry.action.into(),
+            actor: entry.actor,
+            changes: entry
+                .changes
+                .iter()
+                .map(|(field, value)| (field.clone(), HistoryChange::parse(field, value)))
+                .collect(),
+        }
+    }
+}
+
+impl HistoryEntry {
+    #[doc = " The change of `field`, which is either a field of the document or the name of"]
+    #[doc = " a custom field."]
+    pub fn change(&self, field: &str) -> Option<&HistoryChange> {
+        self.changes.get(field).or_else(|| {
+            self.changes.values().find(
+                |change| matches!(change, HistoryChange::CustomField { field: f, .. } if f == field),
+            )
+        })
+    }
+
+    #[doc = " Who changed `field` and when, if this entry changed it. See [`HistoryEntry::change`]."]
+    pub fn field_edit(&self, field: &str) -> Option<FieldEdit> {
+        self.change(field).map(|change| FieldEdit {
+            timestamp: self.timestamp,
+            actor: self.actor.clone(),
+            action: self.action.clone(),
+            change: change.clone(),
+        })
+    }
+}
+
+#[doc = " Who changed a field and when, see"]
+#[doc = " [`Documents::field_history`](crate::documents::Documents::field_history)."]
+#[derive(serde :: Serialize, serde :: Deserialize, PartialEq, Debug, Clone)]
+pub struct FieldEdit {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    #[doc = " The user who made the change, `None` for changes made by the server itself."]
+    pub actor: Option<Actor>,
+    pub action: HistoryAction,
+    pub change: HistoryChange,
+}
+
+impl std::fmt::Display for FieldEdit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?
+        )
+    }
+}
+
+#[doc = " Define an enum for a field the API encodes as an integer. Known codes become"]
+#[doc = " unit variants, any other code `Unknown` so newer servers don't break parsing."]
+macro_rules! integer_enum {
+    (
+        $(#[doc = $doc:expr])*
+        pub enum $name:ident {
+            $(
+                #[doc = $variant_doc:expr]
+                $variant:ident = $code:literal => $display:literal,
+            )*
+        }
+    ) => {
+        $(#[doc = $doc])*
+        #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+        pub enum $name {
+            $(
+                #[doc = $variant_doc]
+                $variant,
+            )*
+            #[doc = " A code this version of the client doesn't know."]
+            Unknown(i64),
+        }
+
+        impl $name {
+            #[doc = " The known variants."]
+            pub const VARIANTS: &'static [$name] = &[$($name::$variant),*];
+
+            #[doc = " The integer the API uses for the variant."]
+            pub fn code(&self) -> i64 {
+                match self {
+                    $($name::$variant => $code,)*
+                    $name::Unknown(code) => *code,
+                }
+            }
+
+            #[doc = " The variant for an integer used by the API."]
+            pub fn from_code(code: i64) -> Self {
+                match code {
+                    $($code => $name::$variant,)*
+                    code => $name::Unknown(code),
+                }
+            }
+
+            #[doc = " The name of a known variant, as used by `Display` and `FromStr`."]
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $($name::$variant => Some($display),)*
+                    $name::Unknown(_) => None,
+                }
+            }
+        }
+
+        impl From<i64> for $name {
+            fn from(code: i64) -> Self {
+                $name::from_code(code)
+            }
+        }
+
+        impl From<$name> for i64 {
+            fn from(value: $name) -> Self {
+                value.code()
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_i64(self.code())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <i64 as serde::Deserialize>::deserialize(deserializer).map($name::from_code)
+            }
+        }
+
+        impl schemars::JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).to_string()
+            }
+
+            fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                <i64 as schemars::JsonSchema>::json_schema(gen)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+                match self.name() {
+                    Some(name) => write!(f, "{name}"),
+                    None => write!(f, "{}", self.code()),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = parse_display::ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if let Ok(code) = s.parse::<i64>() {
+                    return Ok($name::from_code(code));
+                }
+                $name::VARIANTS
+                    .iter()
+                    .find(|variant| variant.name() == Some(s))
+                    .copied()
+                    .ok_or_else(parse_display::ParseError::new)
+            }
+        }
+
+        #[cfg(feature = "clap")]
+        impl clap::ValueEnum for $name {
+            fn value_variants<'a>() -> &'a [Self] {
+                $name::VARIANTS
+            }
+
+            fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+                self.name().map(clap::builder::PossibleValue::new)
+            }
+        }
+    };
+}
+
+integer_enum! {
+    #[doc = " How the name or pattern of a tag, correspondent, document type, storage path"]
+    #[doc = " or workflow trigger is matched against the content of documents."]
+    pub enum MatchingAlgorithm {
+        #[doc = " Never match automatically."]
+        None = 0 => "none",
+        #[doc = " Any of the words."]
+        Any = 1 => "any",
+        #[doc = " All of the words."]
+        All = 2 => "all",
+        #[doc = " The exact text."]
+        Literal = 3 => "literal",
+        #[doc = " A regular expression."]
+        Regex = 4 => "regex",
+        #[doc = " A fuzzy match of the text."]
+        Fuzzy = 5 => "fuzzy",
+        #[doc = " Learned from the documents the object is assigned to."]
+        Auto = 6 => "auto",
+    }
+}
+
+integer_enum! {
+    #[doc = " The event starting a workflow."]
+    pub enum WorkflowTriggerType {
+        #[doc = " A document is about to be consumed."]
+        Consumption = 1 => "consumption",
+        #[doc = " A document was added."]
+        DocumentAdded = 2 => "document_added",
+        #[doc = " A document was updated."]
+        DocumentUpdated = 3 => "document_updated",
+        #[doc = " A date of a document was reached."]
+        Scheduled = 4 => "scheduled",
+    }
+}
+
+integer_enum! {
+    #[doc = " What a workflow action does."]
+    pub enum WorkflowActionType {
+        #[doc = " Assign metadata and permissions."]
+        Assignment = 1 => "assignment",
+        #[doc = " Remove metadata and permissions."]
+        Removal = 2 => "removal",
+        #[doc = " Send an email."]
+        Email = 3 => "email",
+        #[doc = " Call a webhook."]
+        Webhook = 4 => "webhook",
+    }
+}
+
+integer_enum! {
+    #[doc = " Where a consumed document came from, filtering the triggers of workflows."]
+    pub enum DocumentSource {
+        #[doc = " The consumption directory."]
+        ConsumeFolder = 1 => "consume_folder",
+        #[doc = " An upload through the API."]
+        ApiUpload = 2 => "api_upload",
+        #[doc = " A mail rule."]
+        MailFetch = 3 => "mail_fetch",
+        #[doc = " An upload in the web UI."]
+        WebUi = 4 => "web_ui",
+    }
+}
+
+integer_enum! {
+    #[doc = " What a mail rule does with processed mails."]
+    pub enum MailRuleAction {
+        #[doc = " Delete the mail."]
+        Delete = 1 => "delete",
+        #[doc = " Move the mail to a folder."]
+        Move = 2 => "move",
+        #[doc = " Mark the mail as read."]
+        MarkRead = 3 => "mark_read",
+        #[doc = " Flag the mail."]
+        Flag = 4 => "flag",
+        #[doc = " Tag the mail with a keyword."]
+        Tag = 5 => "tag",
+    }
+}
+
+integer_enum! {
+    #[doc = " Where a mail rule takes the titles of documents from."]
+    pub enum MailTitleSource {
+        #[doc = " The subject of the mail."]
+        Subject = 1 => "subject",
+        #[doc = " The file name of the attachment."]
+        Filename = 2 => "filename",
+        #[doc = " Don't assign a title from the mail."]
+        None = 3 => "none",
+    }
+}
+
+integer_enum! {
+    #[doc = " Where a mail rule takes the correspondents of documents from."]
+    pub enum MailCorrespondentSource {
+        #[doc = " Don't assign a correspondent."]
+        Nothing = 1 => "nothing",
+        #[doc = " The mail address of the sender."]
+        Email = 2 => "email",
+        #[doc = " The name of the sender."]
+        Name = 3 => "name",
+        #[doc = " The correspondent configured in the rule."]
+        Custom = 4 => "custom",
+    }
+}
+
+integer_enum! {
+    #[doc = " Which attachments a mail rule consumes."]
+    pub enum MailAttachmentType {
+        #[doc = " Only attachments, not inline files."]
+        Attachments = 1 => "attachments",
+        #[doc = " All files, including inline ones."]
+        Everything = 2 => "everything",
+    }
+}
+
+integer_enum! {
+    #[doc = " What a mail rule consumes of a mail."]
+    pub enum MailConsumptionScope {
+        #[doc = " Only the attachments."]
+        Attachments = 1 => "attachments",
+        #[doc = " The mail itself, as a PDF."]
+        EmailOnly = 2 => "email_only",
+        #[doc = " The mail and its attachments."]
+        Everything = 3 => "everything",
+    }
+}
+
+integer_enum! {
+    #[doc = " The layout of mails consumed as a PDF."]
+    pub enum MailPdfLayout {
+        #[doc = " The system default."]
+        Default = 0 => "default",
+        #[doc = " The text part, then the HTML part."]
+        TextHtml = 1 => "text_html",
+        #[doc = " The HTML part, then the text part."]
+        HtmlText = 2 => "html_text",
+        #[doc = " Only the HTML part."]
+        HtmlOnly = 3 => "html_only",
+        #[doc = " Only the text part."]
+        TextOnly = 4 => "text_only",
+    }
+}
+
+integer_enum! {
+    #[doc = " The encryption of the connection to an IMAP server."]
+    pub enum ImapSecurity {
+        #[doc = " No encryption."]
+        None = 1 => "none",
+        #[doc = " TLS from the start."]
+        Ssl = 2 => "ssl",
+        #[doc = " An upgrade to TLS with STARTTLS."]
+        Starttls = 3 => "starttls",
+    }
+}
+
+integer_enum! {
+    #[doc = " How a mail account signs in."]
+    pub enum MailAccountType {
+        #[doc = " IMAP with a password."]
+        Imap = 1 => "imap",
+        #[doc = " Gmail with OAuth."]
+        GmailOauth = 2 => "gmail_oauth",
+        #[doc = " Outlook with OAuth."]
+        OutlookOauth = 3 => "outlook_oauth",
+    }
+}
+
+integer_enum! {
+    #[doc = " The condition of a filter rule of a saved view."]
+    pub enum FilterRuleType {
+        #[doc = " The title contains the value."]
+        TitleContains = 0 => "title_contains",
+        #[doc = " The content contains the value."]
+        ContentContains = 1 => "content_contains",
+        #[doc = " The archive serial number is the value."]
+        AsnIs = 2 => "asn_is",
+        #[doc = " The correspondent is the value."]
+        CorrespondentIs = 3 => "correspondent_is",
+        #[doc = " The document type is the value."]
+        DocumentTypeIs = 4 => "document_type_is",
+        #[doc = " The document has an inbox tag."]
+        IsInInbox = 5 => "is_in_inbox",
+        #[doc = " The document has the tag."]
+        HasTag = 6 => "has_tag",
+        #[doc = " The document has any tag."]
+        HasAnyTag = 7 => "has_any_tag",
+        #[doc = " Created before the date."]
+        CreatedBefore = 8 => "created_before",
+        #[doc = " Created after the date."]
+        CreatedAfter = 9 => "created_after",
+        #[doc = " Created in the year."]
+        CreatedYearIs = 10 => "created_year_is",
+        #[doc = " Created in the month."]
+        CreatedMonthIs = 11 => "created_month_is",
+        #[doc = " Created on the day of the month."]
+        CreatedDayIs = 12 => "created_day_is",
+        #[doc = " Added before the date."]
+        AddedBefore = 13 => "added_before",
+        #[doc = " Added after the date."]
+        AddedAfter = 14 => "added_after",
+        #[doc = " Modified before the date."]
+        ModifiedBefore = 15 => "modified_before",
+        #[doc = " Modified after the date."]
+        ModifiedAfter = 16 => "modified_after",
+        #[doc = " The document doesn't have the tag."]
+        DoesNotHaveTag = 17 => "does_not_have_tag",
+        #[doc = " The document has no archive serial number."]
+        DoesNotHaveAsn = 18 => "does_not_have_asn",
+        #[doc = " The title or the content contains the value."]
+        TitleOrContentContains = 19 => "title_or_content_contains",
+        #[doc = " A full text query."]
+        FulltextQuery = 20 => "fulltext_query",
+        #[doc = " Documents similar to the document with the id."]
+        MoreLikeThis = 21 => "more_like_this",
+        #[doc = " The document has any of the tags."]
+        HasTagsIn = 22 => "has_tags_in",
+        #[doc = " The archive serial number is greater than the value."]
+        AsnGreaterThan = 23 => "asn_greater_than",
+        #[doc = " The archive serial number is less than the value."]
+        AsnLessThan = 24 => "asn_less_than",
+        #[doc = " The storage path is the value."]
+        StoragePathIs = 25 => "storage_path_is",
+        #[doc = " The correspondent is any of the values."]
+        HasCorrespondentIn = 26 => "has_correspondent_in",
+        #[doc = " The correspondent is none of the values."]
+        DoesNotHaveCorrespondentIn = 27 => "does_not_have_correspondent_in",
+        #[doc = " The document type is any of the values."]
+        HasDocumentTypeIn = 28 => "has_document_type_in",
+        #[doc = " The document type is none of the values."]
+        DoesNotHaveDocumentTypeIn = 29 => "does_not_have_document_type_in",
+        #[doc = " The storage path is any of the values."]
+        HasStoragePathIn = 30 => "has_storage_path_in",
+        #[doc = " The storage path is none of the values."]
+        DoesNotHaveStoragePathIn = 31 => "does_not_have_storage_path_in",
+        #[doc = " The owner is the value."]
+        OwnerIs = 32 => "owner_is",
+        #[doc = " The owner is any of the values."]
+        HasOwnerIn = 33 => "has_owner_in",
+        #[doc = " The document has no owner."]
+        DoesNotHaveOwner = 34 => "does_not_have_owner",
+        #[doc = " The owner is none of the values."]
+        DoesNotHaveOwnerIn = 35 => "does_not_have_owner_in",
+        #[doc = " A custom field contains the value."]
+        HasCustomFieldValue = 36 => "has_custom_field_value",
+        #[doc = " The document is shared by the current user."]
+        IsSharedByMe = 37 => "is_shared_by_me",
+        #[doc = " The document has all of the custom fields."]
+        HasCustomFields = 38 => "has_custom_fields",
+        #[doc = " The document has any of the custom fields."]
+        HasCustomFieldIn = 39 => "has_custom_field_in",
+        #[doc = " The document has none of the custom fields."]
+        DoesNotHaveCustomFieldIn = 40 => "does_not_have_custom_field_in",
+        #[doc = " The document has no custom field."]
+        DoesNotHaveCustomField = 41 => "does_not_have_custom_field",
+        #[doc = " A custom field query."]
+        CustomFieldsQuery = 42 => "custom_fields_query",
+        #[doc = " Created on or before the date."]
+        CreatedTo = 43 => "created_to",
+        #[doc = " Created on or after the date."]
+        CreatedFrom = 44 => "created_from",
+        #[doc = " Added on or before the date."]
+        AddedTo = 45 => "added_to",
+        #[doc = " Added on or after the date."]
+        AddedFrom = 46 => "added_from",
+        #[doc = " The MIME type is the value."]
+        MimeTypeIs = 47 => "mime_type_is",
+    }
+}
@@ -21,7 +21,7 @@ impl UiSettings {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/ui_settings/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -52,7 +52,7 @@ impl UiSettings {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/ui_settings/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -28,7 +28,7 @@ impl Users {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/users/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = ordering {
             query_params.push(("ordering", p));
@@ -92,70 +92,18 @@ impl Users {
         username__istartswith: Option<String>,
     ) -> impl futures::Stream<Item = Result<crate::types::User, crate::types::error::Error>> + Unpin + '_
     {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(
-            ordering,
-            None,
-            page_size,
-            username__icontains,
-            username__iendswith,
-            username__iexact,
-            username__istartswith,
+        crate::paginator::list_items(
+            &self.client,
+            self.list(
+                ordering,
+                None,
+                page_size,
+                username__icontains,
+                username__iendswith,
+                username__iexact,
+                username__istartswith,
+            ),
         )
-        .map_ok(move |result| {
-            let items = futures::stream::iter(result.items().into_iter().map(Ok));
-            let next_pages = futures::stream::try_unfold(
-                (None, result),
-                move |(prev_page_token, new_result)| async move {
-                    if new_result.has_more_pages()
-                        && !new_result.items().is_empty()
-                        && prev_page_token != new_result.next_page_token()
-                    {
-                        async {
-                            let mut req = self.client.client.request(
-                                http::Method::GET,
-                                format!("{}/{}", self.client.base_url, "api/users/"),
-                            );
-                            req = req
-                                .header("Authorization", format!("Token {}", &self.client.token));
-                            let mut request = req.build()?;
-                            request = new_result.next_page(request)?;
-                            let resp = self.client.client.execute(request).await?;
-                            let status = resp.status();
-                            if status.is_success() {
-                                let text = resp.text().await.unwrap_or_default();
-                                serde_json::from_str(&text).map_err(|err| {
-                                    crate::types::error::Error::from_serde_error(
-                                        format_serde_error::SerdeError::new(text.to_string(), err),
-                                        status,
-                                    )
-                                })
-                            } else {
-                                let text = resp.text().await.unwrap_or_default();
-                                Err(crate::types::error::Error::Server {
-                                    body: text.to_string(),
-                                    status,
-                                })
-                            }
-                        }
-                        .map_ok(|result: crate::types::PaginatedUserList| {
-                            Some((
-                                futures::stream::iter(result.items().into_iter().map(Ok)),
-                                (new_result.next_page_token(), result),
-                            ))
-                        })
-                        .await
-                    } else {
-                        Ok(None)
-                    }
-                },
-            )
-            .try_flatten();
-            items.chain(next_pages)
-        })
-        .try_flatten_stream()
-        .boxed()
     }
 
     #[doc = "Perform a `POST` request to `/api/users/`.\n\n```rust,no_run\nasync fn example_users_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::User = client\n        .users()\n        .create(&paperless_api_client::types::UserRequest {\n            username: \"some-string\".to_string(),\n            email: Some(\"email@example.com\".to_string()),\n            password: Some(\"some-string\".to_string()),\n            first_name: Some(\"some-string\".to_string()),\n            last_name: Some(\"some-string\".to_string()),\n            date_joined: Some(chrono::Utc::now()),\n            is_staff: Some(true),\n            is_active: Some(true),\n            is_superuser: Some(true),\n            groups: Some(vec![4 as i64]),\n            user_permissions: Some(vec![\"some-string\".to_string()]),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
@@ -169,7 +117,7 @@ impl Users {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/users/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -205,7 +153,7 @@ impl Users {
                 "api/users/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -241,7 +189,7 @@ impl Users {
                 "api/users/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -274,7 +222,7 @@ impl Users {
                 "api/users/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -304,7 +252,7 @@ impl Users {
                 "api/users/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -340,7 +288,7 @@ impl Users {
                 "api/users/{id}/deactivate_totp/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -359,4 +307,13 @@ impl Users {
             })
         }
     }
+
+    #[doc = "Fetch users by id with parallel `GET` requests to `/api/users/{id}/`.\n\nThe endpoint has no `id__in` filter, so every object is retrieved on its own. Ids not found are reported as missing.\n\n```rust,no_run\nasync fn example_users_get_many() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::paginator::ByIds<paperless_api_client::types::User> =\n        client.users().get_many(&[4 as i64, 5 as i64]).await?;\n    println!(\"{:?} missing\", result.missing);\n    Ok(())\n}\n```"]
+    #[tracing::instrument]
+    pub async fn get_many<'a>(
+        &'a self,
+        ids: &'a [i64],
+    ) -> Result<crate::paginator::ByIds<crate::types::User>, crate::types::error::Error> {
+        crate::paginator::retrieve_many(ids, |id| self.retrieve(id)).await
+    }
 }
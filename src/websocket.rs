@@ -0,0 +1,105 @@
+use crate::Client;
+use anyhow::Result;
+use futures::StreamExt;
+use tokio_tungstenite::tungstenite;
+
+type Socket =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+const MIN_RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+const MAX_RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(60);
+
+#[doc = " The websocket URL of the status endpoint for a client base URL, which must"]
+#[doc = " start with `http://` or `https://`."]
+pub fn status_url(base_url: &str) -> Result<String, crate::types::error::Error> {
+    let base_url = base_url.trim_end_matches('/');
+    let base_url = if let Some(rest) = base_url.strip_prefix("https://") {
+        format!("wss://{rest}")
+    } else if let Some(rest) = base_url.strip_prefix("http://") {
+        format!("ws://{rest}")
+    } else {
+        return Err(crate::types::error::Error::InvalidRequest(format!(
+            "base URL {base_url:?} has no http or https scheme"
+        )));
+    };
+    Ok(format!("{base_url}/ws/status/"))
+}
+
+async fn connect(client: &Client) -> Result<Socket, crate::types::error::Error> {
+    use tungstenite::client::IntoClientRequest;
+    let mut request = status_url(&client.base_url)?.into_client_request()?;
+    request.headers_mut().extend(client.auth_headers().await?);
+    let (socket, _) = tokio_tungstenite::connect_async(request).await?;
+    Ok(socket)
+}
+
+struct State {
+    client: Client,
+    socket: Option<Socket>,
+    delay: std::time::Duration,
+    reconnecting: bool,
+    done: bool,
+}
+
+#[doc = " Stream the consumer progress messages pushed over `/ws/status/`."]
+#[doc = ""]
+#[doc = " The connection is re-established with exponential backoff whenever it drops;"]
+#[doc = " every failed attempt is reported as an error item without ending the stream."]
+#[doc = " Only a rejected handshake (401 or 403) or a base URL without an http or https"]
+#[doc = " scheme ends it."]
+pub fn status_updates(
+    client: Client,
+) -> impl futures::Stream<Item = Result<crate::types::StatusUpdate, crate::types::error::Error>> + Unpin
+{
+    let state = State {
+        client,
+        socket: None,
+        delay: MIN_RECONNECT_DELAY,
+        reconnecting: false,
+        done: false,
+    };
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if state.done {
+                return None;
+            }
+            let Some(socket) = state.socket.as_mut() else {
+                if state.reconnecting {
+                    tokio::time::sleep(state.delay).await;
+                    state.delay = (state.delay * 2).min(MAX_RECONNECT_DELAY);
+                }
+                state.reconnecting = true;
+                match connect(&state.client).await {
+                    Ok(socket) => {
+                        state.socket = Some(socket);
+                        state.delay = MIN_RECONNECT_DELAY;
+                        continue;
+                    }
+                    Err(err) => {
+                        state.done = err.is_permission_denied()
+                            || matches!(err, crate::types::error::Error::InvalidRequest(_));
+                        return Some((Err(err), state));
+                    }
+                }
+            };
+            match socket.next().await {
+                Some(Ok(tungstenite::Message::Text(text))) => {
+                    match serde_json::from_str::<crate::types::StatusMessage>(&text) {
+                        Ok(crate::types::StatusMessage::StatusUpdate(update)) => {
+                            return Some((Ok(update), state))
+                        }
+                        Ok(_) => {}
+                        Err(err) => return Some((Err(err.into()), state)),
+                    }
+                }
+                Some(Ok(tungstenite::Message::Close(_))) | None => state.socket = None,
+                Some(Ok(_)) => {}
+                Some(Err(err)) => {
+                    state.socket = None;
+                    return Some((Err(err.into()), state));
+                }
+            }
+        }
+    })
+    .boxed()
+}
@@ -23,7 +23,7 @@ impl WorkflowTriggers {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/workflow_triggers/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = page {
             query_params.push(("page", format!("{p}")));
@@ -63,73 +63,10 @@ impl WorkflowTriggers {
     ) -> impl futures::Stream<Item = Result<crate::types::WorkflowTrigger, crate::types::error::Error>>
            + Unpin
            + '_ {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(None, page_size)
-            .map_ok(move |result| {
-                let items = futures::stream::iter(result.items().into_iter().map(Ok));
-                let next_pages = futures::stream::try_unfold(
-                    (None, result),
-                    move |(prev_page_token, new_result)| async move {
-                        if new_result.has_more_pages()
-                            && !new_result.items().is_empty()
-                            && prev_page_token != new_result.next_page_token()
-                        {
-                            async {
-                                let mut req = self.client.client.request(
-                                    http::Method::GET,
-                                    format!(
-                                        "{}/{}",
-                                        self.client.base_url, "api/workflow_triggers/"
-                                    ),
-                                );
-                                req = req.header(
-                                    "Authorization",
-                                    format!("Token {}", &self.client.token),
-                                );
-                                let mut request = req.build()?;
-                                request = new_result.next_page(request)?;
-                                let resp = self.client.client.execute(request).await?;
-                                let status = resp.status();
-                                if status.is_success() {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    serde_json::from_str(&text).map_err(|err| {
-                                        crate::types::error::Error::from_serde_error(
-                                            format_serde_error::SerdeError::new(
-                                                text.to_string(),
-                                                err,
-                                            ),
-                                            status,
-                                        )
-                                    })
-                                } else {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    Err(crate::types::error::Error::Server {
-                                        body: text.to_string(),
-                                        status,
-                                    })
-                                }
-                            }
-                            .map_ok(|result: crate::types::PaginatedWorkflowTriggerList| {
-                                Some((
-                                    futures::stream::iter(result.items().into_iter().map(Ok)),
-                                    (new_result.next_page_token(), result),
-                                ))
-                            })
-                            .await
-                        } else {
-                            Ok(None)
-                        }
-                    },
-                )
-                .try_flatten();
-                items.chain(next_pages)
-            })
-            .try_flatten_stream()
-            .boxed()
+        crate::paginator::list_items(&self.client, self.list(None, page_size))
     }
 
-    #[doc = "Perform a `POST` request to `/api/workflow_triggers/`.\n\n```rust,no_run\nasync fn example_workflow_triggers_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::WorkflowTrigger = client\n        .workflow_triggers()\n        .create(&paperless_api_client::types::WorkflowTriggerRequest {\n            id: Some(4 as i64),\n            sources: vec![4 as i64],\n            type_: 4 as i64,\n            filter_path: Some(\"some-string\".to_string()),\n            filter_filename: Some(\"some-string\".to_string()),\n            filter_mailrule: Some(4 as i64),\n            matching_algorithm: Some(4 as i64),\n            match_: Some(\"some-string\".to_string()),\n            is_insensitive: Some(true),\n            filter_has_tags: Some(vec![4 as i64]),\n            filter_has_correspondent: Some(4 as i64),\n            filter_has_document_type: Some(4 as i64),\n            schedule_offset_days: Some(4 as i64),\n            schedule_is_recurring: Some(true),\n            schedule_recurring_interval_days: Some(4 as i64),\n            schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n            schedule_date_custom_field: Some(4 as i64),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `POST` request to `/api/workflow_triggers/`.\n\n```rust,no_run\nasync fn example_workflow_triggers_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::WorkflowTrigger = client\n        .workflow_triggers()\n        .create(&paperless_api_client::types::WorkflowTriggerRequest {\n            id: Some(4 as i64),\n            sources: vec![paperless_api_client::types::DocumentSource::ApiUpload],\n            type_: paperless_api_client::types::WorkflowTriggerType::DocumentAdded,\n            filter_path: Some(\"some-string\".to_string()),\n            filter_filename: Some(\"some-string\".to_string()),\n            filter_mailrule: Some(4 as i64),\n            matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n            match_: Some(\"some-string\".to_string()),\n            is_insensitive: Some(true),\n            filter_has_tags: Some(vec![4 as i64]),\n            filter_has_correspondent: Some(4 as i64),\n            filter_has_document_type: Some(4 as i64),\n            schedule_offset_days: Some(4 as i64),\n            schedule_is_recurring: Some(true),\n            schedule_recurring_interval_days: Some(4 as i64),\n            schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n            schedule_date_custom_field: Some(4 as i64),\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn create<'a>(
@@ -140,7 +77,7 @@ impl WorkflowTriggers {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/workflow_triggers/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -176,7 +113,7 @@ impl WorkflowTriggers {
                 "api/workflow_triggers/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -196,7 +133,7 @@ impl WorkflowTriggers {
         }
     }
 
-    #[doc = "Perform a `PUT` request to `/api/workflow_triggers/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this workflow trigger. (required)\n\n```rust,no_run\nasync fn example_workflow_triggers_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::WorkflowTrigger = client\n        .workflow_triggers()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::WorkflowTriggerRequest {\n                id: Some(4 as i64),\n                sources: vec![4 as i64],\n                type_: 4 as i64,\n                filter_path: Some(\"some-string\".to_string()),\n                filter_filename: Some(\"some-string\".to_string()),\n                filter_mailrule: Some(4 as i64),\n                matching_algorithm: Some(4 as i64),\n                match_: Some(\"some-string\".to_string()),\n                is_insensitive: Some(true),\n                filter_has_tags: Some(vec![4 as i64]),\n                filter_has_correspondent: Some(4 as i64),\n                filter_has_document_type: Some(4 as i64),\n                schedule_offset_days: Some(4 as i64),\n                schedule_is_recurring: Some(true),\n                schedule_recurring_interval_days: Some(4 as i64),\n                schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n                schedule_date_custom_field: Some(4 as i64),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PUT` request to `/api/workflow_triggers/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this workflow trigger. (required)\n\n```rust,no_run\nasync fn example_workflow_triggers_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::WorkflowTrigger = client\n        .workflow_triggers()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::WorkflowTriggerRequest {\n                id: Some(4 as i64),\n                sources: vec![paperless_api_client::types::DocumentSource::ApiUpload],\n                type_: paperless_api_client::types::WorkflowTriggerType::DocumentAdded,\n                filter_path: Some(\"some-string\".to_string()),\n                filter_filename: Some(\"some-string\".to_string()),\n                filter_mailrule: Some(4 as i64),\n                matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                match_: Some(\"some-string\".to_string()),\n                is_insensitive: Some(true),\n                filter_has_tags: Some(vec![4 as i64]),\n                filter_has_correspondent: Some(4 as i64),\n                filter_has_document_type: Some(4 as i64),\n                schedule_offset_days: Some(4 as i64),\n                schedule_is_recurring: Some(true),\n                schedule_recurring_interval_days: Some(4 as i64),\n                schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n                schedule_date_custom_field: Some(4 as i64),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn update<'a>(
@@ -212,7 +149,7 @@ impl WorkflowTriggers {
                 "api/workflow_triggers/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -245,7 +182,7 @@ impl WorkflowTriggers {
                 "api/workflow_triggers/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -259,7 +196,7 @@ impl WorkflowTriggers {
         }
     }
 
-    #[doc = "Perform a `PATCH` request to `/api/workflow_triggers/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this workflow trigger. (required)\n\n```rust,no_run\nasync fn example_workflow_triggers_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::WorkflowTrigger = client\n        .workflow_triggers()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedWorkflowTriggerRequest {\n                id: Some(4 as i64),\n                sources: vec![4 as i64],\n                type_: Some(4 as i64),\n                filter_path: Some(\"some-string\".to_string()),\n                filter_filename: Some(\"some-string\".to_string()),\n                filter_mailrule: Some(4 as i64),\n                matching_algorithm: Some(4 as i64),\n                match_: Some(\"some-string\".to_string()),\n                is_insensitive: Some(true),\n                filter_has_tags: Some(vec![4 as i64]),\n                filter_has_correspondent: Some(4 as i64),\n                filter_has_document_type: Some(4 as i64),\n                schedule_offset_days: Some(4 as i64),\n                schedule_is_recurring: Some(true),\n                schedule_recurring_interval_days: Some(4 as i64),\n                schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n                schedule_date_custom_field: Some(4 as i64),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PATCH` request to `/api/workflow_triggers/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this workflow trigger. (required)\n\n```rust,no_run\nasync fn example_workflow_triggers_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::WorkflowTrigger = client\n        .workflow_triggers()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedWorkflowTriggerRequest {\n                id: Some(4 as i64),\n                sources: vec![paperless_api_client::types::DocumentSource::ApiUpload],\n                type_: Some(paperless_api_client::types::WorkflowTriggerType::DocumentAdded),\n                filter_path: Some(\"some-string\".to_string()),\n                filter_filename: Some(\"some-string\".to_string()),\n                filter_mailrule: Some(4 as i64),\n                matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                match_: Some(\"some-string\".to_string()),\n                is_insensitive: Some(true),\n                filter_has_tags: Some(vec![4 as i64]),\n                filter_has_correspondent: Some(4 as i64),\n                filter_has_document_type: Some(4 as i64),\n                schedule_offset_days: Some(4 as i64),\n                schedule_is_recurring: Some(true),\n                schedule_recurring_interval_days: Some(4 as i64),\n                schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n                schedule_date_custom_field: Some(4 as i64),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn partial_update<'a>(
@@ -275,7 +212,7 @@ impl WorkflowTriggers {
                 "api/workflow_triggers/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
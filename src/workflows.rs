@@ -23,7 +23,7 @@ impl Workflows {
             http::Method::GET,
             format!("{}/{}", self.client.base_url, "api/workflows/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let mut query_params = vec![];
         if let Some(p) = page {
             query_params.push(("page", format!("{p}")));
@@ -63,70 +63,10 @@ impl Workflows {
     ) -> impl futures::Stream<Item = Result<crate::types::Workflow, crate::types::error::Error>>
            + Unpin
            + '_ {
-        use crate::types::paginate::Pagination;
-        use futures::{StreamExt, TryFutureExt, TryStreamExt};
-        self.list(None, page_size)
-            .map_ok(move |result| {
-                let items = futures::stream::iter(result.items().into_iter().map(Ok));
-                let next_pages = futures::stream::try_unfold(
-                    (None, result),
-                    move |(prev_page_token, new_result)| async move {
-                        if new_result.has_more_pages()
-                            && !new_result.items().is_empty()
-                            && prev_page_token != new_result.next_page_token()
-                        {
-                            async {
-                                let mut req = self.client.client.request(
-                                    http::Method::GET,
-                                    format!("{}/{}", self.client.base_url, "api/workflows/"),
-                                );
-                                req = req.header(
-                                    "Authorization",
-                                    format!("Token {}", &self.client.token),
-                                );
-                                let mut request = req.build()?;
-                                request = new_result.next_page(request)?;
-                                let resp = self.client.client.execute(request).await?;
-                                let status = resp.status();
-                                if status.is_success() {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    serde_json::from_str(&text).map_err(|err| {
-                                        crate::types::error::Error::from_serde_error(
-                                            format_serde_error::SerdeError::new(
-                                                text.to_string(),
-                                                err,
-                                            ),
-                                            status,
-                                        )
-                                    })
-                                } else {
-                                    let text = resp.text().await.unwrap_or_default();
-                                    Err(crate::types::error::Error::Server {
-                                        body: text.to_string(),
-                                        status,
-                                    })
-                                }
-                            }
-                            .map_ok(|result: crate::types::PaginatedWorkflowList| {
-                                Some((
-                                    futures::stream::iter(result.items().into_iter().map(Ok)),
-                                    (new_result.next_page_token(), result),
-                                ))
-                            })
-                            .await
-                        } else {
-                            Ok(None)
-                        }
-                    },
-                )
-                .try_flatten();
-                items.chain(next_pages)
-            })
-            .try_flatten_stream()
-            .boxed()
+        crate::paginator::list_items(&self.client, self.list(None, page_size))
     }
 
-    #[doc = "Perform a `POST` request to `/api/workflows/`.\n\n```rust,no_run\nasync fn example_workflows_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Workflow = client\n        .workflows()\n        .create(&paperless_api_client::types::WorkflowRequest {\n            name: \"some-string\".to_string(),\n            order: Some(4 as i64),\n            enabled: Some(true),\n            triggers: vec![paperless_api_client::types::WorkflowTriggerRequest {\n                id: Some(4 as i64),\n                sources: vec![4 as i64],\n                type_: 4 as i64,\n                filter_path: Some(\"some-string\".to_string()),\n                filter_filename: Some(\"some-string\".to_string()),\n                filter_mailrule: Some(4 as i64),\n                matching_algorithm: Some(4 as i64),\n                match_: Some(\"some-string\".to_string()),\n                is_insensitive: Some(true),\n                filter_has_tags: Some(vec![4 as i64]),\n                filter_has_correspondent: Some(4 as i64),\n                filter_has_document_type: Some(4 as i64),\n                schedule_offset_days: Some(4 as i64),\n                schedule_is_recurring: Some(true),\n                schedule_recurring_interval_days: Some(4 as i64),\n                schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n                schedule_date_custom_field: Some(4 as i64),\n            }],\n            actions: vec![paperless_api_client::types::WorkflowActionRequest {\n                id: Some(4 as i64),\n                type_: Some(4 as i64),\n                assign_title: Some(\"some-string\".to_string()),\n                assign_tags: Some(vec![4 as i64]),\n                assign_correspondent: Some(4 as i64),\n                assign_document_type: Some(4 as i64),\n                assign_storage_path: Some(4 as i64),\n                assign_owner: Some(4 as i64),\n                assign_view_users: Some(vec![4 as i64]),\n                assign_view_groups: Some(vec![4 as i64]),\n                assign_change_users: Some(vec![4 as i64]),\n                assign_change_groups: Some(vec![4 as i64]),\n                assign_custom_fields: Some(vec![4 as i64]),\n                assign_custom_fields_values: Some(serde_json::Value::String(\n                    \"some-string\".to_string(),\n                )),\n                remove_all_tags: Some(true),\n                remove_tags: Some(vec![4 as i64]),\n                remove_all_correspondents: Some(true),\n                remove_correspondents: Some(vec![4 as i64]),\n                remove_all_document_types: Some(true),\n                remove_document_types: Some(vec![4 as i64]),\n                remove_all_storage_paths: Some(true),\n                remove_storage_paths: Some(vec![4 as i64]),\n                remove_custom_fields: Some(vec![4 as i64]),\n                remove_all_custom_fields: Some(true),\n                remove_all_owners: Some(true),\n                remove_owners: Some(vec![4 as i64]),\n                remove_all_permissions: Some(true),\n                remove_view_users: Some(vec![4 as i64]),\n                remove_view_groups: Some(vec![4 as i64]),\n                remove_change_users: Some(vec![4 as i64]),\n                remove_change_groups: Some(vec![4 as i64]),\n                email: Some(paperless_api_client::types::WorkflowActionEmailRequest {\n                    id: Some(4 as i64),\n                    subject: \"some-string\".to_string(),\n                    body: \"some-string\".to_string(),\n                    to: \"some-string\".to_string(),\n                    include_document: Some(true),\n                }),\n                webhook: Some(paperless_api_client::types::WorkflowActionWebhookRequest {\n                    id: Some(4 as i64),\n                    url: \"some-string\".to_string(),\n                    use_params: Some(true),\n                    as_json: Some(true),\n                    params: Some(serde_json::Value::String(\"some-string\".to_string())),\n                    body: Some(\"some-string\".to_string()),\n                    headers: Some(serde_json::Value::String(\"some-string\".to_string())),\n                    include_document: Some(true),\n                }),\n            }],\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `POST` request to `/api/workflows/`.\n\n```rust,no_run\nasync fn example_workflows_create() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Workflow = client\n        .workflows()\n        .create(&paperless_api_client::types::WorkflowRequest {\n            name: \"some-string\".to_string(),\n            order: Some(4 as i64),\n            enabled: Some(true),\n            triggers: vec![paperless_api_client::types::WorkflowTriggerRequest {\n                id: Some(4 as i64),\n                sources: vec![paperless_api_client::types::DocumentSource::ApiUpload],\n                type_: paperless_api_client::types::WorkflowTriggerType::DocumentAdded,\n                filter_path: Some(\"some-string\".to_string()),\n                filter_filename: Some(\"some-string\".to_string()),\n                filter_mailrule: Some(4 as i64),\n                matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                match_: Some(\"some-string\".to_string()),\n                is_insensitive: Some(true),\n                filter_has_tags: Some(vec![4 as i64]),\n                filter_has_correspondent: Some(4 as i64),\n                filter_has_document_type: Some(4 as i64),\n                schedule_offset_days: Some(4 as i64),\n                schedule_is_recurring: Some(true),\n                schedule_recurring_interval_days: Some(4 as i64),\n                schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n                schedule_date_custom_field: Some(4 as i64),\n            }],\n            actions: vec![paperless_api_client::types::WorkflowActionRequest {\n                id: Some(4 as i64),\n                type_: Some(paperless_api_client::types::WorkflowActionType::Assignment),\n                assign_title: Some(\"some-string\".to_string()),\n                assign_tags: Some(vec![Some(4 as i64)]),\n                assign_correspondent: Some(4 as i64),\n                assign_document_type: Some(4 as i64),\n                assign_storage_path: Some(4 as i64),\n                assign_owner: Some(4 as i64),\n                assign_view_users: Some(vec![4 as i64]),\n                assign_view_groups: Some(vec![4 as i64]),\n                assign_change_users: Some(vec![4 as i64]),\n                assign_change_groups: Some(vec![4 as i64]),\n                assign_custom_fields: Some(vec![4 as i64]),\n                assign_custom_fields_values: Some(serde_json::Value::String(\n                    \"some-string\".to_string(),\n                )),\n                remove_all_tags: Some(true),\n                remove_tags: Some(vec![4 as i64]),\n                remove_all_correspondents: Some(true),\n                remove_correspondents: Some(vec![4 as i64]),\n                remove_all_document_types: Some(true),\n                remove_document_types: Some(vec![4 as i64]),\n                remove_all_storage_paths: Some(true),\n                remove_storage_paths: Some(vec![4 as i64]),\n                remove_custom_fields: Some(vec![4 as i64]),\n                remove_all_custom_fields: Some(true),\n                remove_all_owners: Some(true),\n                remove_owners: Some(vec![4 as i64]),\n                remove_all_permissions: Some(true),\n                remove_view_users: Some(vec![4 as i64]),\n                remove_view_groups: Some(vec![4 as i64]),\n                remove_change_users: Some(vec![4 as i64]),\n                remove_change_groups: Some(vec![4 as i64]),\n                email: Some(paperless_api_client::types::WorkflowActionEmailRequest {\n                    id: Some(4 as i64),\n                    subject: \"some-string\".to_string(),\n                    body: \"some-string\".to_string(),\n                    to: \"some-string\".to_string(),\n                    include_document: Some(true),\n                }),\n                webhook: Some(paperless_api_client::types::WorkflowActionWebhookRequest {\n                    id: Some(4 as i64),\n                    url: \"some-string\".to_string(),\n                    use_params: Some(true),\n                    as_json: Some(true),\n                    params: Some(serde_json::Value::String(\"some-string\".to_string())),\n                    body: Some(\"some-string\".to_string()),\n                    headers: Some(serde_json::Value::String(\"some-string\".to_string())),\n                    include_document: Some(true),\n                }),\n            }],\n        })\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn create<'a>(
@@ -137,7 +77,7 @@ impl Workflows {
             http::Method::POST,
             format!("{}/{}", self.client.base_url, "api/workflows/"),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -173,7 +113,7 @@ impl Workflows {
                 "api/workflows/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -193,7 +133,7 @@ impl Workflows {
         }
     }
 
-    #[doc = "Perform a `PUT` request to `/api/workflows/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this workflow. (required)\n\n```rust,no_run\nasync fn example_workflows_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Workflow = client\n        .workflows()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::WorkflowRequest {\n                name: \"some-string\".to_string(),\n                order: Some(4 as i64),\n                enabled: Some(true),\n                triggers: vec![paperless_api_client::types::WorkflowTriggerRequest {\n                    id: Some(4 as i64),\n                    sources: vec![4 as i64],\n                    type_: 4 as i64,\n                    filter_path: Some(\"some-string\".to_string()),\n                    filter_filename: Some(\"some-string\".to_string()),\n                    filter_mailrule: Some(4 as i64),\n                    matching_algorithm: Some(4 as i64),\n                    match_: Some(\"some-string\".to_string()),\n                    is_insensitive: Some(true),\n                    filter_has_tags: Some(vec![4 as i64]),\n                    filter_has_correspondent: Some(4 as i64),\n                    filter_has_document_type: Some(4 as i64),\n                    schedule_offset_days: Some(4 as i64),\n                    schedule_is_recurring: Some(true),\n                    schedule_recurring_interval_days: Some(4 as i64),\n                    schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n                    schedule_date_custom_field: Some(4 as i64),\n                }],\n                actions: vec![paperless_api_client::types::WorkflowActionRequest {\n                    id: Some(4 as i64),\n                    type_: Some(4 as i64),\n                    assign_title: Some(\"some-string\".to_string()),\n                    assign_tags: Some(vec![4 as i64]),\n                    assign_correspondent: Some(4 as i64),\n                    assign_document_type: Some(4 as i64),\n                    assign_storage_path: Some(4 as i64),\n                    assign_owner: Some(4 as i64),\n                    assign_view_users: Some(vec![4 as i64]),\n                    assign_view_groups: Some(vec![4 as i64]),\n                    assign_change_users: Some(vec![4 as i64]),\n                    assign_change_groups: Some(vec![4 as i64]),\n                    assign_custom_fields: Some(vec![4 as i64]),\n                    assign_custom_fields_values: Some(serde_json::Value::String(\n                        \"some-string\".to_string(),\n                    )),\n                    remove_all_tags: Some(true),\n                    remove_tags: Some(vec![4 as i64]),\n                    remove_all_correspondents: Some(true),\n                    remove_correspondents: Some(vec![4 as i64]),\n                    remove_all_document_types: Some(true),\n                    remove_document_types: Some(vec![4 as i64]),\n                    remove_all_storage_paths: Some(true),\n                    remove_storage_paths: Some(vec![4 as i64]),\n                    remove_custom_fields: Some(vec![4 as i64]),\n                    remove_all_custom_fields: Some(true),\n                    remove_all_owners: Some(true),\n                    remove_owners: Some(vec![4 as i64]),\n                    remove_all_permissions: Some(true),\n                    remove_view_users: Some(vec![4 as i64]),\n                    remove_view_groups: Some(vec![4 as i64]),\n                    remove_change_users: Some(vec![4 as i64]),\n                    remove_change_groups: Some(vec![4 as i64]),\n                    email: Some(paperless_api_client::types::WorkflowActionEmailRequest {\n                        id: Some(4 as i64),\n                        subject: \"some-string\".to_string(),\n                        body: \"some-string\".to_string(),\n                        to: \"some-string\".to_string(),\n                        include_document: Some(true),\n                    }),\n                    webhook: Some(paperless_api_client::types::WorkflowActionWebhookRequest {\n                        id: Some(4 as i64),\n                        url: \"some-string\".to_string(),\n                        use_params: Some(true),\n                        as_json: Some(true),\n                        params: Some(serde_json::Value::String(\"some-string\".to_string())),\n                        body: Some(\"some-string\".to_string()),\n                        headers: Some(serde_json::Value::String(\"some-string\".to_string())),\n                        include_document: Some(true),\n                    }),\n                }],\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PUT` request to `/api/workflows/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this workflow. (required)\n\n```rust,no_run\nasync fn example_workflows_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Workflow = client\n        .workflows()\n        .update(\n            4 as i64,\n            &paperless_api_client::types::WorkflowRequest {\n                name: \"some-string\".to_string(),\n                order: Some(4 as i64),\n                enabled: Some(true),\n                triggers: vec![paperless_api_client::types::WorkflowTriggerRequest {\n                    id: Some(4 as i64),\n                    sources: vec![paperless_api_client::types::DocumentSource::ApiUpload],\n                    type_: paperless_api_client::types::WorkflowTriggerType::DocumentAdded,\n                    filter_path: Some(\"some-string\".to_string()),\n                    filter_filename: Some(\"some-string\".to_string()),\n                    filter_mailrule: Some(4 as i64),\n                    matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                    match_: Some(\"some-string\".to_string()),\n                    is_insensitive: Some(true),\n                    filter_has_tags: Some(vec![4 as i64]),\n                    filter_has_correspondent: Some(4 as i64),\n                    filter_has_document_type: Some(4 as i64),\n                    schedule_offset_days: Some(4 as i64),\n                    schedule_is_recurring: Some(true),\n                    schedule_recurring_interval_days: Some(4 as i64),\n                    schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n                    schedule_date_custom_field: Some(4 as i64),\n                }],\n                actions: vec![paperless_api_client::types::WorkflowActionRequest {\n                    id: Some(4 as i64),\n                    type_: Some(paperless_api_client::types::WorkflowActionType::Assignment),\n                    assign_title: Some(\"some-string\".to_string()),\n                    assign_tags: Some(vec![Some(4 as i64)]),\n                    assign_correspondent: Some(4 as i64),\n                    assign_document_type: Some(4 as i64),\n                    assign_storage_path: Some(4 as i64),\n                    assign_owner: Some(4 as i64),\n                    assign_view_users: Some(vec![4 as i64]),\n                    assign_view_groups: Some(vec![4 as i64]),\n                    assign_change_users: Some(vec![4 as i64]),\n                    assign_change_groups: Some(vec![4 as i64]),\n                    assign_custom_fields: Some(vec![4 as i64]),\n                    assign_custom_fields_values: Some(serde_json::Value::String(\n                        \"some-string\".to_string(),\n                    )),\n                    remove_all_tags: Some(true),\n                    remove_tags: Some(vec![4 as i64]),\n                    remove_all_correspondents: Some(true),\n                    remove_correspondents: Some(vec![4 as i64]),\n                    remove_all_document_types: Some(true),\n                    remove_document_types: Some(vec![4 as i64]),\n                    remove_all_storage_paths: Some(true),\n                    remove_storage_paths: Some(vec![4 as i64]),\n                    remove_custom_fields: Some(vec![4 as i64]),\n                    remove_all_custom_fields: Some(true),\n                    remove_all_owners: Some(true),\n                    remove_owners: Some(vec![4 as i64]),\n                    remove_all_permissions: Some(true),\n                    remove_view_users: Some(vec![4 as i64]),\n                    remove_view_groups: Some(vec![4 as i64]),\n                    remove_change_users: Some(vec![4 as i64]),\n                    remove_change_groups: Some(vec![4 as i64]),\n                    email: Some(paperless_api_client::types::WorkflowActionEmailRequest {\n                        id: Some(4 as i64),\n                        subject: \"some-string\".to_string(),\n                        body: \"some-string\".to_string(),\n                        to: \"some-string\".to_string(),\n                        include_document: Some(true),\n                    }),\n                    webhook: Some(paperless_api_client::types::WorkflowActionWebhookRequest {\n                        id: Some(4 as i64),\n                        url: \"some-string\".to_string(),\n                        use_params: Some(true),\n                        as_json: Some(true),\n                        params: Some(serde_json::Value::String(\"some-string\".to_string())),\n                        body: Some(\"some-string\".to_string()),\n                        headers: Some(serde_json::Value::String(\"some-string\".to_string())),\n                        include_document: Some(true),\n                    }),\n                }],\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn update<'a>(
@@ -209,7 +149,7 @@ impl Workflows {
                 "api/workflows/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();
@@ -242,7 +182,7 @@ impl Workflows {
                 "api/workflows/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         let resp = req.send().await?;
         let status = resp.status();
         if status.is_success() {
@@ -256,7 +196,7 @@ impl Workflows {
         }
     }
 
-    #[doc = "Perform a `PATCH` request to `/api/workflows/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this workflow. (required)\n\n```rust,no_run\nasync fn example_workflows_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Workflow = client\n        .workflows()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedWorkflowRequest {\n                name: Some(\"some-string\".to_string()),\n                order: Some(4 as i64),\n                enabled: Some(true),\n                triggers: Some(vec![paperless_api_client::types::WorkflowTriggerRequest {\n                    id: Some(4 as i64),\n                    sources: vec![4 as i64],\n                    type_: 4 as i64,\n                    filter_path: Some(\"some-string\".to_string()),\n                    filter_filename: Some(\"some-string\".to_string()),\n                    filter_mailrule: Some(4 as i64),\n                    matching_algorithm: Some(4 as i64),\n                    match_: Some(\"some-string\".to_string()),\n                    is_insensitive: Some(true),\n                    filter_has_tags: Some(vec![4 as i64]),\n                    filter_has_correspondent: Some(4 as i64),\n                    filter_has_document_type: Some(4 as i64),\n                    schedule_offset_days: Some(4 as i64),\n                    schedule_is_recurring: Some(true),\n                    schedule_recurring_interval_days: Some(4 as i64),\n                    schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n                    schedule_date_custom_field: Some(4 as i64),\n                }]),\n                actions: Some(vec![paperless_api_client::types::WorkflowActionRequest {\n                    id: Some(4 as i64),\n                    type_: Some(4 as i64),\n                    assign_title: Some(\"some-string\".to_string()),\n                    assign_tags: Some(vec![4 as i64]),\n                    assign_correspondent: Some(4 as i64),\n                    assign_document_type: Some(4 as i64),\n                    assign_storage_path: Some(4 as i64),\n                    assign_owner: Some(4 as i64),\n                    assign_view_users: Some(vec![4 as i64]),\n                    assign_view_groups: Some(vec![4 as i64]),\n                    assign_change_users: Some(vec![4 as i64]),\n                    assign_change_groups: Some(vec![4 as i64]),\n                    assign_custom_fields: Some(vec![4 as i64]),\n                    assign_custom_fields_values: Some(serde_json::Value::String(\n                        \"some-string\".to_string(),\n                    )),\n                    remove_all_tags: Some(true),\n                    remove_tags: Some(vec![4 as i64]),\n                    remove_all_correspondents: Some(true),\n                    remove_correspondents: Some(vec![4 as i64]),\n                    remove_all_document_types: Some(true),\n                    remove_document_types: Some(vec![4 as i64]),\n                    remove_all_storage_paths: Some(true),\n                    remove_storage_paths: Some(vec![4 as i64]),\n                    remove_custom_fields: Some(vec![4 as i64]),\n                    remove_all_custom_fields: Some(true),\n                    remove_all_owners: Some(true),\n                    remove_owners: Some(vec![4 as i64]),\n                    remove_all_permissions: Some(true),\n                    remove_view_users: Some(vec![4 as i64]),\n                    remove_view_groups: Some(vec![4 as i64]),\n                    remove_change_users: Some(vec![4 as i64]),\n                    remove_change_groups: Some(vec![4 as i64]),\n                    email: Some(paperless_api_client::types::WorkflowActionEmailRequest {\n                        id: Some(4 as i64),\n                        subject: \"some-string\".to_string(),\n                        body: \"some-string\".to_string(),\n                        to: \"some-string\".to_string(),\n                        include_document: Some(true),\n                    }),\n                    webhook: Some(paperless_api_client::types::WorkflowActionWebhookRequest {\n                        id: Some(4 as i64),\n                        url: \"some-string\".to_string(),\n                        use_params: Some(true),\n                        as_json: Some(true),\n                        params: Some(serde_json::Value::String(\"some-string\".to_string())),\n                        body: Some(\"some-string\".to_string()),\n                        headers: Some(serde_json::Value::String(\"some-string\".to_string())),\n                        include_document: Some(true),\n                    }),\n                }]),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
+    #[doc = "Perform a `PATCH` request to `/api/workflows/{id}/`.\n\n**Parameters:**\n\n- `id: i64`: A unique integer value identifying this workflow. (required)\n\n```rust,no_run\nasync fn example_workflows_partial_update() -> anyhow::Result<()> {\n    let client = paperless_api_client::Client::new_from_env();\n    let result: paperless_api_client::types::Workflow = client\n        .workflows()\n        .partial_update(\n            4 as i64,\n            &paperless_api_client::types::PatchedWorkflowRequest {\n                name: Some(\"some-string\".to_string()),\n                order: Some(4 as i64),\n                enabled: Some(true),\n                triggers: Some(vec![paperless_api_client::types::WorkflowTriggerRequest {\n                    id: Some(4 as i64),\n                    sources: vec![paperless_api_client::types::DocumentSource::ApiUpload],\n                    type_: paperless_api_client::types::WorkflowTriggerType::DocumentAdded,\n                    filter_path: Some(\"some-string\".to_string()),\n                    filter_filename: Some(\"some-string\".to_string()),\n                    filter_mailrule: Some(4 as i64),\n                    matching_algorithm: Some(paperless_api_client::types::MatchingAlgorithm::Regex),\n                    match_: Some(\"some-string\".to_string()),\n                    is_insensitive: Some(true),\n                    filter_has_tags: Some(vec![4 as i64]),\n                    filter_has_correspondent: Some(4 as i64),\n                    filter_has_document_type: Some(4 as i64),\n                    schedule_offset_days: Some(4 as i64),\n                    schedule_is_recurring: Some(true),\n                    schedule_recurring_interval_days: Some(4 as i64),\n                    schedule_date_field: Some(paperless_api_client::types::ScheduleDateFieldEnum::Modified),\n                    schedule_date_custom_field: Some(4 as i64),\n                }]),\n                actions: Some(vec![paperless_api_client::types::WorkflowActionRequest {\n                    id: Some(4 as i64),\n                    type_: Some(paperless_api_client::types::WorkflowActionType::Assignment),\n                    assign_title: Some(\"some-string\".to_string()),\n                    assign_tags: Some(vec![Some(4 as i64)]),\n                    assign_correspondent: Some(4 as i64),\n                    assign_document_type: Some(4 as i64),\n                    assign_storage_path: Some(4 as i64),\n                    assign_owner: Some(4 as i64),\n                    assign_view_users: Some(vec![4 as i64]),\n                    assign_view_groups: Some(vec![4 as i64]),\n                    assign_change_users: Some(vec![4 as i64]),\n                    assign_change_groups: Some(vec![4 as i64]),\n                    assign_custom_fields: Some(vec![4 as i64]),\n                    assign_custom_fields_values: Some(serde_json::Value::String(\n                        \"some-string\".to_string(),\n                    )),\n                    remove_all_tags: Some(true),\n                    remove_tags: Some(vec![4 as i64]),\n                    remove_all_correspondents: Some(true),\n                    remove_correspondents: Some(vec![4 as i64]),\n                    remove_all_document_types: Some(true),\n                    remove_document_types: Some(vec![4 as i64]),\n                    remove_all_storage_paths: Some(true),\n                    remove_storage_paths: Some(vec![4 as i64]),\n                    remove_custom_fields: Some(vec![4 as i64]),\n                    remove_all_custom_fields: Some(true),\n                    remove_all_owners: Some(true),\n                    remove_owners: Some(vec![4 as i64]),\n                    remove_all_permissions: Some(true),\n                    remove_view_users: Some(vec![4 as i64]),\n                    remove_view_groups: Some(vec![4 as i64]),\n                    remove_change_users: Some(vec![4 as i64]),\n                    remove_change_groups: Some(vec![4 as i64]),\n                    email: Some(paperless_api_client::types::WorkflowActionEmailRequest {\n                        id: Some(4 as i64),\n                        subject: \"some-string\".to_string(),\n                        body: \"some-string\".to_string(),\n                        to: \"some-string\".to_string(),\n                        include_document: Some(true),\n                    }),\n                    webhook: Some(paperless_api_client::types::WorkflowActionWebhookRequest {\n                        id: Some(4 as i64),\n                        url: \"some-string\".to_string(),\n                        use_params: Some(true),\n                        as_json: Some(true),\n                        params: Some(serde_json::Value::String(\"some-string\".to_string())),\n                        body: Some(\"some-string\".to_string()),\n                        headers: Some(serde_json::Value::String(\"some-string\".to_string())),\n                        include_document: Some(true),\n                    }),\n                }]),\n            },\n        )\n        .await?;\n    println!(\"{:?}\", result);\n    Ok(())\n}\n```"]
     #[tracing::instrument]
     #[allow(non_snake_case)]
     pub async fn partial_update<'a>(
@@ -272,7 +212,7 @@ impl Workflows {
                 "api/workflows/{id}/".replace("{id}", &format!("{id}"))
             ),
         );
-        req = req.header("Authorization", format!("Token {}", &self.client.token));
+        req = self.client.authenticate(req).await?;
         req = req.json(body);
         let resp = req.send().await?;
         let status = resp.status();